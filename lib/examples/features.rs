@@ -84,7 +84,7 @@ async fn test_get_chat_members_count(api: Api, message: Message) -> Result<(), E
 async fn test_get_chat_member(api: Api, message: Message) -> Result<(), Error> {
     let member = api.send(message.chat.get_member(&message.from)).await?;
     let first_name = member.user.first_name.clone();
-    let status = member.status;
+    let status = member.kind;
     api.send(message.text_reply(format!("Member {}, status {:?}", first_name, status)))
         .await?;
     Ok(())
@@ -0,0 +1,27 @@
+use std::env;
+
+use futures::StreamExt;
+use telegram_bot::*;
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    let token = env::var("TELEGRAM_BOT_TOKEN").expect("TELEGRAM_BOT_TOKEN not set");
+    let api = Api::new(token);
+
+    let mut stream = api.stream();
+    while let Some(update) = stream.next().await {
+        let update = update?;
+        if let UpdateKind::InlineQuery(query) = update.kind {
+            // Echo the query back as an article which sends it as a bold text message.
+            let mut content = InputTextMessageContent::new(format!("<b>{}</b>", query.query));
+            content.parse_mode(ParseMode::Html);
+
+            let mut article = InlineQueryResultArticle::new("echo", query.query.clone(), content);
+            article.description("Send the query in bold");
+
+            let mut answer = query.answer(vec![article.into()]);
+            api.send(answer.cache_time(0).is_personal()).await?;
+        }
+    }
+    Ok(())
+}
@@ -3,7 +3,9 @@ use std::env;
 use futures::StreamExt;
 
 use telegram_bot::prelude::*;
-use telegram_bot::{Api, Error, InputFileRef, InputFileUpload, Message, MessageKind, UpdateKind};
+use telegram_bot::{
+    Api, Error, InputFileRef, InputFileUpload, InputMediaPhoto, Message, MessageKind, UpdateKind,
+};
 
 async fn run_test(api: Api, message: Message) -> Result<(), Error> {
     let chat = message.chat.clone();
@@ -37,6 +39,13 @@ async fn run_test(api: Api, message: Message) -> Result<(), Error> {
     // Send an image from disk
     api.send(chat.photo(&file)).await?;
 
+    // Send an album of an uploaded image and an image by url
+    api.send(chat.media_group(vec![
+        InputMediaPhoto::new(&file).into(),
+        InputMediaPhoto::new(InputFileRef::new("https://telegram.org/img/t_logo.png")).into(),
+    ]))
+    .await?;
+
     // Send an audio file from disk
     let file = InputFileUpload::with_path("data/sound.mp3");
     let resp = api.send(chat.audio(file)).await?;
@@ -0,0 +1,172 @@
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+use futures::Stream;
+
+use telegram_bot_raw::{Integer, Update};
+
+use crate::errors::Error;
+use crate::stream::UpdatesStream;
+
+/// Stream of updates which have to be acknowledged once they are handled, created
+/// with `UpdatesStream::acknowledged`.
+///
+/// The offset is only advanced past an update after its `AckHandle` was acknowledged,
+/// so an update is never confirmed to Telegram or stored in the `OffsetStorage` before
+/// it has been handled. The next batch of updates is requested once every update of
+/// the current batch was either acknowledged or its handle dropped. Dropping a handle
+/// without acknowledging it makes Telegram deliver the update again, together with all
+/// updates following it in the batch.
+#[must_use = "streams do nothing unless polled"]
+pub struct AckUpdatesStream {
+    inner: UpdatesStream,
+    acks: Arc<Acks>,
+}
+
+impl AckUpdatesStream {
+    pub(crate) fn new(mut inner: UpdatesStream) -> Self {
+        let acks = Arc::new(Acks::default());
+        inner.acks = Some(acks.clone());
+        AckUpdatesStream { inner, acks }
+    }
+}
+
+impl Stream for AckUpdatesStream {
+    type Item = Result<(Update, AckHandle), Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let ref_mut = self.get_mut();
+        Pin::new(&mut ref_mut.inner).poll_next(cx).map(|item| {
+            item.map(|result| {
+                result.map(|update| {
+                    let handle = ref_mut.acks.register(update.id);
+                    (update, handle)
+                })
+            })
+        })
+    }
+}
+
+/// Acknowledges that an update from `AckUpdatesStream` was handled.
+///
+/// Dropping the handle without calling `ack` marks the update for redelivery.
+#[must_use = "updates are delivered again unless acknowledged"]
+pub struct AckHandle {
+    update_id: Integer,
+    acks: Option<Arc<Acks>>,
+}
+
+impl AckHandle {
+    /// Id of the update this handle belongs to.
+    pub fn update_id(&self) -> Integer {
+        self.update_id
+    }
+
+    /// Marks the update as handled.
+    pub fn ack(mut self) {
+        if let Some(acks) = self.acks.take() {
+            acks.settle(self.update_id, true);
+        }
+    }
+}
+
+impl Drop for AckHandle {
+    fn drop(&mut self) {
+        if let Some(acks) = self.acks.take() {
+            acks.settle(self.update_id, false);
+        }
+    }
+}
+
+impl fmt::Debug for AckHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AckHandle")
+            .field("update_id", &self.update_id)
+            .finish()
+    }
+}
+
+/// Updates which were yielded but not acknowledged yet.
+#[derive(Default)]
+pub(crate) struct Acks {
+    state: Mutex<AckState>,
+}
+
+#[derive(Default)]
+struct AckState {
+    outstanding: usize,
+    redeliver_from: Option<Integer>,
+    waker: Option<Waker>,
+}
+
+impl Acks {
+    fn register(self: &Arc<Self>, update_id: Integer) -> AckHandle {
+        self.lock().outstanding += 1;
+        AckHandle {
+            update_id,
+            acks: Some(self.clone()),
+        }
+    }
+
+    fn settle(&self, update_id: Integer, acked: bool) {
+        let mut state = self.lock();
+        state.outstanding -= 1;
+        if !acked {
+            tracing::trace!(update_id = update_id, "update was not acknowledged");
+            state.redeliver_from = Some(
+                state
+                    .redeliver_from
+                    .map_or(update_id, |redeliver| redeliver.min(update_id)),
+            );
+        }
+        if state.outstanding == 0 {
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Resolves once all yielded updates are settled, to the id of the first update
+    /// that has to be delivered again, if any.
+    pub(crate) fn poll_settled(&self, cx: &mut Context) -> Poll<Option<Integer>> {
+        let mut state = self.lock();
+        if state.outstanding > 0 {
+            state.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        Poll::Ready(state.redeliver_from.take())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, AckState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::task::noop_waker;
+
+    use super::*;
+
+    #[test]
+    fn settled_after_all_handles() {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let acks = Arc::new(Acks::default());
+
+        let first = acks.register(1);
+        let second = acks.register(2);
+        let third = acks.register(3);
+        assert_eq!(acks.poll_settled(&mut cx), Poll::Pending);
+
+        first.ack();
+        drop(third);
+        assert_eq!(acks.poll_settled(&mut cx), Poll::Pending);
+
+        drop(second);
+        assert_eq!(acks.poll_settled(&mut cx), Poll::Ready(Some(2)));
+        assert_eq!(acks.poll_settled(&mut cx), Poll::Ready(None));
+    }
+}
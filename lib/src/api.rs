@@ -2,17 +2,37 @@ use std::sync::{
     atomic::{AtomicUsize, Ordering},
     Arc,
 };
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use futures::{Future, FutureExt};
-use tokio::time::timeout;
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::{stream, Future, FutureExt, Stream, StreamExt, TryStreamExt};
+use tokio::time::{sleep, timeout};
 use tracing_futures::Instrument;
 
-use telegram_bot_raw::{HttpRequest, Request, ResponseType};
+use telegram_bot_raw::{
+    telegram_api_url, Body, ChatAction, ChatRef, File, GetUserProfilePhotos, HttpRequest, Integer,
+    MessageEntity, MessageOrChannelPost, Method, PhotoSize, Request, RequestUrl, ResponseType,
+    SendChatAction, SendMessage, ToChatRef, ToUserId, Update,
+};
 
+use crate::chat_action::ChatActionGuard;
+use crate::config::Config;
 use crate::connector::{default_connector, Connector};
 use crate::errors::{Error, ErrorKind};
+use crate::interceptor::Interceptor;
+use crate::middleware::Middleware;
+use crate::rate_limit::RateLimiter;
+use crate::send_queue::{SendQueue, Turn};
 use crate::stream::UpdatesStream;
+use crate::util::formatting::{split_text, MAX_MESSAGE_LENGTH};
+
+/// Maximum number of profile photos Telegram returns for a single request.
+const PROFILE_PHOTOS_PAGE_SIZE: Integer = 100;
+/// Number of broadcast messages that are sent concurrently.
+const BROADCAST_CONCURRENCY: usize = 30;
+/// Number of attempts per chat when a broadcast message exceeds flood control.
+const BROADCAST_MAX_ATTEMPTS: u32 = 3;
 
 /// Main type for sending requests to the Telegram bot API.
 #[derive(Clone)]
@@ -20,7 +40,13 @@ pub struct Api(Arc<ApiInner>);
 
 struct ApiInner {
     token: String,
+    server: Option<String>,
+    test_environment: bool,
     connector: Box<dyn Connector>,
+    rate_limiter: Option<RateLimiter>,
+    send_queue: Option<Arc<SendQueue>>,
+    middleware: Vec<Box<dyn Middleware>>,
+    interceptors: Vec<Box<dyn Interceptor>>,
     next_request_id: AtomicUsize,
 }
 
@@ -40,14 +66,46 @@ impl Api {
     /// # }
     /// ```
     pub fn new<T: AsRef<str>>(token: T) -> Self {
-        Self::with_connector(token, default_connector())
+        Self::with_config(Config::new(token))
     }
 
     /// Create a new `Api` instance wtih custom connector.
     pub fn with_connector<T: AsRef<str>>(token: T, connector: Box<dyn Connector>) -> Self {
+        let mut config = Config::new(token);
+        config.connector(connector);
+        Self::with_config(config)
+    }
+
+    /// Create a new `Api` instance from a `Config`.
+    ///
+    /// # Example
+    ///
+    /// Throttling outgoing messages to stay within Telegram's flood limits.
+    ///
+    /// ```rust
+    /// use telegram_bot::{Api, Config, RateLimits};
+    ///
+    /// # fn main() {
+    /// # let telegram_token = "token";
+    /// let mut config = Config::new(telegram_token);
+    /// config.rate_limits(RateLimits::default());
+    /// let api = Api::with_config(config);
+    /// # }
+    /// ```
+    pub fn with_config(config: Config) -> Self {
         Api(Arc::new(ApiInner {
-            token: token.as_ref().to_string(),
-            connector,
+            token: config.token,
+            server: config.server,
+            test_environment: config.test_environment,
+            connector: config.connector.unwrap_or_else(default_connector),
+            rate_limiter: config.rate_limits.map(RateLimiter::new),
+            send_queue: if config.ordered_per_chat {
+                Some(Arc::new(SendQueue::default()))
+            } else {
+                None
+            },
+            middleware: config.middleware,
+            interceptors: config.interceptors,
             next_request_id: AtomicUsize::new(0),
         }))
     }
@@ -75,6 +133,9 @@ impl Api {
 
     /// Send a request to the Telegram server and do not wait for a response.
     ///
+    /// With `Config::ordered_per_chat`, the message takes its place in the queue of its chat
+    /// right away.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -94,12 +155,40 @@ impl Api {
     pub fn spawn<Req: Request>(&self, request: Req) {
         let api = self.clone();
         if let Ok(request) = request.serialize() {
+            let turn = self.queue_turn(Some(&request));
             tokio::spawn(async move {
+                let _turn = turn.await;
                 let _ = api.send_http_request::<Req::Response>(request).await;
             });
         }
     }
 
+    /// Show a chat action (e.g. "typing…") until the returned guard is dropped.
+    ///
+    /// The action is re-sent in the background every few seconds, so it stays visible
+    /// during long-running handlers. Errors while sending the action are logged and ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::{Api, ChatAction, ChatId};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let telegram_token = "token";
+    /// # let api = Api::new(telegram_token);
+    /// # if false {
+    /// let chat = ChatId::new(61031);
+    /// let typing = api.keep_chat_action(chat, ChatAction::Typing);
+    /// // ... do some work ...
+    /// drop(typing);
+    /// # }
+    /// # }
+    /// ```
+    pub fn keep_chat_action<C: ToChatRef>(&self, chat: C, action: ChatAction) -> ChatActionGuard {
+        ChatActionGuard::new(self.clone(), SendChatAction::new(chat, action))
+    }
+
     /// Send a request to the Telegram server and wait for a response, timing out after `duration`.
     /// Future will resolve to `None` if timeout fired.
     ///
@@ -128,12 +217,13 @@ impl Api {
         let api = self.clone();
         let request = request.serialize();
         async move {
-            match timeout(
-                duration,
-                api.send_http_request::<Req::Response>(request.map_err(ErrorKind::from)?),
-            )
-            .await
-            {
+            let request = request.map_err(ErrorKind::from)?;
+            // Waiting for the turn in the send queue counts towards the timeout.
+            let send = async {
+                let _turn = api.queue_turn(Some(&request)).await;
+                api.send_http_request::<Req::Response>(request).await
+            };
+            match timeout(duration, send).await {
                 Err(_) => Ok(None),
                 Ok(Ok(result)) => Ok(Some(result)),
                 Ok(Err(error)) => Err(error),
@@ -143,6 +233,9 @@ impl Api {
 
     /// Send a request to the Telegram server and wait for a response.
     ///
+    /// With `Config::ordered_per_chat`, the message takes its place in the queue of its chat
+    /// when the returned future is first polled, not when it is created.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -165,19 +258,382 @@ impl Api {
         let api = self.clone();
         let request = request.serialize();
         async move {
+            let _turn = api.queue_turn(request.as_ref().ok()).await;
             api.send_http_request::<Req::Response>(request.map_err(ErrorKind::from)?)
                 .await
         }
     }
 
+    /// Send a request to the Telegram server and wait for a response. When the request exceeds
+    /// flood control, wait for the time Telegram asks for in `retry_after` and send it again,
+    /// up to `max_attempts` times in total. Other errors are returned right away.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::{Api, ChatId, prelude::*};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let telegram_token = "token";
+    /// # let api = Api::new(telegram_token);
+    /// # if false {
+    /// let chat = ChatId::new(61031);
+    /// let result = api.send_retry(chat.text("Message"), 3).await;
+    /// println!("{:?}", result);
+    /// # }
+    /// # }
+    /// ```
+    pub fn send_retry<Req: Request>(
+        &self,
+        request: Req,
+        max_attempts: u32,
+    ) -> impl Future<Output = Result<<Req::Response as ResponseType>::Type, Error>> + Send {
+        let api = self.clone();
+        let request = request.serialize();
+        async move {
+            let _turn = api.queue_turn(request.as_ref().ok()).await;
+            let request = request.map_err(ErrorKind::from)?;
+            let mut attempt = 1;
+            loop {
+                let delay = {
+                    let result = api
+                        .send_http_request::<Req::Response>(request.clone())
+                        .await;
+                    match result.as_ref().err().and_then(Error::retry_after) {
+                        Some(delay) if attempt < max_attempts => delay,
+                        _ => return result,
+                    }
+                };
+                tracing::warn!(delay = ?delay, attempt = attempt, "flood control exceeded, retrying");
+                sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+
+    /// Send a request built by `make_request` to each of the `chats`, yielding the result for
+    /// every chat as soon as it is known. Requests exceeding flood control are retried like
+    /// with `send_retry`, other failures are reported for the chat, so that e.g. users who
+    /// blocked the bot can be found with `Error::is_forbidden`.
+    ///
+    /// Enable `Config::rate_limits` to spread the messages over time instead of relying
+    /// on Telegram's flood control.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::{Api, ChatId, prelude::*};
+    /// use futures::StreamExt;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let telegram_token = "token";
+    /// # let api = Api::new(telegram_token);
+    /// # if false {
+    /// let chats = vec![ChatId::new(61031), ChatId::new(61032)];
+    /// let mut results = api.broadcast(chats, |chat| chat.text("Announcement"));
+    /// while let Some((chat, result)) = results.next().await {
+    ///     match result {
+    ///         Err(ref error) if error.is_forbidden() => println!("{:?} blocked the bot", chat),
+    ///         Err(error) => println!("failed to send to {:?}: {}", chat, error),
+    ///         Ok(_) => (),
+    ///     }
+    /// }
+    /// # }
+    /// # }
+    /// ```
+    pub fn broadcast<I, F, Req>(
+        &self,
+        chats: I,
+        mut make_request: F,
+    ) -> impl Stream<
+        Item = (
+            ChatRef,
+            Result<<Req::Response as ResponseType>::Type, Error>,
+        ),
+    > + Send
+           + Unpin
+    where
+        I: IntoIterator,
+        I::Item: ToChatRef,
+        I::IntoIter: Send + 'static,
+        F: FnMut(&ChatRef) -> Req + Send + 'static,
+        Req: Request,
+    {
+        let api = self.clone();
+        let results = stream::iter(chats)
+            .map(move |chat| {
+                let chat = chat.to_chat_ref();
+                let request = api.send_retry(make_request(&chat), BROADCAST_MAX_ATTEMPTS);
+                async move { (chat, request.await) }
+            })
+            .buffer_unordered(BROADCAST_CONCURRENCY);
+        Box::pin(results)
+    }
+
+    /// Send a text of any length to the chat, split into several messages with
+    /// `split_text` if it is longer than Telegram allows. The entities are passed on to
+    /// the messages the parts of the text they refer to end up in.
+    ///
+    /// The messages are sent one after another, and the first error stops the remaining
+    /// messages from being sent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::{Api, ChatId};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let telegram_token = "token";
+    /// # let api = Api::new(telegram_token);
+    /// # if false {
+    /// let report = "A very long report. ".repeat(1000);
+    /// let messages = api.send_long_text(ChatId::new(61031), &report, Vec::new()).await;
+    /// println!("{:?}", messages);
+    /// # }
+    /// # }
+    /// ```
+    pub fn send_long_text<C: ToChatRef>(
+        &self,
+        chat: C,
+        text: &str,
+        entities: Vec<MessageEntity>,
+    ) -> impl Future<Output = Result<Vec<MessageOrChannelPost>, Error>> + Send {
+        let api = self.clone();
+        let chat = chat.to_chat_ref();
+        let parts = split_text(text, &entities, MAX_MESSAGE_LENGTH);
+        async move {
+            let mut messages = Vec::with_capacity(parts.len());
+            for (text, entities) in parts {
+                let mut request = SendMessage::new(chat.clone(), text);
+                if !entities.is_empty() {
+                    request.entities(entities);
+                }
+                messages.push(api.send(request).await?);
+            }
+            Ok(messages)
+        }
+    }
+
+    /// Download a file from the Telegram server. The `File` must be obtained
+    /// with the `GetFile` request, which fills in the `file_path` needed for downloading.
+    ///
+    /// Fails with the HTTP status as `error_code` if the server doesn't return the file,
+    /// e.g. because the download link expired.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::{Api, FileRef, prelude::*};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let telegram_token = "token";
+    /// # let api = Api::new(telegram_token);
+    /// # if false {
+    /// let file_ref = FileRef::from("file_id");
+    /// let file = api.send(file_ref.get_file()).await.unwrap();
+    /// let data = api.download(&file).await;
+    /// println!("{:?}", data);
+    /// # }
+    /// # }
+    /// ```
+    pub fn download(&self, file: &File) -> impl Future<Output = Result<Vec<u8>, Error>> + Send {
+        let api = self.clone();
+        let request = Self::download_request(file);
+        async move {
+            let request = request?;
+            let request_id = api.0.next_request_id.fetch_add(1, Ordering::Relaxed);
+            let span = tracing::trace_span!("download", request_id = request_id);
+            async {
+                tracing::trace!(url = ?request.url, "downloading file");
+                let request = api.resolve_url(request);
+                let http_response = api.0.connector.request(&api.0.token, request).await?;
+                if !http_response.is_success() {
+                    tracing::trace!(status = http_response.status, "download failed");
+                    return Err(ErrorKind::HttpStatus(http_response.status).into());
+                }
+                tracing::trace!("file downloaded");
+                Ok(http_response.body.unwrap_or_default())
+            }
+            .instrument(span)
+            .await
+        }
+    }
+
+    /// Download a file from the Telegram server as a stream of chunks, so that
+    /// large files don't have to be kept in memory as a whole.
+    ///
+    /// If the server doesn't return the file, the stream yields a single error with the
+    /// HTTP status as `error_code`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::{Api, FileRef, prelude::*};
+    /// use futures::StreamExt;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let telegram_token = "token";
+    /// # let api = Api::new(telegram_token);
+    /// # if false {
+    /// let file_ref = FileRef::from("file_id");
+    /// let file = api.send(file_ref.get_file()).await.unwrap();
+    /// let mut stream = api.download_stream(&file);
+    /// while let Some(chunk) = stream.next().await {
+    ///     println!("received {} bytes", chunk.unwrap().len());
+    /// }
+    /// # }
+    /// # }
+    /// ```
+    pub fn download_stream(
+        &self,
+        file: &File,
+    ) -> impl Stream<Item = Result<Bytes, Error>> + Send + Unpin {
+        let api = self.clone();
+        let request = Self::download_request(file);
+        let stream = async move {
+            let request = request?;
+            tracing::trace!(url = ?request.url, "streaming file");
+            let request = api.resolve_url(request);
+            api.0.connector.request_stream(&api.0.token, request).await
+        };
+        stream::once(Box::pin(stream)).try_flatten()
+    }
+
+    /// Stream all profile photos of a user, fetching further pages with
+    /// `GetUserProfilePhotos` as needed. Each item holds up to 4 sizes of one photo.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::{Api, UserId};
+    /// use futures::StreamExt;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let telegram_token = "token";
+    /// # let api = Api::new(telegram_token);
+    /// # if false {
+    /// let mut photos = api.user_profile_photos(UserId::new(61031));
+    /// while let Some(photo) = photos.next().await {
+    ///     println!("{:?}", photo.unwrap());
+    /// }
+    /// # }
+    /// # }
+    /// ```
+    pub fn user_profile_photos<U: ToUserId>(
+        &self,
+        user: U,
+    ) -> impl Stream<Item = Result<Vec<PhotoSize>, Error>> + Send + Unpin {
+        let api = self.clone();
+        let user = user.to_user_id();
+        let pages = stream::try_unfold(Some(0), move |offset| {
+            let api = api.clone();
+            async move {
+                let offset = match offset {
+                    Some(offset) => offset,
+                    None => return Ok(None),
+                };
+                let mut request = GetUserProfilePhotos::new(user);
+                request.offset(offset).limit(PROFILE_PHOTOS_PAGE_SIZE);
+                let page = api.send(request).await?;
+
+                let next = offset + page.photos.len() as Integer;
+                let next = if page.photos.is_empty() || next >= page.total_count {
+                    None
+                } else {
+                    Some(next)
+                };
+                let photos = stream::iter(page.photos.into_iter().map(Ok));
+                Ok::<_, Error>(Some((photos, next)))
+            }
+        });
+        Box::pin(pages.try_flatten())
+    }
+
+    fn download_request(file: &File) -> Result<HttpRequest, Error> {
+        let file_path = file.file_path.clone().ok_or(ErrorKind::MissingFilePath)?;
+        Ok(HttpRequest {
+            url: RequestUrl::file(file_path),
+            method: Method::Get,
+            body: Body::Empty,
+        })
+    }
+
+    /// Point the request to the configured Bot API server and environment, if any.
+    fn resolve_url(&self, mut request: HttpRequest) -> HttpRequest {
+        if self.0.server.is_none() && !self.0.test_environment {
+            return request;
+        }
+        let server = match &self.0.server {
+            Some(server) => server.clone(),
+            None => telegram_api_url(),
+        };
+        // Test environment URLs look like `bot<token>/test/<method>`.
+        let token = if self.0.test_environment {
+            format!("{}/test", self.0.token)
+        } else {
+            self.0.token.clone()
+        };
+        request.url = RequestUrl::Url(request.url.url_on(&server, &token));
+        request
+    }
+
+    pub(crate) fn has_middleware(&self) -> bool {
+        !self.0.middleware.is_empty()
+    }
+
+    /// Runs the update through all middlewares.
+    pub(crate) fn process_update(&self, update: Update) -> BoxFuture<'static, Option<Update>> {
+        let api = self.clone();
+        async move {
+            let mut update = update;
+            for middleware in api.0.middleware.iter() {
+                update = middleware.process(&api, update).await?;
+            }
+            Some(update)
+        }
+        .boxed()
+    }
+
+    /// Takes a place in the send queue of the request's chat right away. The returned
+    /// future resolves once it is the request's turn.
+    ///
+    /// `send` and friends call this when their future is first polled, so that a future
+    /// which is created but not awaited yet doesn't block later messages to the chat.
+    fn queue_turn(&self, request: Option<&HttpRequest>) -> impl Future<Output = Option<Turn>> {
+        let turn = match (&self.0.send_queue, request) {
+            (Some(queue), Some(request)) => queue.enqueue(request),
+            _ => None,
+        };
+        async move {
+            let mut turn = turn?;
+            turn.wait().await;
+            Some(turn)
+        }
+    }
+
     async fn send_http_request<Resp: ResponseType>(
         &self,
-        request: HttpRequest,
+        mut request: HttpRequest,
     ) -> Result<Resp::Type, Error> {
         let request_id = self.0.next_request_id.fetch_add(1, Ordering::Relaxed);
         let span = tracing::trace_span!("send_http_request", request_id = request_id);
+        for interceptor in self.0.interceptors.iter() {
+            interceptor.before_request(&mut request);
+        }
+        let method = request.name();
+        let started = Instant::now();
         async {
+            if let Some(ref rate_limiter) = self.0.rate_limiter {
+                rate_limiter.acquire(&request).await;
+            }
             tracing::trace!(name = %request.name(), body = %request.body, "sending request");
+            let request = self.resolve_url(request);
             let http_response = self.0.connector.request(&self.0.token, request).await?;
             tracing::trace!(
                 response = %match http_response.body {
@@ -197,9 +653,111 @@ impl Api {
             if let Err(ref error) = result {
                 tracing::error!(error = %error);
             }
+            for interceptor in self.0.interceptors.iter() {
+                interceptor.after_response(method, result.as_ref().map(|_| ()), started.elapsed());
+            }
             result
         })
         .instrument(span)
         .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::pin::Pin;
+
+    use telegram_bot_raw::{ChatId, HttpResponse};
+
+    use super::*;
+    use crate::connector::mock::MockConnector;
+
+    /// Connector whose requests never finish.
+    #[derive(Debug)]
+    struct PendingConnector;
+
+    impl Connector for PendingConnector {
+        fn request(
+            &self,
+            _token: &str,
+            _req: HttpRequest,
+        ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, Error>> + Send>> {
+            futures::future::pending().boxed()
+        }
+    }
+
+    fn ordered_api(connector: Box<dyn Connector>) -> Api {
+        let mut config = Config::new("token");
+        config.connector(connector).ordered_per_chat();
+        Api::with_config(config)
+    }
+
+    #[tokio::test]
+    async fn send_timeout_includes_queue() {
+        let api = ordered_api(Box::new(PendingConnector));
+        let mut first = Box::pin(api.send(SendMessage::new(ChatId::new(1), "first")));
+        assert!((&mut first).now_or_never().is_none());
+
+        let second = api.send_timeout(
+            SendMessage::new(ChatId::new(1), "second"),
+            Duration::from_millis(10),
+        );
+        assert!(second.await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn queue_place_taken_on_first_poll() {
+        let mock = MockConnector::new();
+        let message = r#"{"message_id": 1, "date": 0, "text": "Hi",
+            "from": {"id": 1, "is_bot": true, "first_name": "Bot"},
+            "chat": {"id": 1, "type": "private", "first_name": "Ann"}}"#;
+        mock.respond_ok("sendMessage", message)
+            .respond_ok("sendMessage", message);
+        let api = ordered_api(Box::new(mock.clone()));
+
+        let first = api.send(SendMessage::new(ChatId::new(1), "first"));
+        let second = api.send(SendMessage::new(ChatId::new(1), "second"));
+        second.await.unwrap();
+        first.await.unwrap();
+        assert_eq!(
+            mock.json_bodies("sendMessage"),
+            vec![
+                r#"{"chat_id":1,"text":"second"}"#,
+                r#"{"chat_id":1,"text":"first"}"#
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn download_fails_with_status() {
+        let mock = MockConnector::new();
+        mock.respond("file", "content")
+            .respond_error("file", 404, "Not Found");
+        let api = Api::with_connector("token", Box::new(mock));
+        let file = File {
+            file_id: "id".to_string(),
+            file_size: None,
+            file_path: Some("photos/file_1.jpg".to_string()),
+        };
+
+        assert_eq!(api.download(&file).await.unwrap(), b"content");
+        let error = api.download(&file).await.unwrap_err();
+        assert_eq!(error.error_code(), Some(404));
+    }
+
+    #[tokio::test]
+    async fn download_stream_fails_with_status() {
+        let mock = MockConnector::new();
+        mock.respond_error("file", 404, "Not Found");
+        let api = Api::with_connector("token", Box::new(mock));
+        let file = File {
+            file_id: "id".to_string(),
+            file_size: None,
+            file_path: Some("photos/file_1.jpg".to_string()),
+        };
+
+        let chunks: Vec<_> = api.download_stream(&file).collect().await;
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].as_ref().unwrap_err().error_code(), Some(404));
+    }
+}
@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+
+use telegram_bot_raw::SendChatAction;
+
+use crate::api::Api;
+
+/// Telegram shows a chat action for at most 5 seconds, so it is re-sent a bit earlier.
+const RESEND_INTERVAL: Duration = Duration::from_secs(4);
+
+/// Keeps a chat action (e.g. "typing…") visible until dropped.
+///
+/// Created with [`Api::keep_chat_action`](struct.Api.html#method.keep_chat_action).
+#[must_use = "the chat action stops as soon as the guard is dropped"]
+pub struct ChatActionGuard {
+    handle: JoinHandle<()>,
+}
+
+impl ChatActionGuard {
+    pub(crate) fn new(api: Api, request: SendChatAction) -> Self {
+        let handle = tokio::spawn(async move {
+            loop {
+                if let Err(error) = api.send(&request).await {
+                    tracing::warn!(error = %error, "failed to send chat action");
+                }
+                sleep(RESEND_INTERVAL).await;
+            }
+        });
+        ChatActionGuard { handle }
+    }
+
+    /// Stop sending the chat action. Same as dropping the guard.
+    pub fn stop(self) {}
+}
+
+impl Drop for ChatActionGuard {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
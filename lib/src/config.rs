@@ -0,0 +1,98 @@
+use crate::connector::Connector;
+use crate::interceptor::Interceptor;
+use crate::middleware::Middleware;
+use crate::rate_limit::RateLimits;
+
+/// Configuration of an `Api` instance, see `Api::with_config`.
+pub struct Config {
+    pub(crate) token: String,
+    pub(crate) server: Option<String>,
+    pub(crate) test_environment: bool,
+    pub(crate) connector: Option<Box<dyn Connector>>,
+    pub(crate) rate_limits: Option<RateLimits>,
+    pub(crate) ordered_per_chat: bool,
+    pub(crate) middleware: Vec<Box<dyn Middleware>>,
+    pub(crate) interceptors: Vec<Box<dyn Interceptor>>,
+}
+
+impl Config {
+    pub fn new<T: AsRef<str>>(token: T) -> Self {
+        Config {
+            token: token.as_ref().to_string(),
+            server: None,
+            test_environment: false,
+            connector: None,
+            rate_limits: None,
+            ordered_per_chat: false,
+            middleware: Vec::new(),
+            interceptors: Vec::new(),
+        }
+    }
+
+    /// Send requests and file downloads to the Bot API server at `url`, e.g. a self-hosted
+    /// `telegram-bot-api` server at `http://localhost:8081`, instead of
+    /// `https://api.telegram.org`.
+    pub fn server<U: Into<String>>(&mut self, url: U) -> &mut Self {
+        let mut url = url.into();
+        if !url.ends_with('/') {
+            url.push('/');
+        }
+        self.server = Some(url);
+        self
+    }
+
+    /// Use the test environment of Telegram, whose users, chats and bots are separate from
+    /// the production ones. The bot's token must be created in the test environment.
+    pub fn test_environment(&mut self) -> &mut Self {
+        self.test_environment = true;
+        self
+    }
+
+    /// Use a custom connector instead of the default one.
+    pub fn connector(&mut self, connector: Box<dyn Connector>) -> &mut Self {
+        self.connector = Some(connector);
+        self
+    }
+
+    /// Delay outgoing messages so that they stay within the given limits.
+    /// Messages are not throttled by default.
+    pub fn rate_limits(&mut self, rate_limits: RateLimits) -> &mut Self {
+        self.rate_limits = Some(rate_limits);
+        self
+    }
+
+    /// Send messages to the same chat one after another, in the order in which they were
+    /// submitted with `Api::send` and friends, instead of concurrently. Without this,
+    /// concurrent handlers replying to the same chat may see their messages arrive in any
+    /// order. Requests that don't send a message, e.g. `editMessageText`, are not queued.
+    ///
+    /// A message submitted with `Api::send` takes its place in the queue when its future is
+    /// first polled, so futures may be awaited in any order. `Api::spawn` takes the place
+    /// right away. The time a message waits in the queue counts towards the timeout of
+    /// `Api::send_timeout`.
+    pub fn ordered_per_chat(&mut self) -> &mut Self {
+        self.ordered_per_chat = true;
+        self
+    }
+
+    /// Process incoming updates with `middleware` before `UpdatesStream` yields them.
+    /// Middlewares run in the order in which they were added.
+    pub fn middleware<M>(&mut self, middleware: M) -> &mut Self
+    where
+        M: Middleware + 'static,
+    {
+        self.middleware.push(Box::new(middleware));
+        self
+    }
+
+    /// Call `interceptor` around every request sent with this `Api`. Interceptors run in
+    /// the order in which they were added. The time passed to `after_response` includes
+    /// delays caused by `rate_limits`.
+    pub fn interceptor<I>(&mut self, interceptor: I) -> &mut Self
+    where
+        I: Interceptor + 'static,
+    {
+        self.interceptors.push(Box::new(interceptor));
+        self
+    }
+}
@@ -1,37 +1,25 @@
-use std::io::{Cursor, Read};
-use std::path::Path;
 use std::pin::Pin;
-use std::str::FromStr;
 
-use bytes::Bytes;
-use futures::{Future, FutureExt};
+use futures::{Future, FutureExt, StreamExt, TryStreamExt};
 use hyper::{
     body::to_bytes,
     client::{connect::Connect, Client},
-    header::CONTENT_TYPE,
-    http::Error as HttpError,
-    Method, Request, Uri,
 };
 #[cfg(feature = "rustls")]
 use hyper_rustls::HttpsConnector;
 #[cfg(feature = "openssl")]
 use hyper_tls::HttpsConnector;
-use multipart::client::lazy::Multipart;
-use telegram_bot_raw::{
-    Body as TelegramBody, HttpRequest, HttpResponse, Method as TelegramMethod, MultipartValue, Text,
-};
+use telegram_bot_raw::{HttpRequest, HttpResponse};
 
-use super::Connector;
+#[cfg(any(feature = "openssl", feature = "rustls"))]
+use super::proxy::{Proxy, ProxyConnector};
+use super::request::build_request;
+use super::{ByteStream, Connector};
 use crate::errors::{Error, ErrorKind};
 
 #[derive(Debug)]
 pub struct HyperConnector<C>(Client<C>);
 
-enum MultipartTemporaryValue {
-    Text(Text),
-    Data { file_name: Text, data: Bytes },
-}
-
 impl<C> HyperConnector<C> {
     pub fn new(client: Client<C>) -> Self {
         HyperConnector(client)
@@ -44,103 +32,15 @@ impl<C: Connect + std::fmt::Debug + 'static + Clone + Send + Sync> Connector for
         token: &str,
         req: HttpRequest,
     ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, Error>> + Send>> {
-        let uri = Uri::from_str(&req.url.url(token));
+        let request = build_request(token, req);
         let client = self.0.clone();
 
         let future = async move {
-            let uri = uri.map_err(HttpError::from).map_err(ErrorKind::from)?;
-
-            let method = match req.method {
-                TelegramMethod::Get => Method::GET,
-                TelegramMethod::Post => Method::POST,
-            };
-
-            let mut http_request = Request::builder().method(method).uri(uri);
-
-            let request = match req.body {
-                TelegramBody::Empty => http_request.body(Into::<hyper::Body>::into(vec![])),
-                TelegramBody::Json(body) => {
-                    let content_type = "application/json"
-                        .parse()
-                        .map_err(HttpError::from)
-                        .map_err(ErrorKind::from)?;
-                    http_request
-                        .headers_mut()
-                        .map(move |headers| headers.insert(CONTENT_TYPE, content_type));
-                    http_request.body(Into::<hyper::Body>::into(body))
-                }
-                TelegramBody::Multipart(parts) => {
-                    let mut fields = Vec::new();
-                    for (key, value) in parts {
-                        match value {
-                            MultipartValue::Text(text) => {
-                                fields.push((key, MultipartTemporaryValue::Text(text)))
-                            }
-                            MultipartValue::Path { file_name, path } => {
-                                let file_name = file_name
-                                    .or_else(|| {
-                                        AsRef::<Path>::as_ref(&path)
-                                            .file_name()
-                                            .and_then(|s| s.to_str())
-                                            .map(Into::into)
-                                    })
-                                    .ok_or(ErrorKind::InvalidMultipartFilename)?;
-
-                                let data = tokio::fs::read(path).await.map_err(ErrorKind::from)?;
-                                fields.push((
-                                    key,
-                                    MultipartTemporaryValue::Data {
-                                        file_name,
-                                        data: data.into(),
-                                    },
-                                ))
-                            }
-                            MultipartValue::Data { file_name, data } => fields
-                                .push((key, MultipartTemporaryValue::Data { file_name, data })),
-                        }
-                    }
-
-                    let mut prepared = {
-                        let mut part = Multipart::new();
-                        for (key, value) in &fields {
-                            match value {
-                                MultipartTemporaryValue::Text(text) => {
-                                    part.add_text(*key, text.as_str());
-                                }
-                                MultipartTemporaryValue::Data { file_name, data } => {
-                                    part.add_stream(
-                                        *key,
-                                        Cursor::new(data),
-                                        Some(file_name.as_str()),
-                                        None,
-                                    );
-                                }
-                            }
-                        }
-                        part.prepare().map_err(|err| err.error)
-                    }
-                    .map_err(ErrorKind::from)?;
-
-                    let boundary = prepared.boundary();
-
-                    let content_type =
-                        format!("multipart/form-data;boundary={bound}", bound = boundary)
-                            .parse()
-                            .map_err(HttpError::from)
-                            .map_err(ErrorKind::from)?;
-                    http_request.headers_mut().map(move |headers| {
-                        headers.insert(CONTENT_TYPE, content_type);
-                    });
-
-                    let mut bytes = Vec::new();
-                    prepared.read_to_end(&mut bytes).map_err(ErrorKind::from)?;
-                    http_request.body(bytes.into())
-                }
-                body => panic!("Unknown body type {:?}", body),
-            }
-            .map_err(ErrorKind::from)?;
-
-            let response = client.request(request).await.map_err(ErrorKind::from)?;
+            let response = client
+                .request(request.await?.map(hyper::Body::from))
+                .await
+                .map_err(ErrorKind::from)?;
+            let status = response.status().as_u16();
             let whole_chunk = to_bytes(response.into_body()).await;
 
             let body = whole_chunk
@@ -150,13 +50,81 @@ impl<C: Connect + std::fmt::Debug + 'static + Clone + Send + Sync> Connector for
                     acc
                 });
 
-            Ok::<HttpResponse, Error>(HttpResponse { body: Some(body) })
+            Ok::<HttpResponse, Error>(HttpResponse {
+                status,
+                body: Some(body),
+            })
         };
 
         future.boxed()
     }
+
+    fn request_stream(
+        &self,
+        token: &str,
+        req: HttpRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<ByteStream, Error>> + Send>> {
+        let request = build_request(token, req);
+        let client = self.0.clone();
+
+        let future = async move {
+            let response = client
+                .request(request.await?.map(hyper::Body::from))
+                .await
+                .map_err(ErrorKind::from)?;
+            if !response.status().is_success() {
+                return Err(ErrorKind::HttpStatus(response.status().as_u16()).into());
+            }
+            let stream = response
+                .into_body()
+                .map_err(|error| Error::from(ErrorKind::from(error)));
+
+            Ok::<ByteStream, Error>(stream.boxed())
+        };
+
+        future.boxed()
+    }
+}
+
+/// A hyper connector sending all requests through `proxy`.
+///
+/// # Examples
+///
+/// ```rust
+/// use telegram_bot::connector::hyper::proxy_connector;
+/// use telegram_bot::connector::proxy::Proxy;
+/// use telegram_bot::{Api, Config};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mut config = Config::new("token");
+/// config.connector(proxy_connector(Proxy::socks5("127.0.0.1", 9050)).unwrap());
+/// let api = Api::with_config(config);
+/// # }
+/// ```
+#[cfg(any(feature = "openssl", feature = "rustls"))]
+pub fn proxy_connector(proxy: Proxy) -> Result<Box<dyn Connector>, Error> {
+    let proxy = ProxyConnector::new(proxy);
+
+    #[cfg(feature = "rustls")]
+    let connector = {
+        let mut config = rustls_crate::ClientConfig::new();
+        config.root_store = match rustls_native_certs::load_native_certs() {
+            Ok(store) | Err((Some(store), _)) => store,
+            Err((None, error)) => return Err(ErrorKind::from(error).into()),
+        };
+        HttpsConnector::from((proxy, config))
+    };
+
+    #[cfg(feature = "openssl")]
+    let connector = HttpsConnector::new_with_connector(proxy);
+
+    Ok(Box::new(HyperConnector::new(
+        Client::builder().build(connector),
+    )))
 }
 
+#[cfg(any(feature = "openssl", feature = "rustls"))]
 pub fn default_connector() -> Result<Box<dyn Connector>, Error> {
     #[cfg(feature = "rustls")]
     let connector = HttpsConnector::with_native_roots();
@@ -168,3 +136,43 @@ pub fn default_connector() -> Result<Box<dyn Connector>, Error> {
         Client::builder().build(connector),
     )))
 }
+
+#[cfg(test)]
+mod tests {
+    use telegram_bot_raw::{Body, Method, RequestUrl};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn stream_fails_with_status() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0; 1024];
+            let _ = socket.read(&mut request).await.unwrap();
+            let body = r#"{"ok":false,"error_code":404,"description":"Not Found"}"#;
+            let response = format!(
+                "HTTP/1.1 404 Not Found\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        let connector = HyperConnector::new(Client::new());
+        let request = HttpRequest {
+            url: RequestUrl::Url(format!("http://{}/file/bottoken/file_1.jpg", address)),
+            method: Method::Get,
+            body: Body::Empty,
+        };
+        let error = connector
+            .request_stream("token", request)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(error.error_code(), Some(404));
+    }
+}
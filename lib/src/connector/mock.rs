@@ -0,0 +1,179 @@
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use futures::{future, Future, FutureExt};
+use telegram_bot_raw::{Body, HttpRequest, HttpResponse};
+
+use super::Connector;
+use crate::errors::Error;
+
+/// Connector answering requests with canned responses instead of sending them, to test bots
+/// without network access.
+///
+/// Responses are queued per method name, e.g. `sendMessage`, or `file` for downloads.
+/// Requests without a queued response fail like rejected requests, with error code 404,
+/// which is also the HTTP status of the response.
+/// All requests are recorded, so tests can check what the bot sent.
+///
+/// # Examples
+///
+/// ```rust
+/// use telegram_bot::connector::mock::MockConnector;
+/// use telegram_bot::{Api, ChatId, SendMessage};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mock = MockConnector::new();
+/// mock.respond_ok(
+///     "sendMessage",
+///     r#"{"message_id": 1, "date": 0, "text": "Hi",
+///         "from": {"id": 1, "is_bot": true, "first_name": "Bot"},
+///         "chat": {"id": 42, "type": "private", "first_name": "Ann"}}"#,
+/// );
+/// let api = Api::with_connector("token", Box::new(mock.clone()));
+///
+/// api.send(SendMessage::new(ChatId::new(42), "Hi")).await.unwrap();
+/// assert_eq!(mock.json_bodies("sendMessage"), vec![r#"{"chat_id":42,"text":"Hi"}"#]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockConnector(Arc<Mutex<MockState>>);
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses: HashMap<String, VecDeque<(u16, Vec<u8>)>>,
+    requests: Vec<HttpRequest>,
+}
+
+impl MockConnector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer the next request of `method` with the given response body.
+    pub fn respond<B: Into<Vec<u8>>>(&self, method: &str, body: B) -> &Self {
+        self.respond_with_status(method, 200, body)
+    }
+
+    /// Answer the next request of `method` with the given HTTP status and response body.
+    pub fn respond_with_status<B: Into<Vec<u8>>>(
+        &self,
+        method: &str,
+        status: u16,
+        body: B,
+    ) -> &Self {
+        self.lock()
+            .responses
+            .entry(method.to_string())
+            .or_default()
+            .push_back((status, body.into()));
+        self
+    }
+
+    /// Answer the next request of `method` successfully, with the JSON `result`.
+    pub fn respond_ok(&self, method: &str, result: &str) -> &Self {
+        self.respond(method, format!(r#"{{"ok":true,"result":{}}}"#, result))
+    }
+
+    /// Answer the next request of `method` with an error, like Telegram rejecting it.
+    /// The error code is also used as the HTTP status, so this makes downloads fail too.
+    pub fn respond_error(&self, method: &str, error_code: i64, description: &str) -> &Self {
+        let description = description.replace('\\', "\\\\").replace('"', "\\\"");
+        self.respond_with_status(
+            method,
+            u16::try_from(error_code).unwrap_or(500),
+            format!(
+                r#"{{"ok":false,"error_code":{},"description":"{}"}}"#,
+                error_code, description
+            ),
+        )
+    }
+
+    /// All requests received so far, in order.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// The JSON bodies of the requests of `method` received so far, in order.
+    pub fn json_bodies(&self, method: &str) -> Vec<String> {
+        self.lock()
+            .requests
+            .iter()
+            .filter(|request| request.name() == method)
+            .filter_map(|request| match &request.body {
+                Body::Json(body) => Some(body.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Forget the requests received so far.
+    pub fn clear_requests(&self) {
+        self.lock().requests.clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Connector for MockConnector {
+    fn request(
+        &self,
+        _token: &str,
+        req: HttpRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, Error>> + Send>> {
+        let mut state = self.lock();
+        let method = req.name();
+        let (status, body) = state
+            .responses
+            .get_mut(method)
+            .and_then(VecDeque::pop_front)
+            .unwrap_or_else(|| {
+                let body = format!(
+                    r#"{{"ok":false,"error_code":404,"description":"no mock response for {}"}}"#,
+                    method
+                );
+                (404, body.into_bytes())
+            });
+        state.requests.push(req);
+        future::ready(Ok(HttpResponse {
+            status,
+            body: Some(body),
+        }))
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use telegram_bot_raw::{ChatId, GetMe, LeaveChat};
+
+    use super::*;
+    use crate::Api;
+
+    #[tokio::test]
+    async fn responses() {
+        let mock = MockConnector::new();
+        mock.respond_ok(
+            "getMe",
+            r#"{"id": 1, "is_bot": true, "first_name": "Bot", "username": "bot"}"#,
+        )
+        .respond_error("getMe", 401, "Unauthorized");
+        let api = Api::with_connector("token", Box::new(mock.clone()));
+
+        assert_eq!(api.send(GetMe).await.unwrap().first_name, "Bot");
+        assert_eq!(api.send(GetMe).await.unwrap_err().error_code(), Some(401));
+        assert_eq!(api.send(GetMe).await.unwrap_err().error_code(), Some(404));
+        assert_eq!(mock.requests().len(), 3);
+
+        mock.clear_requests();
+        let _ = api.send(LeaveChat::new(ChatId::new(5))).await;
+        assert_eq!(mock.json_bodies("leaveChat"), vec![r#"{"chat_id":5}"#]);
+        assert!(mock.json_bodies("getMe").is_empty());
+    }
+}
@@ -1,23 +1,132 @@
-//! Connector with hyper backend.
+//! Connectors send the HTTP requests of an `Api`. The default one uses hyper, or reqwest
+//! if only the `reqwest_connector` feature is enabled.
 
 pub mod hyper;
+pub mod mock;
+pub mod proxy;
+#[cfg(feature = "record-replay")]
+pub mod record;
+mod request;
+#[cfg(feature = "reqwest_connector")]
+pub mod reqwest;
 
 use std::fmt::Debug;
 use std::pin::Pin;
+use std::sync::Arc;
 
-use futures::Future;
+use bytes::Bytes;
+use futures::{stream, Future, FutureExt, Stream, StreamExt};
 use telegram_bot_raw::{HttpRequest, HttpResponse};
 
-use crate::errors::Error;
+use crate::errors::{Error, ErrorKind};
 
+/// Stream of response body chunks.
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Bytes, Error>> + Send>>;
+
+/// Sends HTTP requests to the Bot API, so any HTTP client can be used with `Api`, see
+/// `Api::with_connector` and `Config::connector`.
+///
+/// The URL of a request is `req.url.url(token)`. The body is empty, JSON or multipart
+/// form data. Files in multipart bodies may have to be read from `MultipartValue::Path`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::pin::Pin;
+///
+/// use futures::Future;
+/// use telegram_bot::connector::{default_connector, Connector};
+/// use telegram_bot::types::{HttpRequest, HttpResponse};
+/// use telegram_bot::{Api, Error};
+///
+/// /// Prints the name of every request before sending it with the default connector.
+/// #[derive(Debug)]
+/// struct PrintingConnector(Box<dyn Connector>);
+///
+/// impl Connector for PrintingConnector {
+///     fn request(
+///         &self,
+///         token: &str,
+///         req: HttpRequest,
+///     ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, Error>> + Send>> {
+///         println!("sending {}", req.name());
+///         self.0.request(token, req)
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let api = Api::with_connector("token", Box::new(PrintingConnector(default_connector())));
+/// # }
+/// ```
 pub trait Connector: Debug + Send + Sync {
+    /// Send a request and return the whole response body.
     fn request(
         &self,
         token: &str,
         req: HttpRequest,
     ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, Error>> + Send>>;
+
+    /// Send a request and return the response body as a stream of chunks,
+    /// without buffering it in memory. Fails with `error_code` set to the HTTP status
+    /// if the status is not a success, so that an error body is never streamed as data.
+    ///
+    /// The default implementation buffers the whole body using `request`
+    /// and yields it as a single chunk.
+    fn request_stream(
+        &self,
+        token: &str,
+        req: HttpRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<ByteStream, Error>> + Send>> {
+        self.request(token, req)
+            .map(|response| {
+                let response = response?;
+                if !response.is_success() {
+                    return Err(ErrorKind::HttpStatus(response.status).into());
+                }
+                let body = Bytes::from(response.body.unwrap_or_default());
+                Ok(stream::once(async move { Ok(body) }).boxed())
+            })
+            .boxed()
+    }
+}
+
+/// Shares a connector, e.g. between several `Api`s.
+impl<C: Connector + ?Sized> Connector for Arc<C> {
+    fn request(
+        &self,
+        token: &str,
+        req: HttpRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, Error>> + Send>> {
+        (**self).request(token, req)
+    }
+
+    fn request_stream(
+        &self,
+        token: &str,
+        req: HttpRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<ByteStream, Error>> + Send>> {
+        (**self).request_stream(token, req)
+    }
 }
 
+/// The hyper connector, using the TLS implementation selected by the `openssl` or
+/// `rustls` feature.
+#[cfg(any(feature = "openssl", feature = "rustls"))]
 pub fn default_connector() -> Box<dyn Connector> {
     hyper::default_connector().unwrap()
 }
+
+/// The reqwest connector with `reqwest::Client::new()`, because neither the `openssl` nor
+/// the `rustls` feature is enabled.
+///
+/// This crate doesn't enable any TLS support of reqwest. The bot has to enable a TLS
+/// feature of reqwest in its own dependency on it, e.g. `rustls-tls` or `native-tls`,
+/// otherwise every request to `https://api.telegram.org` fails.
+#[cfg(all(
+    feature = "reqwest_connector",
+    not(any(feature = "openssl", feature = "rustls"))
+))]
+pub fn default_connector() -> Box<dyn Connector> {
+    reqwest::default_connector().unwrap()
+}
@@ -0,0 +1,368 @@
+//! Connecting to the Bot API through an HTTP or SOCKS5 proxy, see
+//! `hyper::proxy_connector`.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use hyper::service::Service;
+use hyper::Uri;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+const SOCKS5_VERSION: u8 = 5;
+const SOCKS5_NO_AUTHENTICATION: u8 = 0;
+const SOCKS5_PASSWORD_AUTHENTICATION: u8 = 2;
+const SOCKS5_CONNECT: u8 = 1;
+const SOCKS5_DOMAIN_NAME: u8 = 3;
+
+/// A proxy server requests are tunneled through.
+///
+/// # Examples
+///
+/// ```rust
+/// use telegram_bot::connector::proxy::Proxy;
+///
+/// // A local Tor client.
+/// let tor = Proxy::socks5("127.0.0.1", 9050);
+///
+/// let mut proxy = Proxy::http("proxy.example.org", 3128);
+/// proxy.credentials("user", "password");
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Proxy {
+    kind: ProxyKind,
+    host: String,
+    port: u16,
+    credentials: Option<(String, String)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProxyKind {
+    Http,
+    Socks5,
+}
+
+impl Proxy {
+    /// An HTTP proxy, which is asked to open a tunnel with `CONNECT`.
+    pub fn http<H: Into<String>>(host: H, port: u16) -> Self {
+        Proxy {
+            kind: ProxyKind::Http,
+            host: host.into(),
+            port,
+            credentials: None,
+        }
+    }
+
+    /// A SOCKS5 proxy, e.g. of Tor or shadowsocks. Host names are resolved by the proxy.
+    pub fn socks5<H: Into<String>>(host: H, port: u16) -> Self {
+        Proxy {
+            kind: ProxyKind::Socks5,
+            host: host.into(),
+            port,
+            credentials: None,
+        }
+    }
+
+    /// Authenticate with username and password, using basic authentication for HTTP proxies.
+    pub fn credentials<U, P>(&mut self, username: U, password: P) -> &mut Self
+    where
+        U: Into<String>,
+        P: Into<String>,
+    {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+}
+
+impl fmt::Debug for Proxy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Proxy")
+            .field("kind", &self.kind)
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field(
+                "credentials",
+                &self
+                    .credentials
+                    .as_ref()
+                    .map(|(username, _)| (username, "<hidden>")),
+            )
+            .finish()
+    }
+}
+
+/// Opens connections tunneled through a proxy for a hyper client, which wraps them in TLS.
+#[derive(Debug, Clone)]
+pub struct ProxyConnector {
+    proxy: Arc<Proxy>,
+}
+
+impl ProxyConnector {
+    pub fn new(proxy: Proxy) -> Self {
+        ProxyConnector {
+            proxy: Arc::new(proxy),
+        }
+    }
+}
+
+impl Service<Uri> for ProxyConnector {
+    type Response = TcpStream;
+    type Error = io::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<TcpStream, io::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        let proxy = self.proxy.clone();
+        Box::pin(async move {
+            let host = uri
+                .host()
+                .ok_or_else(|| invalid_data("URL without host"))?
+                .to_string();
+            let port = uri.port_u16().unwrap_or(match uri.scheme_str() {
+                Some("http") => 80,
+                _ => 443,
+            });
+
+            let mut stream = TcpStream::connect((proxy.host.as_str(), proxy.port)).await?;
+            match proxy.kind {
+                ProxyKind::Http => http_connect(&mut stream, &proxy, &host, port).await?,
+                ProxyKind::Socks5 => socks5_connect(&mut stream, &proxy, &host, port).await?,
+            }
+            Ok(stream)
+        })
+    }
+}
+
+async fn http_connect<S>(stream: &mut S, proxy: &Proxy, host: &str, port: u16) -> io::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut request = format!(
+        "CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n",
+        host = host,
+        port = port
+    );
+    if let Some((username, password)) = &proxy.credentials {
+        let credentials = base64(format!("{}:{}", username, password).as_bytes());
+        request.push_str(&format!("Proxy-Authorization: Basic {}\r\n", credentials));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    // Read the response head byte by byte, so nothing of the tunneled data is consumed.
+    let mut response = Vec::new();
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() > 8192 {
+            return Err(invalid_data("proxy response too long"));
+        }
+        response.push(stream.read_u8().await?);
+    }
+    let status_line = response.split(|&b| b == b'\r').next().unwrap_or_default();
+    let status = status_line.split(|&b| b == b' ').nth(1);
+    if status != Some(b"200") {
+        let status_line = String::from_utf8_lossy(status_line);
+        return Err(io::Error::other(format!(
+            "proxy refused to connect: {}",
+            status_line
+        )));
+    }
+    Ok(())
+}
+
+async fn socks5_connect<S>(stream: &mut S, proxy: &Proxy, host: &str, port: u16) -> io::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let method = match proxy.credentials {
+        Some(_) => SOCKS5_PASSWORD_AUTHENTICATION,
+        None => SOCKS5_NO_AUTHENTICATION,
+    };
+    stream.write_all(&[SOCKS5_VERSION, 1, method]).await?;
+    let mut reply = [0; 2];
+    stream.read_exact(&mut reply).await?;
+    if reply != [SOCKS5_VERSION, method] {
+        return Err(invalid_data(
+            "SOCKS5 proxy doesn't support the authentication",
+        ));
+    }
+
+    if let Some((username, password)) = &proxy.credentials {
+        let mut request = vec![1];
+        for field in &[username, password] {
+            let length = u8::try_from(field.len())
+                .map_err(|_| invalid_input("SOCKS5 credentials longer than 255 bytes"))?;
+            request.push(length);
+            request.extend_from_slice(field.as_bytes());
+        }
+        stream.write_all(&request).await?;
+        stream.read_exact(&mut reply).await?;
+        if reply[1] != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "SOCKS5 proxy rejected the credentials",
+            ));
+        }
+    }
+
+    let length =
+        u8::try_from(host.len()).map_err(|_| invalid_input("host longer than 255 bytes"))?;
+    let mut request = vec![
+        SOCKS5_VERSION,
+        SOCKS5_CONNECT,
+        0,
+        SOCKS5_DOMAIN_NAME,
+        length,
+    ];
+    request.extend_from_slice(host.as_bytes());
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut reply = [0; 4];
+    stream.read_exact(&mut reply).await?;
+    if reply[1] != 0 {
+        return Err(io::Error::other(format!(
+            "SOCKS5 proxy refused to connect, reply {}",
+            reply[1]
+        )));
+    }
+    // Skip the address the proxy bound, followed by the port.
+    let address_length = match reply[3] {
+        1 => 4,
+        4 => 16,
+        SOCKS5_DOMAIN_NAME => stream.read_u8().await? as usize,
+        _ => return Err(invalid_data("invalid SOCKS5 reply")),
+    };
+    let mut address = vec![0; address_length + 2];
+    stream.read_exact(&mut address).await?;
+    Ok(())
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let triple = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(triple >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"a"), "YQ==");
+        assert_eq!(base64(b"ab"), "YWI=");
+        assert_eq!(base64(b"user:pass"), "dXNlcjpwYXNz");
+    }
+
+    #[tokio::test]
+    async fn socks5_handshake() {
+        let (mut client, mut server) = tokio::io::duplex(1024);
+        let mut proxy = Proxy::socks5("localhost", 1080);
+        proxy.credentials("u", "p");
+        let server_side = async {
+            let mut request = [0; 3];
+            server.read_exact(&mut request).await.unwrap();
+            assert_eq!(request, [5, 1, 2]);
+            server.write_all(&[5, 2]).await.unwrap();
+            let mut request = [0; 5];
+            server.read_exact(&mut request).await.unwrap();
+            assert_eq!(&request, b"\x01\x01u\x01p");
+            server.write_all(&[1, 0]).await.unwrap();
+            let mut request = [0; 23];
+            server.read_exact(&mut request).await.unwrap();
+            assert_eq!(&request, b"\x05\x01\x00\x03\x10api.telegram.org\x01\xbb");
+            server
+                .write_all(&[5, 0, 0, 1, 1, 2, 3, 4, 0, 80])
+                .await
+                .unwrap();
+        };
+        let (result, ()) = futures::join!(
+            socks5_connect(&mut client, &proxy, "api.telegram.org", 443),
+            server_side
+        );
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn http_connect_established() {
+        let (mut client, mut server) = tokio::io::duplex(1024);
+        let mut proxy = Proxy::http("localhost", 3128);
+        proxy.credentials("user", "pass");
+        let expected: &[u8] = b"CONNECT api.telegram.org:443 HTTP/1.1\r\n\
+            Host: api.telegram.org:443\r\n\
+            Proxy-Authorization: Basic dXNlcjpwYXNz\r\n\r\n";
+        let server_side = async {
+            let mut request = vec![0; expected.len()];
+            server.read_exact(&mut request).await.unwrap();
+            assert_eq!(request, expected);
+            server
+                .write_all(b"HTTP/1.1 200 Connection established\r\n\r\ntunneled")
+                .await
+                .unwrap();
+        };
+        let (result, ()) = futures::join!(
+            http_connect(&mut client, &proxy, "api.telegram.org", 443),
+            server_side
+        );
+        result.unwrap();
+
+        let mut tunneled = [0; 8];
+        client.read_exact(&mut tunneled).await.unwrap();
+        assert_eq!(&tunneled, b"tunneled");
+    }
+
+    #[tokio::test]
+    async fn http_connect_rejected() {
+        let (mut client, mut server) = tokio::io::duplex(1024);
+        let proxy = Proxy::http("localhost", 3128);
+        let server_side = async {
+            let mut request = vec![0; 80];
+            let length = server.read(&mut request).await.unwrap();
+            assert_eq!(
+                &request[..length],
+                &b"CONNECT api.telegram.org:443 HTTP/1.1\r\nHost: api.telegram.org:443\r\n\r\n"[..]
+            );
+            server
+                .write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n")
+                .await
+                .unwrap();
+        };
+        let (result, ()) = futures::join!(
+            http_connect(&mut client, &proxy, "api.telegram.org", 443),
+            server_side
+        );
+        assert!(result.is_err());
+    }
+}
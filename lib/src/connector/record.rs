@@ -0,0 +1,216 @@
+//! Recording the requests of a bot with their responses, and replaying them in tests.
+//! Requires the `record-replay` feature.
+//!
+//! Recordings are files with one JSON object per line, holding the method name, the
+//! request body and the response status and body of one request.
+
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use futures::{future, Future, FutureExt};
+use serde_json::{json, Value};
+use telegram_bot_raw::{HttpRequest, HttpResponse};
+
+use super::Connector;
+use crate::errors::{Error, ErrorKind};
+
+/// Sends requests with another connector and appends every request with its response to
+/// a recording, which can be replayed with `ReplayConnector`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use telegram_bot::connector::default_connector;
+/// use telegram_bot::connector::record::RecordingConnector;
+/// use telegram_bot::Api;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let connector = RecordingConnector::new(default_connector(), "tests/echo.jsonl").unwrap();
+/// let api = Api::with_connector("token", Box::new(connector));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RecordingConnector {
+    inner: Box<dyn Connector>,
+    file: Arc<Mutex<File>>,
+}
+
+impl RecordingConnector {
+    /// Record to the file at `path`, replacing a previous recording.
+    pub fn new<P: AsRef<Path>>(inner: Box<dyn Connector>, path: P) -> Result<Self, Error> {
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .map_err(ErrorKind::from)?;
+        Ok(RecordingConnector {
+            inner,
+            file: Arc::new(Mutex::new(file)),
+        })
+    }
+}
+
+impl Connector for RecordingConnector {
+    fn request(
+        &self,
+        token: &str,
+        req: HttpRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, Error>> + Send>> {
+        let method = req.name();
+        let request = req.body.to_string();
+        let response = self.inner.request(token, req);
+        let file = self.file.clone();
+
+        let future = async move {
+            let response = response.await?;
+            let body = response.body.as_deref().unwrap_or_default();
+            let mut exchange = json!({
+                "method": method,
+                "request": request,
+                "status": response.status,
+            });
+            match std::str::from_utf8(body) {
+                Ok(body) => exchange["response"] = Value::from(body),
+                Err(_) => exchange["response_bytes"] = Value::from(body),
+            }
+
+            let mut line = serde_json::to_vec(&exchange).map_err(ErrorKind::from)?;
+            line.push(b'\n');
+            let mut file = file.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            file.write_all(&line).map_err(ErrorKind::from)?;
+            Ok(response)
+        };
+
+        future.boxed()
+    }
+}
+
+/// Answers requests with the responses of a recording made with `RecordingConnector`,
+/// without network access.
+///
+/// The requests must be the same as the recorded ones and arrive in the same order,
+/// otherwise they fail.
+#[derive(Debug, Clone)]
+pub struct ReplayConnector(Arc<Mutex<VecDeque<Exchange>>>);
+
+#[derive(Debug)]
+struct Exchange {
+    method: String,
+    request: String,
+    status: u16,
+    response: Vec<u8>,
+}
+
+impl ReplayConnector {
+    /// Replay the recording in the file at `path`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let recording = std::fs::read_to_string(path).map_err(ErrorKind::from)?;
+        Self::from_recording(&recording)
+    }
+
+    /// Replay a recording, e.g. included in the test with `include_str!`.
+    pub fn from_recording(recording: &str) -> Result<Self, Error> {
+        let mut exchanges = VecDeque::new();
+        for line in recording.lines().filter(|line| !line.trim().is_empty()) {
+            let exchange: Value = serde_json::from_str(line).map_err(ErrorKind::from)?;
+            let field = |name: &str| exchange[name].as_str().map(str::to_string);
+            let response = match (field("response"), &exchange["response_bytes"]) {
+                (Some(response), _) => response.into_bytes(),
+                (None, Value::Array(bytes)) => bytes
+                    .iter()
+                    .filter_map(Value::as_u64)
+                    .map(|byte| byte as u8)
+                    .collect(),
+                _ => return Err(invalid_recording(line)),
+            };
+            let status = exchange["status"]
+                .as_u64()
+                .and_then(|status| u16::try_from(status).ok())
+                .ok_or_else(|| invalid_recording(line))?;
+            exchanges.push_back(Exchange {
+                method: field("method").ok_or_else(|| invalid_recording(line))?,
+                request: field("request").ok_or_else(|| invalid_recording(line))?,
+                status,
+                response,
+            });
+        }
+        Ok(ReplayConnector(Arc::new(Mutex::new(exchanges))))
+    }
+
+    /// True, if all recorded requests were made.
+    pub fn is_finished(&self) -> bool {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .is_empty()
+    }
+}
+
+impl Connector for ReplayConnector {
+    fn request(
+        &self,
+        _token: &str,
+        req: HttpRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, Error>> + Send>> {
+        let mut exchanges = self
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let request = req.body.to_string();
+        let result = match exchanges.front() {
+            Some(exchange) if exchange.method == req.name() && exchange.request == request => {
+                let exchange = exchanges.pop_front().expect("exchange was just seen");
+                Ok(HttpResponse {
+                    status: exchange.status,
+                    body: Some(exchange.response),
+                })
+            }
+            _ => Err(
+                ErrorKind::UnexpectedReplayRequest(format!("{} {}", req.name(), request)).into(),
+            ),
+        };
+        future::ready(result).boxed()
+    }
+}
+
+fn invalid_recording(line: &str) -> Error {
+    ErrorKind::InvalidRecording(line.to_string()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use telegram_bot_raw::{ChatId, GetMe, LeaveChat};
+
+    use super::*;
+    use crate::connector::mock::MockConnector;
+    use crate::Api;
+
+    #[tokio::test]
+    async fn record_and_replay() {
+        let file_name = format!("telegram-bot-record-{}.jsonl", std::process::id());
+        let path = std::env::temp_dir().join(file_name);
+        let mock = MockConnector::new();
+        mock.respond_ok("getMe", r#"{"id": 1, "is_bot": true, "first_name": "Bot"}"#)
+            .respond_ok("leaveChat", "true");
+        let recording = RecordingConnector::new(Box::new(mock), &path).unwrap();
+        let api = Api::with_connector("token", Box::new(recording));
+        api.send(GetMe).await.unwrap();
+        api.send(LeaveChat::new(ChatId::new(5))).await.unwrap();
+
+        let replay = ReplayConnector::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let api = Api::with_connector("token", Box::new(replay.clone()));
+        assert_eq!(api.send(GetMe).await.unwrap().first_name, "Bot");
+        assert!(api.send(LeaveChat::new(ChatId::new(6))).await.is_err());
+        assert!(!replay.is_finished());
+        api.send(LeaveChat::new(ChatId::new(5))).await.unwrap();
+        assert!(replay.is_finished());
+    }
+}
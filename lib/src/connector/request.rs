@@ -0,0 +1,123 @@
+use std::io::{Cursor, Read};
+use std::path::Path;
+use std::str::FromStr;
+
+use bytes::Bytes;
+use futures::Future;
+use hyper::{header::CONTENT_TYPE, http::Error as HttpError, Method, Request, Uri};
+use multipart::client::lazy::Multipart;
+use telegram_bot_raw::{
+    Body as TelegramBody, HttpRequest, Method as TelegramMethod, MultipartValue, Text,
+};
+
+use crate::errors::{Error, ErrorKind};
+
+enum MultipartTemporaryValue {
+    Text(Text),
+    Data { file_name: Text, data: Bytes },
+}
+
+/// Build the HTTP request with the serialized body, reading files to upload from disk.
+pub(crate) fn build_request(
+    token: &str,
+    req: HttpRequest,
+) -> impl Future<Output = Result<Request<Vec<u8>>, Error>> + Send {
+    let uri = Uri::from_str(&req.url.url(token));
+
+    async move {
+        let uri = uri.map_err(HttpError::from).map_err(ErrorKind::from)?;
+
+        let method = match req.method {
+            TelegramMethod::Get => Method::GET,
+            TelegramMethod::Post => Method::POST,
+        };
+
+        let mut http_request = Request::builder().method(method).uri(uri);
+
+        let request = match req.body {
+            TelegramBody::Empty => http_request.body(vec![]),
+            TelegramBody::Json(body) => {
+                let content_type = "application/json"
+                    .parse()
+                    .map_err(HttpError::from)
+                    .map_err(ErrorKind::from)?;
+                if let Some(headers) = http_request.headers_mut() {
+                    headers.insert(CONTENT_TYPE, content_type);
+                }
+                http_request.body(body.into_bytes())
+            }
+            TelegramBody::Multipart(parts) => {
+                let mut fields = Vec::new();
+                for (key, value) in parts {
+                    match value {
+                        MultipartValue::Text(text) => {
+                            fields.push((key, MultipartTemporaryValue::Text(text)))
+                        }
+                        MultipartValue::Path { file_name, path } => {
+                            let file_name = file_name
+                                .or_else(|| {
+                                    AsRef::<Path>::as_ref(&path)
+                                        .file_name()
+                                        .and_then(|s| s.to_str())
+                                        .map(Into::into)
+                                })
+                                .ok_or(ErrorKind::InvalidMultipartFilename)?;
+
+                            let data = tokio::fs::read(path).await.map_err(ErrorKind::from)?;
+                            fields.push((
+                                key,
+                                MultipartTemporaryValue::Data {
+                                    file_name,
+                                    data: data.into(),
+                                },
+                            ))
+                        }
+                        MultipartValue::Data { file_name, data } => {
+                            fields.push((key, MultipartTemporaryValue::Data { file_name, data }))
+                        }
+                    }
+                }
+
+                let mut prepared = {
+                    let mut part = Multipart::new();
+                    for (key, value) in &fields {
+                        match value {
+                            MultipartTemporaryValue::Text(text) => {
+                                part.add_text(*key, text.as_str());
+                            }
+                            MultipartTemporaryValue::Data { file_name, data } => {
+                                part.add_stream(
+                                    *key,
+                                    Cursor::new(data),
+                                    Some(file_name.as_str()),
+                                    None,
+                                );
+                            }
+                        }
+                    }
+                    part.prepare().map_err(|err| err.error)
+                }
+                .map_err(ErrorKind::from)?;
+
+                let boundary = prepared.boundary();
+
+                let content_type =
+                    format!("multipart/form-data;boundary={bound}", bound = boundary)
+                        .parse()
+                        .map_err(HttpError::from)
+                        .map_err(ErrorKind::from)?;
+                if let Some(headers) = http_request.headers_mut() {
+                    headers.insert(CONTENT_TYPE, content_type);
+                }
+
+                let mut bytes = Vec::new();
+                prepared.read_to_end(&mut bytes).map_err(ErrorKind::from)?;
+                http_request.body(bytes)
+            }
+            body => panic!("Unknown body type {:?}", body),
+        }
+        .map_err(ErrorKind::from)?;
+
+        Ok(request)
+    }
+}
@@ -0,0 +1,79 @@
+use std::convert::TryFrom;
+use std::pin::Pin;
+
+use futures::{Future, FutureExt, StreamExt, TryStreamExt};
+use reqwest::Client;
+use telegram_bot_raw::{HttpRequest, HttpResponse};
+
+use super::request::build_request;
+use super::{ByteStream, Connector};
+use crate::errors::{Error, ErrorKind};
+
+/// Connector with reqwest backend, available with the `reqwest_connector` feature.
+///
+/// The TLS implementation is chosen with the features of reqwest, e.g. `rustls-tls` or
+/// `native-tls`, which are enabled by the bot's own dependency on reqwest. Proxies are
+/// configured on the `Client`, with `reqwest::Proxy`.
+#[derive(Debug)]
+pub struct ReqwestConnector(Client);
+
+impl ReqwestConnector {
+    pub fn new(client: Client) -> Self {
+        ReqwestConnector(client)
+    }
+}
+
+impl Connector for ReqwestConnector {
+    fn request(
+        &self,
+        token: &str,
+        req: HttpRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, Error>> + Send>> {
+        let request = build_request(token, req);
+        let client = self.0.clone();
+
+        let future = async move {
+            let request = reqwest::Request::try_from(request.await?).map_err(ErrorKind::from)?;
+            let response = client.execute(request).await.map_err(ErrorKind::from)?;
+            let status = response.status().as_u16();
+            let body = response.bytes().await.map_err(ErrorKind::from)?;
+
+            Ok::<HttpResponse, Error>(HttpResponse {
+                status,
+                body: Some(body.to_vec()),
+            })
+        };
+
+        future.boxed()
+    }
+
+    fn request_stream(
+        &self,
+        token: &str,
+        req: HttpRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<ByteStream, Error>> + Send>> {
+        let request = build_request(token, req);
+        let client = self.0.clone();
+
+        let future = async move {
+            let request = reqwest::Request::try_from(request.await?).map_err(ErrorKind::from)?;
+            let response = client.execute(request).await.map_err(ErrorKind::from)?;
+            if !response.status().is_success() {
+                return Err(ErrorKind::HttpStatus(response.status().as_u16()).into());
+            }
+            let stream = response
+                .bytes_stream()
+                .map_err(|error| Error::from(ErrorKind::from(error)));
+
+            Ok::<ByteStream, Error>(stream.boxed())
+        };
+
+        future.boxed()
+    }
+}
+
+/// A `ReqwestConnector` with `Client::new()`, which needs a TLS feature of reqwest to be
+/// enabled by the bot, see `ReqwestConnector`.
+pub fn default_connector() -> Result<Box<dyn Connector>, Error> {
+    Ok(Box::new(ReqwestConnector::new(Client::new())))
+}
@@ -0,0 +1,178 @@
+use std::future::Future;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+use telegram_bot_raw::{Message, Update, UpdateKind};
+
+use crate::api::Api;
+use crate::errors::Error;
+use crate::session::{SessionKey, SessionStorage};
+
+/// What happens to a conversation after a handler ran.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Transition<S> {
+    /// Continue the conversation in the given state.
+    Next(S),
+    /// Keep the current state, e.g. to ask again after an invalid answer.
+    Stay,
+    /// End the conversation.
+    End,
+}
+
+type StartHandler<S> =
+    Box<dyn Fn(Api, Message) -> BoxFuture<'static, Result<Transition<S>, Error>> + Send + Sync>;
+type StateMatcher<S> = Box<dyn Fn(&S) -> bool + Send + Sync>;
+type StateHandler<S> =
+    Box<dyn Fn(Api, Message, S) -> BoxFuture<'static, Result<Transition<S>, Error>> + Send + Sync>;
+
+/// Multi-step dialogs, implemented as a state machine per user and chat.
+///
+/// New messages are passed to the handler registered for the current state of the
+/// conversation between their sender and chat, which decides about the next state.
+/// Messages from users without a conversation go to the `start` handler. The states are
+/// kept in a `SessionStorage`.
+///
+/// Messages of the same user in the same chat should be dispatched one after another,
+/// otherwise their handlers see the same state.
+///
+/// # Examples
+///
+/// ```rust
+/// # use telegram_bot::{Api, Conversation, MemorySessionStorage, Transition, prelude::*};
+/// use futures::StreamExt;
+///
+/// #[derive(Clone)]
+/// enum Registration {
+///     AskName,
+///     AskAge { name: String },
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), telegram_bot::Error> {
+/// # let api: Api = Api::new("token");
+/// # if false {
+/// let mut conversation = Conversation::new(&api, MemorySessionStorage::new());
+/// conversation
+///     .start(|api, message| async move {
+///         api.send(message.text_reply("What's your name?")).await?;
+///         Ok(Transition::Next(Registration::AskName))
+///     })
+///     .state(
+///         |state| matches!(state, Registration::AskName),
+///         |api, message, _| async move {
+///             let name = message.text().unwrap_or_default();
+///             api.send(message.text_reply("How old are you?")).await?;
+///             Ok(Transition::Next(Registration::AskAge { name }))
+///         },
+///     )
+///     .state(
+///         |state| matches!(state, Registration::AskAge { .. }),
+///         |api, message, state| async move {
+///             if let Registration::AskAge { name } = state {
+///                 let text = format!("Welcome, {}!", name);
+///                 api.send(message.text_reply(text)).await?;
+///             }
+///             Ok(Transition::End)
+///         },
+///     );
+///
+/// let mut stream = api.stream();
+/// while let Some(update) = stream.next().await {
+///     conversation.dispatch(update?).await?;
+/// }
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Conversation<S> {
+    api: Api,
+    storage: Box<dyn SessionStorage<S>>,
+    start: Option<StartHandler<S>>,
+    states: Vec<(StateMatcher<S>, StateHandler<S>)>,
+}
+
+impl<S> Conversation<S>
+where
+    S: Send + 'static,
+{
+    pub fn new<T>(api: &Api, storage: T) -> Self
+    where
+        T: SessionStorage<S> + 'static,
+    {
+        Conversation {
+            api: api.clone(),
+            storage: Box::new(storage),
+            start: None,
+            states: Vec::new(),
+        }
+    }
+
+    /// Call `handler` for messages from users without a conversation. Without a start
+    /// handler, such messages are ignored.
+    pub fn start<H, F>(&mut self, handler: H) -> &mut Self
+    where
+        H: Fn(Api, Message) -> F + Send + Sync + 'static,
+        F: Future<Output = Result<Transition<S>, Error>> + Send + 'static,
+    {
+        self.start = Some(Box::new(move |api, message| handler(api, message).boxed()));
+        self
+    }
+
+    /// Call `handler` for messages in conversations whose state matches `matches`.
+    /// If several handlers match a state, the one registered first is used.
+    pub fn state<M, H, F>(&mut self, matches: M, handler: H) -> &mut Self
+    where
+        M: Fn(&S) -> bool + Send + Sync + 'static,
+        H: Fn(Api, Message, S) -> F + Send + Sync + 'static,
+        F: Future<Output = Result<Transition<S>, Error>> + Send + 'static,
+    {
+        self.states.push((
+            Box::new(matches),
+            Box::new(move |api, message, state| handler(api, message, state).boxed()),
+        ));
+        self
+    }
+
+    /// Run the handler for the update, if it is a new message. Returns whether a handler
+    /// was run, and the error of the handler or the storage.
+    pub async fn dispatch(&self, update: Update) -> Result<bool, Error> {
+        match update.kind {
+            UpdateKind::Message(message) => self.dispatch_message(message).await,
+            _ => Ok(false),
+        }
+    }
+
+    /// Run the handler for the current state of the conversation the message belongs to.
+    /// Returns whether a handler was run, and the error of the handler or the storage.
+    pub async fn dispatch_message(&self, message: Message) -> Result<bool, Error> {
+        let key = SessionKey::new(message.chat.id(), message.from.id);
+        let transition = match self.storage.get(key).await? {
+            Some(state) => {
+                let handler = self
+                    .states
+                    .iter()
+                    .find(|(matches, _)| matches(&state))
+                    .map(|(_, handler)| handler);
+                match handler {
+                    Some(handler) => handler(self.api.clone(), message, state).await?,
+                    None => {
+                        tracing::warn!(key = ?key, "no handler for the state of the conversation");
+                        return Ok(false);
+                    }
+                }
+            }
+            None => match self.start {
+                Some(ref start) => start(self.api.clone(), message).await?,
+                None => return Ok(false),
+            },
+        };
+
+        match transition {
+            Transition::Next(state) => self.storage.set(key, state).await?,
+            Transition::Stay => {}
+            Transition::End => self.storage.remove(key).await?,
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use crate::errors::Error;
+
+const DEFAULT_INITIAL_DELAY_MILLISECONDS: u64 = 500;
+const DEFAULT_MAX_DELAY_SECONDS: u64 = 60;
+
+/// Decides how `UpdatesStream` proceeds after a failed `getUpdates` request.
+pub trait ErrorPolicy: Send {
+    /// Returns the delay before the next request, or `None` to end the stream.
+    ///
+    /// `attempt` is the number of consecutive failed requests, starting at 1.
+    /// It is reset once a request succeeds.
+    fn retry_delay(&mut self, error: &Error, attempt: u32) -> Option<Duration>;
+}
+
+impl<F> ErrorPolicy for F
+where
+    F: FnMut(&Error, u32) -> Option<Duration> + Send,
+{
+    fn retry_delay(&mut self, error: &Error, attempt: u32) -> Option<Duration> {
+        self(error, attempt)
+    }
+}
+
+/// Retries after the same delay after every error, forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedDelay(pub Duration);
+
+impl ErrorPolicy for FixedDelay {
+    fn retry_delay(&mut self, _error: &Error, _attempt: u32) -> Option<Duration> {
+        Some(self.0)
+    }
+}
+
+/// Retries with a jittered delay that doubles after every consecutive error.
+///
+/// The delay for an attempt is picked randomly between half and the full
+/// `initial_delay * 2^(attempt - 1)`, capped at `max_delay`. By default the first
+/// retry happens after 250–500 ms, the delay is capped at 60 seconds and retries
+/// never stop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExponentialBackoff {
+    initial_delay: Duration,
+    max_delay: Duration,
+    max_attempts: Option<u32>,
+}
+
+impl ExponentialBackoff {
+    pub fn new(initial_delay: Duration, max_delay: Duration) -> Self {
+        ExponentialBackoff {
+            initial_delay,
+            max_delay,
+            max_attempts: None,
+        }
+    }
+
+    /// End the stream after this many consecutive failed requests.
+    pub fn max_attempts(&mut self, max_attempts: u32) -> &mut Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .initial_delay
+            .checked_mul(1 << exponent)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay));
+
+        let half = delay / 2;
+        let jitter = random() % (half.as_nanos() as u64 + 1);
+        half + Duration::from_nanos(jitter)
+    }
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        ExponentialBackoff::new(
+            Duration::from_millis(DEFAULT_INITIAL_DELAY_MILLISECONDS),
+            Duration::from_secs(DEFAULT_MAX_DELAY_SECONDS),
+        )
+    }
+}
+
+impl ErrorPolicy for ExponentialBackoff {
+    fn retry_delay(&mut self, _error: &Error, attempt: u32) -> Option<Duration> {
+        match self.max_attempts {
+            Some(max_attempts) if attempt >= max_attempts => None,
+            _ => Some(self.delay(attempt)),
+        }
+    }
+}
+
+/// Randomly seeded hashers are good enough as a source of jitter.
+fn random() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exponential_backoff_delay() {
+        let backoff = ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(10));
+
+        for _ in 0..100 {
+            let first = backoff.delay(1);
+            assert!(first >= Duration::from_millis(500) && first <= Duration::from_secs(1));
+
+            let third = backoff.delay(3);
+            assert!(third >= Duration::from_secs(2) && third <= Duration::from_secs(4));
+
+            let capped = backoff.delay(40);
+            assert!(capped >= Duration::from_secs(5) && capped <= Duration::from_secs(10));
+        }
+    }
+}
@@ -1,5 +1,10 @@
 use std::error;
 use std::fmt;
+use std::time::Duration;
+
+use telegram_bot_raw::Integer;
+
+const FORBIDDEN_ERROR_CODE: Integer = 403;
 
 #[derive(Debug)]
 pub struct Error(ErrorKind);
@@ -10,7 +15,61 @@ pub(crate) enum ErrorKind {
     Hyper(hyper::Error),
     Http(hyper::http::Error),
     Io(std::io::Error),
+    #[cfg(feature = "reqwest_connector")]
+    Reqwest(reqwest::Error),
     InvalidMultipartFilename,
+    MissingFilePath,
+    MissingBotUsername,
+    HttpStatus(u16),
+    UnsupportedWebhookReply,
+    #[cfg(feature = "passport")]
+    Crypto(openssl_crypto::error::ErrorStack),
+    #[cfg(any(
+        feature = "passport",
+        feature = "session-sled",
+        feature = "session-redis",
+        feature = "record-replay"
+    ))]
+    Json(serde_json::Error),
+    #[cfg(feature = "passport")]
+    InvalidPassportData,
+    #[cfg(feature = "session-sled")]
+    Sled(sled::Error),
+    #[cfg(feature = "record-replay")]
+    InvalidRecording(String),
+    #[cfg(feature = "record-replay")]
+    UnexpectedReplayRequest(String),
+    #[cfg(feature = "session-redis")]
+    Redis(redis::RedisError),
+}
+
+impl Error {
+    /// Error code of a request rejected by Telegram, which corresponds to the HTTP
+    /// status code, or the HTTP status code of a failed download.
+    pub fn error_code(&self) -> Option<Integer> {
+        match &self.0 {
+            ErrorKind::Raw(error) => error.error_code(),
+            ErrorKind::HttpStatus(status) => Some(Integer::from(*status)),
+            _ => None,
+        }
+    }
+
+    /// True, if Telegram refused the request because the bot may not write to the chat,
+    /// e.g. because the user blocked the bot or the bot was removed from the group.
+    pub fn is_forbidden(&self) -> bool {
+        self.error_code() == Some(FORBIDDEN_ERROR_CODE)
+    }
+
+    /// In case of exceeding flood control, the time left to wait before the request
+    /// can be repeated.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match &self.0 {
+            ErrorKind::Raw(error) => error
+                .retry_after()
+                .map(|seconds| Duration::from_secs(seconds.max(0) as u64)),
+            _ => None,
+        }
+    }
 }
 
 impl From<telegram_bot_raw::Error> for ErrorKind {
@@ -37,6 +96,46 @@ impl From<std::io::Error> for ErrorKind {
     }
 }
 
+#[cfg(feature = "reqwest_connector")]
+impl From<reqwest::Error> for ErrorKind {
+    fn from(error: reqwest::Error) -> Self {
+        ErrorKind::Reqwest(error)
+    }
+}
+
+#[cfg(feature = "passport")]
+impl From<openssl_crypto::error::ErrorStack> for ErrorKind {
+    fn from(error: openssl_crypto::error::ErrorStack) -> Self {
+        ErrorKind::Crypto(error)
+    }
+}
+
+#[cfg(any(
+    feature = "passport",
+    feature = "session-sled",
+    feature = "session-redis",
+    feature = "record-replay"
+))]
+impl From<serde_json::Error> for ErrorKind {
+    fn from(error: serde_json::Error) -> Self {
+        ErrorKind::Json(error)
+    }
+}
+
+#[cfg(feature = "session-sled")]
+impl From<sled::Error> for ErrorKind {
+    fn from(error: sled::Error) -> Self {
+        ErrorKind::Sled(error)
+    }
+}
+
+#[cfg(feature = "session-redis")]
+impl From<redis::RedisError> for ErrorKind {
+    fn from(error: redis::RedisError) -> Self {
+        ErrorKind::Redis(error)
+    }
+}
+
 impl From<ErrorKind> for Error {
     fn from(kind: ErrorKind) -> Self {
         Error(kind)
@@ -50,7 +149,41 @@ impl fmt::Display for Error {
             ErrorKind::Hyper(error) => write!(f, "{}", error),
             ErrorKind::Http(error) => write!(f, "{}", error),
             ErrorKind::Io(error) => write!(f, "{}", error),
+            #[cfg(feature = "reqwest_connector")]
+            ErrorKind::Reqwest(error) => write!(f, "{}", error),
             ErrorKind::InvalidMultipartFilename => write!(f, "invalid multipart filename"),
+            ErrorKind::MissingFilePath => write!(f, "file has no file_path to download from"),
+            ErrorKind::MissingBotUsername => write!(f, "getMe returned no username for the bot"),
+            ErrorKind::HttpStatus(status) => {
+                write!(f, "request failed with HTTP status {}", status)
+            }
+            ErrorKind::UnsupportedWebhookReply => {
+                write!(
+                    f,
+                    "request can't be sent in the response to a webhook request"
+                )
+            }
+            #[cfg(feature = "passport")]
+            ErrorKind::Crypto(error) => write!(f, "{}", error),
+            #[cfg(any(
+                feature = "passport",
+                feature = "session-sled",
+                feature = "session-redis",
+                feature = "record-replay"
+            ))]
+            ErrorKind::Json(error) => write!(f, "{}", error),
+            #[cfg(feature = "passport")]
+            ErrorKind::InvalidPassportData => write!(f, "passport data failed authentication"),
+            #[cfg(feature = "session-sled")]
+            ErrorKind::Sled(error) => write!(f, "{}", error),
+            #[cfg(feature = "record-replay")]
+            ErrorKind::InvalidRecording(line) => write!(f, "invalid recorded request: {}", line),
+            #[cfg(feature = "record-replay")]
+            ErrorKind::UnexpectedReplayRequest(request) => {
+                write!(f, "request differs from the recording: {}", request)
+            }
+            #[cfg(feature = "session-redis")]
+            ErrorKind::Redis(error) => write!(f, "{}", error),
         }
     }
 }
@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use telegram_bot_raw::HttpRequest;
+
+use crate::errors::Error;
+
+/// Hooks around every request sent by `Api`, see `Config::interceptor`.
+///
+/// Interceptors see requests after they were serialized, so they work for requests of
+/// any type. Use `HttpRequest::set_param` to change a parameter.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use telegram_bot::{Error, HttpRequest, Interceptor};
+///
+/// struct Silent;
+///
+/// impl Interceptor for Silent {
+///     fn before_request(&self, request: &mut HttpRequest) {
+///         if request.name().starts_with("send") {
+///             request.set_param("disable_notification", true.into());
+///         }
+///     }
+///
+///     fn after_response(&self, method: &str, result: Result<(), &Error>, elapsed: Duration) {
+///         println!("{} took {:?}: {:?}", method, elapsed, result);
+///     }
+/// }
+/// ```
+pub trait Interceptor: Send + Sync {
+    /// Called before the request is sent, and may modify it.
+    fn before_request(&self, _request: &mut HttpRequest) {}
+
+    /// Called once the response was parsed or the request failed, with the time it took.
+    fn after_response(&self, _method: &str, _result: Result<(), &Error>, _elapsed: Duration) {}
+}
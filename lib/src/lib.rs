@@ -1,18 +1,55 @@
 //! This crate helps writing bots for the messenger Telegram.
 //! See [readme](https://github.com/telegram-rs/telegram-bot) for details.
 
+mod acknowledge;
 mod api;
+mod chat_action;
+mod config;
+mod conversation;
+mod error_policy;
 mod errors;
+mod interceptor;
 mod macros;
+mod middleware;
+mod offset_storage;
+mod rate_limit;
+mod router;
+mod send_queue;
+mod shutdown;
+mod split;
 mod stream;
 
 pub mod connector;
+#[cfg(feature = "passport")]
+pub mod passport;
 pub mod prelude;
+pub mod session;
+pub mod testing;
 pub mod types;
 pub mod util;
+pub mod webhook;
 
+pub use self::acknowledge::{AckHandle, AckUpdatesStream};
 pub use self::api::Api;
+pub use self::chat_action::ChatActionGuard;
+pub use self::config::Config;
+pub use self::conversation::{Conversation, Transition};
+pub use self::error_policy::{ErrorPolicy, ExponentialBackoff, FixedDelay};
 pub use self::errors::Error;
+pub use self::interceptor::Interceptor;
+pub use self::middleware::Middleware;
+pub use self::offset_storage::{FileOffsetStorage, MemoryOffsetStorage, OffsetStorage};
+pub use self::rate_limit::RateLimits;
+pub use self::router::CommandRouter;
+#[cfg(feature = "session-redis")]
+pub use self::session::RedisSessionStorage;
+#[cfg(feature = "session-sled")]
+pub use self::session::SledSessionStorage;
+pub use self::session::{MemorySessionStorage, SessionKey, SessionStorage};
+pub use self::shutdown::ShutdownHandle;
+pub use self::split::{KindStream, SplitUpdates};
 pub use prelude::*;
-pub use stream::UpdatesStream;
+pub use stream::{UpdateGap, UpdatesStream};
 pub use types::*;
+pub use util::formatting::{escape_html, escape_markdown_v2, split_text, TextBuilder};
+pub use util::streams::{Command, TextMessage};
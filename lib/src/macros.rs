@@ -1,10 +1,14 @@
 /// Create a reply markup.
 ///
+/// Callback data given as a string literal is checked at compile time against
+/// Telegram's limit of 64 bytes.
+///
 /// # Examples
 /// ```
 /// # #[macro_use] extern crate telegram_bot;
 /// # fn main() {
 /// let reply_keyboard = reply_markup!(reply_keyboard, selective, one_time, resize,
+///     placeholder "Pick one",
 ///     ["button", "button"],
 ///     ["button" contact], // Request contact
 ///     ["button" location] // Request location
@@ -18,9 +22,18 @@
 ///
 /// let inline_keyboard = reply_markup!(inline_keyboard,
 ///     ["button" callback "0,0", "button" callback "0,1"],
-///     ["button" callback "1,0", "button" callback "1,1", "button" callback "1,2"]
+///     ["button" callback "1,0", "button" callback "1,1", "button" callback "1,2"],
+///     ["button" url "https://example.com", "button" login_url "https://example.com/login"],
+///     ["button" switch_inline "query", "button" switch_inline_current "query"],
+///     ["button" web_app "https://example.com/app"]
+/// );
+///
+/// // Pay buttons take no argument and must come first in invoice messages.
+/// let invoice_keyboard = reply_markup!(inline_keyboard,
+///     ["Pay" pay, "button" callback "cancel"]
 /// );
 ///
+/// # drop(invoice_keyboard);
 /// # drop(inline_keyboard);
 /// # drop(selective_force_reply);
 /// # drop(force_reply);
@@ -67,6 +80,12 @@ macro_rules! reply_markup {
         keyboard
     });
 
+    (_reply_keyboard, placeholder $placeholder:tt, $($content:tt)*) => ({
+        let mut keyboard = reply_markup!(_reply_keyboard, $($content)*);
+        keyboard.input_field_placeholder($placeholder);
+        keyboard
+    });
+
     (_reply_keyboard, selective, $($content:tt)*) => ({
         let mut keyboard = reply_markup!(_reply_keyboard, $($content)*);
         keyboard.selective();
@@ -108,20 +127,48 @@ macro_rules! reply_markup {
         $crate::InlineKeyboardMarkup::from(vec![$(reply_markup![_inline_keyboard_row, $($content)*]), *])
     );
 
+    (_inline_keyboard_row, ($($acc:tt)*); $text:tt pay) => (
+        vec![$($acc)* reply_markup!(_inline_keyboard_button, pay, $text)]
+    );
+    (_inline_keyboard_row, ($($acc:tt)*); $text:tt pay, $($remaining:tt)*) => (
+        reply_markup!(_inline_keyboard_row, ($($acc)* reply_markup!(_inline_keyboard_button, pay, $text),); $($remaining)*)
+    );
     (_inline_keyboard_row, ($($acc:tt)*); $text:tt $request:tt $callback:tt) => (
         vec![$($acc)* reply_markup!(_inline_keyboard_button, $request,  $text, $callback)]
     );
+    (_inline_keyboard_row, ($($acc:tt)*); $text:tt $request:tt $callback:tt, $($remaining:tt)*) => (
+        reply_markup!(_inline_keyboard_row, ($($acc)* reply_markup!(_inline_keyboard_button, $request, $text, $callback),); $($remaining)*)
+    );
     (_inline_keyboard_row, $($text:tt $request:tt $callback:tt), *) => (
         vec![$(reply_markup!(_inline_keyboard_button, $request, $text, $callback)), *]
     );
     (_inline_keyboard_row, $($content:tt)*) => (reply_markup!(_inline_keyboard_row, (); $($content)*));
 
+    (_inline_keyboard_button, callback, $text:expr, $callback:literal) => ({
+        const _: () = assert!($callback.len() <= 64, "callback data must be at most 64 bytes");
+        $crate::InlineKeyboardButton::callback($text, $callback)
+    });
     (_inline_keyboard_button, callback, $text:expr, $callback:expr) => (
         $crate::InlineKeyboardButton::callback($text, $callback)
     );
     (_inline_keyboard_button, url, $text:expr, $url:expr) => (
         $crate::InlineKeyboardButton::url($text, $url)
     );
+    (_inline_keyboard_button, login_url, $text:expr, $url:expr) => (
+        $crate::InlineKeyboardButton::login_url($text, $url)
+    );
+    (_inline_keyboard_button, switch_inline, $text:expr, $query:expr) => (
+        $crate::InlineKeyboardButton::switch_inline_query($text, $query)
+    );
+    (_inline_keyboard_button, switch_inline_current, $text:expr, $query:expr) => (
+        $crate::InlineKeyboardButton::switch_inline_query_current_chat($text, $query)
+    );
+    (_inline_keyboard_button, web_app, $text:expr, $url:expr) => (
+        $crate::InlineKeyboardButton::web_app($text, $url)
+    );
+    (_inline_keyboard_button, pay, $text:expr) => (
+        $crate::InlineKeyboardButton::pay($text)
+    );
 }
 
 #[cfg(test)]
@@ -228,6 +275,17 @@ mod tests {
                 ["spam"]
             )
         );
+
+        keyboard.input_field_placeholder("eggs");
+        assert_eq!(
+            keyboard,
+            reply_markup!(
+                reply_keyboard, resize, placeholder "eggs", selective, one_time, [],
+                ["foo", "bar"], ["baz"],
+                ["contact" contact, "location" location],
+                ["spam"]
+            )
+        );
     }
 
     #[test]
@@ -247,5 +305,34 @@ mod tests {
             markup,
             reply_markup!(inline_keyboard, [], ["foo" callback "bar", "baz" callback "quux"])
         );
+
+        {
+            let row = markup.add_empty_row();
+            row.push(InlineKeyboardButton::pay("pay"));
+            row.push(InlineKeyboardButton::url("url", "https://example.com"));
+            row.push(InlineKeyboardButton::login_url(
+                "login",
+                "https://example.com",
+            ));
+            row.push(InlineKeyboardButton::switch_inline_query("inline", "q"));
+        }
+        assert_eq!(
+            markup,
+            reply_markup!(inline_keyboard, [], ["foo" callback "bar", "baz" callback "quux"],
+                ["pay" pay, "url" url "https://example.com",
+                 "login" login_url "https://example.com", "inline" switch_inline "q"])
+        );
+
+        markup.add_empty_row().push(InlineKeyboardButton::web_app(
+            "app",
+            "https://example.com/app",
+        ));
+        assert_eq!(
+            markup,
+            reply_markup!(inline_keyboard, [], ["foo" callback "bar", "baz" callback "quux"],
+                ["pay" pay, "url" url "https://example.com",
+                 "login" login_url "https://example.com", "inline" switch_inline "q"],
+                ["app" web_app "https://example.com/app"])
+        );
     }
 }
@@ -0,0 +1,49 @@
+use futures::future::{self, BoxFuture};
+
+use telegram_bot_raw::Update;
+
+use crate::api::Api;
+
+/// Processes incoming updates before they are yielded by `UpdatesStream`, see
+/// `Config::middleware`.
+///
+/// A middleware may pass the update on unchanged, e.g. to record metrics, modify it,
+/// or drop it by returning `None`, e.g. to ignore users who are not allowed to use
+/// the bot. Dropped updates are not passed to later middlewares and never yielded.
+///
+/// # Examples
+///
+/// ```rust
+/// use futures::future::{self, BoxFuture};
+/// use telegram_bot::{Api, Middleware, Update, UserId};
+///
+/// struct AuthLayer {
+///     admins: Vec<UserId>,
+/// }
+///
+/// impl Middleware for AuthLayer {
+///     fn process<'a>(&'a self, _api: &'a Api, update: Update) -> BoxFuture<'a, Option<Update>> {
+///         let allowed = match update.kind.clone().into_message() {
+///             Some(message) => match message.from() {
+///                 Some(user) => self.admins.contains(&user.id),
+///                 None => false,
+///             },
+///             None => true,
+///         };
+///         Box::pin(future::ready(if allowed { Some(update) } else { None }))
+///     }
+/// }
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Returns the processed update, or `None` to drop it.
+    fn process<'a>(&'a self, api: &'a Api, update: Update) -> BoxFuture<'a, Option<Update>>;
+}
+
+impl<F> Middleware for F
+where
+    F: Fn(Update) -> Option<Update> + Send + Sync,
+{
+    fn process<'a>(&'a self, _api: &'a Api, update: Update) -> BoxFuture<'a, Option<Update>> {
+        Box::pin(future::ready(self(update)))
+    }
+}
@@ -0,0 +1,108 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use telegram_bot_raw::Integer;
+
+use crate::errors::{Error, ErrorKind};
+
+/// Persists the id of the last update confirmed by `UpdatesStream`, so that a restarted
+/// bot continues right after it instead of reprocessing or skipping updates.
+///
+/// An update is confirmed once the stream has yielded it and requests the updates
+/// following it. Both methods are called from `Stream::poll_next` and should be fast.
+pub trait OffsetStorage: Send {
+    /// Returns the id of the last confirmed update, or `None` if nothing was stored yet.
+    fn load(&mut self) -> Result<Option<Integer>, Error>;
+
+    /// Remembers `update_id` as the id of the last confirmed update.
+    fn store(&mut self, update_id: Integer) -> Result<(), Error>;
+}
+
+/// Keeps the last confirmed update id in memory.
+///
+/// Clones share the stored id, so a clone can be given to a new stream to continue
+/// where a previous stream in the same process stopped.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryOffsetStorage {
+    update_id: Arc<Mutex<Option<Integer>>>,
+}
+
+impl MemoryOffsetStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The last stored update id.
+    pub fn get(&self) -> Option<Integer> {
+        *self.update_id.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl OffsetStorage for MemoryOffsetStorage {
+    fn load(&mut self) -> Result<Option<Integer>, Error> {
+        Ok(self.get())
+    }
+
+    fn store(&mut self, update_id: Integer) -> Result<(), Error> {
+        *self.update_id.lock().unwrap_or_else(|err| err.into_inner()) = Some(update_id);
+        Ok(())
+    }
+}
+
+/// Keeps the last confirmed update id in a file.
+///
+/// The file contains the id in decimal. It is replaced atomically on every store,
+/// so it is never left half-written.
+#[derive(Debug, Clone)]
+pub struct FileOffsetStorage {
+    path: PathBuf,
+}
+
+impl FileOffsetStorage {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        FileOffsetStorage { path: path.into() }
+    }
+}
+
+impl OffsetStorage for FileOffsetStorage {
+    fn load(&mut self) -> Result<Option<Integer>, Error> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(ErrorKind::from(err).into()),
+        };
+        let update_id = content
+            .trim()
+            .parse()
+            .map_err(|err| ErrorKind::from(io::Error::new(io::ErrorKind::InvalidData, err)))?;
+        Ok(Some(update_id))
+    }
+
+    fn store(&mut self, update_id: Integer) -> Result<(), Error> {
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+        fs::write(&temporary, update_id.to_string()).map_err(ErrorKind::from)?;
+        fs::rename(&temporary, &self.path).map_err(ErrorKind::from)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_offset_storage() {
+        let path = std::env::temp_dir().join(format!("telegram-bot-offset-{}", std::process::id()));
+        let mut storage = FileOffsetStorage::new(&path);
+
+        assert_eq!(storage.load().unwrap(), None);
+        storage.store(42).unwrap();
+        storage.store(43).unwrap();
+        assert_eq!(FileOffsetStorage::new(&path).load().unwrap(), Some(43));
+
+        fs::remove_file(&path).unwrap();
+    }
+}
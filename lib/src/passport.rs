@@ -0,0 +1,105 @@
+//! Decryption of Telegram Passport data, available with the `passport` feature.
+//!
+//! Telegram encrypts the credentials of a `PassportData` with the bot's public key.
+//! Once the credentials are decrypted with `PassportDecrypter`, they provide the
+//! secrets needed to decrypt the `data` of every element with `decrypt_data` and
+//! the downloaded files with `decrypt_file`.
+
+use openssl_crypto::base64;
+use openssl_crypto::hash::{hash, MessageDigest};
+use openssl_crypto::pkey::Private;
+use openssl_crypto::rsa::{Padding, Rsa};
+use openssl_crypto::symm::{Cipher, Crypter, Mode};
+use telegram_bot_raw::{Credentials, DataCredentials, EncryptedCredentials, FileCredentials};
+
+use crate::errors::{Error, ErrorKind};
+
+/// Decrypts Telegram Passport credentials using the bot's private key.
+pub struct PassportDecrypter {
+    key: Rsa<Private>,
+}
+
+impl PassportDecrypter {
+    /// Creates a decrypter from a PEM-encoded RSA private key.
+    pub fn from_pem(pem: &[u8]) -> Result<Self, Error> {
+        let key = Rsa::private_key_from_pem(pem).map_err(ErrorKind::from)?;
+        Ok(PassportDecrypter { key })
+    }
+
+    /// Decrypts and authenticates the credentials shared with the bot. Check the `nonce`
+    /// of the result against the one used in the authorization request.
+    pub fn decrypt_credentials(
+        &self,
+        credentials: &EncryptedCredentials,
+    ) -> Result<Credentials, Error> {
+        let encrypted_secret = decode(&credentials.secret)?;
+        let mut secret = vec![0; self.key.size() as usize];
+        let len = self
+            .key
+            .private_decrypt(&encrypted_secret, &mut secret, Padding::PKCS1_OAEP)
+            .map_err(ErrorKind::from)?;
+        secret.truncate(len);
+
+        let data = decrypt(
+            &decode(&credentials.data)?,
+            &secret,
+            &decode(&credentials.hash)?,
+        )?;
+        Ok(serde_json::from_slice(&data).map_err(ErrorKind::from)?)
+    }
+}
+
+/// Decrypts the base64-encoded `data` field of an `EncryptedPassportElement`.
+/// The result is the JSON-serialized element data.
+pub fn decrypt_data(credentials: &DataCredentials, data: &str) -> Result<Vec<u8>, Error> {
+    decrypt(
+        &decode(data)?,
+        &decode(&credentials.secret)?,
+        &decode(&credentials.data_hash)?,
+    )
+}
+
+/// Decrypts the contents of a downloaded `PassportFile`.
+pub fn decrypt_file(credentials: &FileCredentials, file: &[u8]) -> Result<Vec<u8>, Error> {
+    decrypt(
+        file,
+        &decode(&credentials.secret)?,
+        &decode(&credentials.file_hash)?,
+    )
+}
+
+fn decode(data: &str) -> Result<Vec<u8>, Error> {
+    Ok(base64::decode_block(data).map_err(ErrorKind::from)?)
+}
+
+fn decrypt(data: &[u8], secret: &[u8], data_hash: &[u8]) -> Result<Vec<u8>, Error> {
+    let secret_hash =
+        hash(MessageDigest::sha512(), &[secret, data_hash].concat()).map_err(ErrorKind::from)?;
+    let (key, iv) = (&secret_hash[..32], &secret_hash[32..48]);
+
+    let cipher = Cipher::aes_256_cbc();
+    let mut crypter =
+        Crypter::new(cipher, Mode::Decrypt, key, Some(iv)).map_err(ErrorKind::from)?;
+    crypter.pad(false);
+    let mut decrypted = vec![0; data.len() + cipher.block_size()];
+    let mut len = crypter
+        .update(data, &mut decrypted)
+        .map_err(ErrorKind::from)?;
+    len += crypter
+        .finalize(&mut decrypted[len..])
+        .map_err(ErrorKind::from)?;
+    decrypted.truncate(len);
+
+    let decrypted_hash = hash(MessageDigest::sha256(), &decrypted).map_err(ErrorKind::from)?;
+    if &*decrypted_hash != data_hash {
+        return Err(ErrorKind::InvalidPassportData.into());
+    }
+
+    // The first byte holds the length of the random padding prepended to the data.
+    match decrypted.first() {
+        Some(&padding) if usize::from(padding) <= decrypted.len() => {
+            Ok(decrypted.split_off(usize::from(padding)))
+        }
+        _ => Err(ErrorKind::InvalidPassportData.into()),
+    }
+}
@@ -4,27 +4,59 @@
 
 pub use telegram_bot_raw::CanAnswerCallbackQuery;
 pub use telegram_bot_raw::CanAnswerInlineQuery;
-pub use telegram_bot_raw::CanExportChatInviteLink;
+pub use telegram_bot_raw::CanGetUserChatBoosts;
 pub use telegram_bot_raw::CanLeaveChat;
 pub use telegram_bot_raw::CanSendChatAction;
-pub use telegram_bot_raw::{CanDeleteMessage, CanForwardMessage};
-pub use telegram_bot_raw::{CanEditMessageCaption, CanEditMessageReplyMarkup, CanEditMessageText};
+pub use telegram_bot_raw::CanSetChatPermissions;
+pub use telegram_bot_raw::CanSetMessageReaction;
+pub use telegram_bot_raw::{CanAnswerPreCheckoutQuery, CanAnswerShippingQuery};
+pub use telegram_bot_raw::{CanApproveChatJoinRequest, CanDeclineChatJoinRequest};
+pub use telegram_bot_raw::{
+    CanCloseForumTopic, CanCreateForumTopic, CanDeleteForumTopic, CanEditForumTopic,
+    CanReopenForumTopic, CanUnpinAllForumTopicMessages,
+};
+pub use telegram_bot_raw::{CanCopyMessage, CanDeleteMessage, CanForwardMessage};
+pub use telegram_bot_raw::{
+    CanCreateChatInviteLink, CanEditChatInviteLink, CanExportChatInviteLink,
+    CanRevokeChatInviteLink,
+};
+pub use telegram_bot_raw::{
+    CanDeleteChatPhoto, CanSetChatDescription, CanSetChatPhoto, CanSetChatTitle,
+};
+pub use telegram_bot_raw::{CanDeleteStickerFromSet, CanSetStickerPositionInSet};
+pub use telegram_bot_raw::{
+    CanEditMessageCaption, CanEditMessageMedia, CanEditMessageReplyMarkup, CanEditMessageText,
+};
 pub use telegram_bot_raw::{CanEditMessageLiveLocation, CanStopMessageLiveLocation};
 pub use telegram_bot_raw::{CanGetChat, CanGetChatAdministrators, CanGetChatMembersCount};
 pub use telegram_bot_raw::{CanGetChatMemberForChat, CanGetChatMemberForUser};
+pub use telegram_bot_raw::{CanGetChatMenuButton, CanSetChatMenuButton};
 pub use telegram_bot_raw::{CanGetFile, CanGetUserProfilePhotos};
+pub use telegram_bot_raw::{CanGetGameHighScores, CanSetGameScore};
 pub use telegram_bot_raw::{CanKickChatMemberForChat, CanKickChatMemberForUser};
-pub use telegram_bot_raw::{CanPinMessage, CanUnpinMessage};
+pub use telegram_bot_raw::{
+    CanPinMessage, CanUnpinAllMessages, CanUnpinMessage, CanUnpinMessageForMessage,
+};
+pub use telegram_bot_raw::{CanPromoteChatMemberForChat, CanPromoteChatMemberForUser};
+pub use telegram_bot_raw::{CanReplySendAnimation, CanSendAnimation};
 pub use telegram_bot_raw::{CanReplySendAudio, CanSendAudio};
 pub use telegram_bot_raw::{CanReplySendContact, CanSendContact};
+pub use telegram_bot_raw::{CanReplySendDice, CanSendDice};
 pub use telegram_bot_raw::{CanReplySendDocument, CanSendDocument};
+pub use telegram_bot_raw::{CanReplySendGame, CanSendGame};
 pub use telegram_bot_raw::{CanReplySendLocation, CanSendLocation};
+pub use telegram_bot_raw::{CanReplySendMediaGroup, CanSendMediaGroup};
 pub use telegram_bot_raw::{CanReplySendMessage, CanSendMessage};
 pub use telegram_bot_raw::{CanReplySendPhoto, CanSendPhoto};
 pub use telegram_bot_raw::{CanReplySendPoll, CanSendPoll, CanStopPoll};
+pub use telegram_bot_raw::{CanReplySendSticker, CanSendSticker};
 pub use telegram_bot_raw::{CanReplySendVenue, CanSendVenue};
 pub use telegram_bot_raw::{CanReplySendVideo, CanSendVideo};
+pub use telegram_bot_raw::{CanReplySendVideoNote, CanSendVideoNote};
+pub use telegram_bot_raw::{CanReplySendVoice, CanSendVoice};
+pub use telegram_bot_raw::{CanRestrictChatMemberForChat, CanRestrictChatMemberForUser};
 pub use telegram_bot_raw::{CanUnbanChatMemberForChat, CanUnbanChatMemberForUser};
 pub use telegram_bot_raw::{ToReplyRequest, ToRequest};
 
-pub use crate::util::messages::{MessageGetFiles, MessageText};
+pub use crate::util::messages::{MessageEntities, MessageGetFiles, MessageText};
+pub use crate::util::streams::UpdatesStreamExt;
@@ -0,0 +1,246 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::{sleep, Instant};
+
+use telegram_bot_raw::{ChatRef, HttpRequest, Integer};
+
+const DEFAULT_GLOBAL_PER_SECOND: usize = 30;
+const DEFAULT_PRIVATE_CHAT_PER_SECOND: usize = 1;
+const DEFAULT_GROUP_PER_MINUTE: usize = 20;
+
+/// Drop the history of idle chats once this many chats are tracked.
+const MAX_TRACKED_CHATS: usize = 1024;
+/// Chats without messages for this long don't affect any limit anymore.
+const IDLE_PERIOD: Duration = Duration::from_secs(60);
+
+/// Limits for outgoing messages, used to stay within Telegram's flood limits.
+///
+/// Only requests that send a message to a chat are throttled, e.g. `sendMessage`,
+/// `sendPhoto`, `copyMessage` or `forwardMessage`. The defaults follow the limits
+/// documented by Telegram: 30 messages per second in total, one message per second
+/// in any chat and additionally 20 messages per minute in a group or channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimits {
+    global_per_second: usize,
+    private_chat_per_second: usize,
+    group_per_minute: usize,
+}
+
+impl RateLimits {
+    /// Maximum number of messages per second across all chats.
+    pub fn global_per_second(&mut self, limit: usize) -> &mut Self {
+        self.global_per_second = limit;
+        self
+    }
+
+    /// Maximum number of messages per second in a single chat. Despite the name, this
+    /// applies to groups and channels too, in addition to `group_per_minute`.
+    pub fn private_chat_per_second(&mut self, limit: usize) -> &mut Self {
+        self.private_chat_per_second = limit;
+        self
+    }
+
+    /// Maximum number of messages per minute in a single group, supergroup or channel.
+    pub fn group_per_minute(&mut self, limit: usize) -> &mut Self {
+        self.group_per_minute = limit;
+        self
+    }
+}
+
+impl Default for RateLimits {
+    fn default() -> Self {
+        RateLimits {
+            global_per_second: DEFAULT_GLOBAL_PER_SECOND,
+            private_chat_per_second: DEFAULT_PRIVATE_CHAT_PER_SECOND,
+            group_per_minute: DEFAULT_GROUP_PER_MINUTE,
+        }
+    }
+}
+
+/// Sliding window of the points in time at which messages were sent.
+#[derive(Debug, Default)]
+struct Window {
+    sent: VecDeque<Instant>,
+}
+
+impl Window {
+    /// Time to wait until another message fits into `limit` messages per `period`.
+    fn wait_time(&mut self, now: Instant, limit: usize, period: Duration) -> Duration {
+        while matches!(self.sent.front(), Some(&sent) if now.duration_since(sent) >= period) {
+            self.sent.pop_front();
+        }
+        if self.sent.len() < limit.max(1) {
+            return Duration::from_secs(0);
+        }
+        let oldest = self.sent[self.sent.len() - limit.max(1)];
+        period - now.duration_since(oldest)
+    }
+}
+
+/// The windows of a single chat. Only groups and channels use the per-minute window.
+#[derive(Debug, Default)]
+struct ChatWindows {
+    per_second: Window,
+    per_minute: Window,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    global: Window,
+    chats: HashMap<ChatRef, ChatWindows>,
+}
+
+/// Delays outgoing messages so they don't exceed the configured `RateLimits`.
+pub(crate) struct RateLimiter {
+    limits: RateLimits,
+    state: Mutex<State>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(limits: RateLimits) -> Self {
+        RateLimiter {
+            limits,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Waits until the request can be sent without exceeding the limits.
+    pub(crate) async fn acquire(&self, request: &HttpRequest) {
+        if !is_message_request(request.name()) {
+            return;
+        }
+        let chat = request.chat_id();
+
+        loop {
+            let wait = self.try_acquire(chat.as_ref(), Instant::now());
+            if wait == Duration::from_secs(0) {
+                return;
+            }
+            tracing::trace!(wait = ?wait, chat = ?chat, "rate limit reached, delaying request");
+            sleep(wait).await;
+        }
+    }
+
+    /// Records a message sent at `now` and returns zero if it fits into the limits,
+    /// otherwise returns how long to wait before trying again.
+    fn try_acquire(&self, chat: Option<&ChatRef>, now: Instant) -> Duration {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        let limits = &self.limits;
+        let second = Duration::from_secs(1);
+        let minute = Duration::from_secs(60);
+
+        let global_wait = state
+            .global
+            .wait_time(now, limits.global_per_second, second);
+        let chat_wait = match chat {
+            Some(chat) => {
+                let windows = state.chats.entry(chat.clone()).or_default();
+                let per_second = limits.private_chat_per_second;
+                let wait = windows.per_second.wait_time(now, per_second, second);
+                if is_private_chat(chat) {
+                    wait
+                } else {
+                    let per_minute = limits.group_per_minute;
+                    wait.max(windows.per_minute.wait_time(now, per_minute, minute))
+                }
+            }
+            None => Duration::from_secs(0),
+        };
+
+        let wait = global_wait.max(chat_wait);
+        if wait > Duration::from_secs(0) {
+            return wait;
+        }
+        state.global.sent.push_back(now);
+        if let Some(chat) = chat {
+            if let Some(windows) = state.chats.get_mut(chat) {
+                windows.per_second.sent.push_back(now);
+                if !is_private_chat(chat) {
+                    windows.per_minute.sent.push_back(now);
+                }
+            }
+        }
+        if state.chats.len() > MAX_TRACKED_CHATS {
+            state.chats.retain(|_, windows| {
+                matches!(windows.per_second.sent.back(), Some(&sent) if now.duration_since(sent) < IDLE_PERIOD)
+            });
+        }
+        wait
+    }
+}
+
+pub(crate) fn is_message_request(method: &str) -> bool {
+    (method.starts_with("send") && method != "sendChatAction")
+        || method.starts_with("copyMessage")
+        || method.starts_with("forwardMessage")
+}
+
+fn is_private_chat(chat: &ChatRef) -> bool {
+    match chat {
+        ChatRef::Id(id) => Integer::from(*id) > 0,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use telegram_bot_raw::ChatId;
+
+    use super::*;
+
+    #[test]
+    fn window_wait_time() {
+        let mut window = Window::default();
+        let start = Instant::now();
+        let period = Duration::from_secs(60);
+
+        for i in 0..3 {
+            let now = start + Duration::from_secs(i);
+            assert_eq!(window.wait_time(now, 3, period), Duration::from_secs(0));
+            window.sent.push_back(now);
+        }
+
+        let now = start + Duration::from_secs(10);
+        assert_eq!(window.wait_time(now, 3, period), Duration::from_secs(50));
+        assert_eq!(
+            window.wait_time(start + period, 3, period),
+            Duration::from_secs(0)
+        );
+        assert_eq!(window.sent.len(), 2);
+
+        // Messages to a group are limited per second and per minute.
+        let limiter = RateLimiter::new(RateLimits::default());
+        let group = ChatRef::from_chat_id(ChatId::new(-100));
+        assert_eq!(
+            limiter.try_acquire(Some(&group), start),
+            Duration::from_secs(0)
+        );
+        assert_eq!(
+            limiter.try_acquire(Some(&group), start),
+            Duration::from_secs(1)
+        );
+        for i in 1..20 {
+            let now = start + Duration::from_secs(i);
+            assert_eq!(
+                limiter.try_acquire(Some(&group), now),
+                Duration::from_secs(0)
+            );
+        }
+        let now = start + Duration::from_secs(20);
+        assert_eq!(
+            limiter.try_acquire(Some(&group), now),
+            Duration::from_secs(40)
+        );
+    }
+
+    #[test]
+    fn message_requests() {
+        assert!(is_message_request("sendMessage"));
+        assert!(is_message_request("copyMessage"));
+        assert!(is_message_request("forwardMessages"));
+        assert!(!is_message_request("sendChatAction"));
+        assert!(!is_message_request("getUpdates"));
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+use telegram_bot_raw::{GetMe, Update, UpdateKind};
+
+use crate::api::Api;
+use crate::errors::{Error, ErrorKind};
+use crate::util::streams::Command;
+
+type CommandHandler =
+    Box<dyn Fn(Api, Command) -> BoxFuture<'static, Result<(), Error>> + Send + Sync>;
+
+/// Routes bot commands like `/start` to the handlers registered for them.
+///
+/// Commands addressed to another bot, like `/start@other_bot` in a group, are ignored.
+/// The username of the bot is requested with `getMe` when it is needed for the first
+/// time and cached afterwards, unless it is set with `bot_name`. Dispatching such a
+/// command fails if `getMe` returns no username.
+///
+/// # Examples
+///
+/// ```rust
+/// # use telegram_bot::{Api, CommandRouter, prelude::*};
+/// use futures::StreamExt;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), telegram_bot::Error> {
+/// # let api: Api = Api::new("token");
+/// # if false {
+/// let mut router = CommandRouter::new(&api);
+/// router
+///     .command("start", |api, command| async move {
+///         api.send(command.message.text_reply("Hello!")).await?;
+///         Ok(())
+///     })
+///     .command("echo", |api, command| async move {
+///         let words: Vec<_> = command.args().collect();
+///         api.send(command.message.text_reply(words.join(" "))).await?;
+///         Ok(())
+///     })
+///     .fallback(|api, command| async move {
+///         let text = format!("Unknown command /{}", command.name);
+///         api.send(command.message.text_reply(text)).await?;
+///         Ok(())
+///     });
+///
+/// let mut stream = api.stream();
+/// while let Some(update) = stream.next().await {
+///     router.dispatch(update?).await?;
+/// }
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+pub struct CommandRouter {
+    api: Api,
+    commands: HashMap<String, CommandHandler>,
+    fallback: Option<CommandHandler>,
+    bot_name: Mutex<Option<String>>,
+}
+
+impl CommandRouter {
+    pub fn new(api: &Api) -> Self {
+        CommandRouter {
+            api: api.clone(),
+            commands: HashMap::new(),
+            fallback: None,
+            bot_name: Mutex::new(None),
+        }
+    }
+
+    /// Call `handler` for the command with the given name, with or without leading slash.
+    pub fn command<H, F>(&mut self, name: &str, handler: H) -> &mut Self
+    where
+        H: Fn(Api, Command) -> F + Send + Sync + 'static,
+        F: Future<Output = Result<(), Error>> + Send + 'static,
+    {
+        let name = name.trim_start_matches('/').to_string();
+        self.commands.insert(name, boxed(handler));
+        self
+    }
+
+    /// Call `handler` for commands that have no handler of their own.
+    pub fn fallback<H, F>(&mut self, handler: H) -> &mut Self
+    where
+        H: Fn(Api, Command) -> F + Send + Sync + 'static,
+        F: Future<Output = Result<(), Error>> + Send + 'static,
+    {
+        self.fallback = Some(boxed(handler));
+        self
+    }
+
+    /// Set the username of the bot instead of requesting it with `getMe`.
+    pub fn bot_name(&mut self, bot_name: &str) -> &mut Self {
+        *self.lock_bot_name() = Some(bot_name.trim_start_matches('@').to_string());
+        self
+    }
+
+    /// Run the handler for the command in the update, if it is a new message starting
+    /// with a command. Returns whether a handler was run, and the error of the handler.
+    pub async fn dispatch(&self, update: Update) -> Result<bool, Error> {
+        let command = match update.kind {
+            UpdateKind::Message(message) => Command::from_message(message),
+            _ => None,
+        };
+        match command {
+            Some(command) => self.dispatch_command(command).await,
+            None => Ok(false),
+        }
+    }
+
+    /// Run the handler for the command. Returns whether a handler was run, and the error
+    /// of the handler.
+    pub async fn dispatch_command(&self, command: Command) -> Result<bool, Error> {
+        if command.bot_name.is_some() {
+            let bot_name = self.fetch_bot_name().await?;
+            if !command.is_addressed_to(&bot_name) {
+                tracing::trace!(command = %command.name, "command is addressed to another bot");
+                return Ok(false);
+            }
+        }
+
+        let handler = match self.commands.get(&command.name) {
+            Some(handler) => handler,
+            None => match self.fallback {
+                Some(ref fallback) => fallback,
+                None => return Ok(false),
+            },
+        };
+        handler(self.api.clone(), command).await?;
+        Ok(true)
+    }
+
+    async fn fetch_bot_name(&self) -> Result<String, Error> {
+        let cached = self.lock_bot_name().clone();
+        if let Some(bot_name) = cached {
+            return Ok(bot_name);
+        }
+
+        let me = self.api.send(GetMe).await?;
+        let bot_name = me.username.ok_or(ErrorKind::MissingBotUsername)?;
+        *self.lock_bot_name() = Some(bot_name.clone());
+        Ok(bot_name)
+    }
+
+    fn lock_bot_name(&self) -> std::sync::MutexGuard<'_, Option<String>> {
+        self.bot_name.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+fn boxed<H, F>(handler: H) -> CommandHandler
+where
+    H: Fn(Api, Command) -> F + Send + Sync + 'static,
+    F: Future<Output = Result<(), Error>> + Send + 'static,
+{
+    Box::new(move |api, command| handler(api, command).boxed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connector::mock::MockConnector;
+
+    #[tokio::test]
+    async fn missing_username_is_not_cached() {
+        let mock = MockConnector::new();
+        mock.respond_ok("getMe", r#"{"id": 1, "is_bot": true, "first_name": "Bot"}"#)
+            .respond_ok(
+                "getMe",
+                r#"{"id": 1, "is_bot": true, "first_name": "Bot", "username": "bot"}"#,
+            );
+        let api = Api::with_connector("token", Box::new(mock));
+        let mut router = CommandRouter::new(&api);
+        router.command("start", |_, _| async { Ok(()) });
+        let update: Update = serde_json::from_str(
+            r#"{"update_id": 1, "message": {"message_id": 1, "date": 0, "text": "/start@bot",
+                "entities": [{"type": "bot_command", "offset": 0, "length": 10}],
+                "from": {"id": 7, "is_bot": false, "first_name": "Ann"},
+                "chat": {"id": -5, "type": "group", "title": "Group",
+                    "all_members_are_administrators": false}}}"#,
+        )
+        .unwrap();
+
+        assert!(router.dispatch(update.clone()).await.is_err());
+        assert!(router.dispatch(update).await.unwrap());
+    }
+}
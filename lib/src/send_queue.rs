@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use futures::channel::oneshot;
+
+use telegram_bot_raw::{ChatRef, HttpRequest};
+
+use crate::rate_limit::is_message_request;
+
+/// Makes messages to the same chat go out one after another, in the order in which
+/// they were submitted.
+#[derive(Default)]
+pub(crate) struct SendQueue {
+    /// The last submitted message per chat, which the next message has to wait for.
+    chats: Mutex<HashMap<ChatRef, Slot>>,
+    next_id: AtomicUsize,
+}
+
+struct Slot {
+    id: usize,
+    done: oneshot::Receiver<()>,
+}
+
+/// The place of a message in the queue of its chat. Dropping it lets the next
+/// message to the chat proceed.
+pub(crate) struct Turn {
+    queue: Arc<SendQueue>,
+    chat: ChatRef,
+    id: usize,
+    previous: Option<oneshot::Receiver<()>>,
+    _done: oneshot::Sender<()>,
+}
+
+impl SendQueue {
+    /// Puts the request at the end of the queue of its chat, if it sends a message.
+    pub(crate) fn enqueue(self: &Arc<Self>, request: &HttpRequest) -> Option<Turn> {
+        if !is_message_request(request.name()) {
+            return None;
+        }
+        let chat = request.chat_id()?;
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (done_sender, done) = oneshot::channel();
+        let previous = self
+            .lock()
+            .insert(chat.clone(), Slot { id, done })
+            .map(|slot| slot.done);
+
+        Some(Turn {
+            queue: self.clone(),
+            chat,
+            id,
+            previous,
+            _done: done_sender,
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<ChatRef, Slot>> {
+        self.chats.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl Turn {
+    /// Waits until all previously submitted messages to the chat are sent.
+    pub(crate) async fn wait(&mut self) {
+        if let Some(ref mut previous) = self.previous {
+            // The previous message is done once its sender is dropped.
+            let _ = previous.await;
+            self.previous = None;
+        }
+    }
+}
+
+impl Drop for Turn {
+    fn drop(&mut self) {
+        let mut chats = self.queue.lock();
+        if matches!(chats.get(&self.chat), Some(slot) if slot.id == self.id) {
+            chats.remove(&self.chat);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+
+    use telegram_bot_raw::{ChatId, Request, SendMessage};
+
+    use super::*;
+
+    fn request(chat: i64) -> HttpRequest {
+        SendMessage::new(ChatId::new(chat), "text")
+            .serialize()
+            .unwrap()
+    }
+
+    #[test]
+    fn messages_wait_for_previous_ones() {
+        let queue = Arc::new(SendQueue::default());
+        let mut first = queue.enqueue(&request(1)).unwrap();
+        let mut second = queue.enqueue(&request(1)).unwrap();
+        let mut other_chat = queue.enqueue(&request(2)).unwrap();
+
+        assert!(first.wait().now_or_never().is_some());
+        assert!(other_chat.wait().now_or_never().is_some());
+        assert!(second.wait().now_or_never().is_none());
+
+        drop(first);
+        assert!(second.wait().now_or_never().is_some());
+
+        drop(second);
+        drop(other_chat);
+        assert!(queue.lock().is_empty());
+    }
+}
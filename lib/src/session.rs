@@ -0,0 +1,290 @@
+//! Storage of conversation state, see `Conversation`.
+//!
+//! Besides `MemorySessionStorage`, states can be kept in a [sled](https://docs.rs/sled)
+//! database with the `session-sled` feature, or in Redis with the `session-redis` feature,
+//! to survive restarts or to share them between several instances of the bot. These
+//! storages serialize states as JSON.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use futures::future::{self, BoxFuture};
+
+use telegram_bot_raw::{ChatId, UserId};
+
+#[cfg(any(feature = "session-sled", feature = "session-redis"))]
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::errors::Error;
+#[cfg(any(feature = "session-sled", feature = "session-redis"))]
+use crate::errors::ErrorKind;
+
+/// Identifies the conversation of a user in a chat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SessionKey {
+    pub chat: ChatId,
+    pub user: UserId,
+}
+
+impl SessionKey {
+    pub fn new(chat: ChatId, user: UserId) -> Self {
+        SessionKey { chat, user }
+    }
+}
+
+impl fmt::Display for SessionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.chat, self.user)
+    }
+}
+
+/// Stores the state of conversations between updates.
+pub trait SessionStorage<S>: Send + Sync {
+    /// Returns the state of the conversation, or `None` if there is no conversation.
+    fn get(&self, key: SessionKey) -> BoxFuture<'_, Result<Option<S>, Error>>;
+
+    /// Replaces the state of the conversation.
+    fn set(&self, key: SessionKey, state: S) -> BoxFuture<'_, Result<(), Error>>;
+
+    /// Ends the conversation.
+    fn remove(&self, key: SessionKey) -> BoxFuture<'_, Result<(), Error>>;
+}
+
+/// Keeps the state of conversations in memory, so it is lost on restart.
+///
+/// Clones share the stored states.
+pub struct MemorySessionStorage<S> {
+    sessions: Arc<Mutex<HashMap<SessionKey, S>>>,
+}
+
+impl<S> MemorySessionStorage<S> {
+    pub fn new() -> Self {
+        MemorySessionStorage {
+            sessions: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<SessionKey, S>> {
+        self.sessions.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl<S> Default for MemorySessionStorage<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Clone for MemorySessionStorage<S> {
+    fn clone(&self) -> Self {
+        MemorySessionStorage {
+            sessions: self.sessions.clone(),
+        }
+    }
+}
+
+impl<S> SessionStorage<S> for MemorySessionStorage<S>
+where
+    S: Clone + Send + 'static,
+{
+    fn get(&self, key: SessionKey) -> BoxFuture<'_, Result<Option<S>, Error>> {
+        let state = self.lock().get(&key).cloned();
+        Box::pin(future::ready(Ok(state)))
+    }
+
+    fn set(&self, key: SessionKey, state: S) -> BoxFuture<'_, Result<(), Error>> {
+        self.lock().insert(key, state);
+        Box::pin(future::ready(Ok(())))
+    }
+
+    fn remove(&self, key: SessionKey) -> BoxFuture<'_, Result<(), Error>> {
+        self.lock().remove(&key);
+        Box::pin(future::ready(Ok(())))
+    }
+}
+
+/// Keeps the state of conversations in a sled database, available with the
+/// `session-sled` feature.
+#[cfg(feature = "session-sled")]
+pub struct SledSessionStorage<S> {
+    tree: sled::Tree,
+    state: std::marker::PhantomData<fn() -> S>,
+}
+
+#[cfg(feature = "session-sled")]
+impl<S> SledSessionStorage<S> {
+    /// Opens the database at the given path, creating it if it doesn't exist.
+    pub fn open<P: AsRef<std::path::Path>>(path: P) -> Result<Self, Error> {
+        let db = sled::open(path).map_err(ErrorKind::from)?;
+        Ok(Self::new(db.open_tree(SLED_TREE).map_err(ErrorKind::from)?))
+    }
+
+    /// Stores the states in the given tree of an already opened database.
+    pub fn new(tree: sled::Tree) -> Self {
+        SledSessionStorage {
+            tree,
+            state: std::marker::PhantomData,
+        }
+    }
+
+    fn get_sync(&self, key: SessionKey) -> Result<Option<S>, Error>
+    where
+        S: DeserializeOwned,
+    {
+        match self.tree.get(key.to_string()).map_err(ErrorKind::from)? {
+            Some(value) => Ok(Some(
+                serde_json::from_slice(&value).map_err(ErrorKind::from)?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    fn set_sync(&self, key: SessionKey, state: S) -> Result<(), Error>
+    where
+        S: Serialize,
+    {
+        let value = serde_json::to_vec(&state).map_err(ErrorKind::from)?;
+        self.tree
+            .insert(key.to_string(), value)
+            .map_err(ErrorKind::from)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "session-sled")]
+const SLED_TREE: &str = "telegram-bot-sessions";
+
+#[cfg(feature = "session-sled")]
+impl<S> SessionStorage<S> for SledSessionStorage<S>
+where
+    S: Serialize + DeserializeOwned + Send + 'static,
+{
+    fn get(&self, key: SessionKey) -> BoxFuture<'_, Result<Option<S>, Error>> {
+        Box::pin(future::ready(self.get_sync(key)))
+    }
+
+    fn set(&self, key: SessionKey, state: S) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(future::ready(self.set_sync(key, state)))
+    }
+
+    fn remove(&self, key: SessionKey) -> BoxFuture<'_, Result<(), Error>> {
+        let result = self
+            .tree
+            .remove(key.to_string())
+            .map(|_| ())
+            .map_err(|err| ErrorKind::from(err).into());
+        Box::pin(future::ready(result))
+    }
+}
+
+/// Keeps the state of conversations in Redis, available with the `session-redis` feature.
+///
+/// States are stored as JSON strings under the key `<prefix><chat id>:<user id>`, with
+/// the prefix `telegram-bot:session:` by default.
+#[cfg(feature = "session-redis")]
+pub struct RedisSessionStorage<S> {
+    connection: redis::aio::ConnectionManager,
+    prefix: String,
+    ttl: Option<std::time::Duration>,
+    state: std::marker::PhantomData<fn() -> S>,
+}
+
+#[cfg(feature = "session-redis")]
+impl<S> RedisSessionStorage<S> {
+    /// Connects to the Redis server at the given URL, e.g. `redis://127.0.0.1/`.
+    pub async fn connect(url: &str) -> Result<Self, Error> {
+        let client = redis::Client::open(url).map_err(ErrorKind::from)?;
+        let connection = client
+            .get_connection_manager()
+            .await
+            .map_err(ErrorKind::from)?;
+        Ok(Self::new(connection))
+    }
+
+    /// Stores the states using an existing connection.
+    pub fn new(connection: redis::aio::ConnectionManager) -> Self {
+        RedisSessionStorage {
+            connection,
+            prefix: REDIS_PREFIX.to_string(),
+            ttl: None,
+            state: std::marker::PhantomData,
+        }
+    }
+
+    /// Prefix of the keys the states are stored under.
+    pub fn prefix(&mut self, prefix: &str) -> &mut Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Let conversations expire when their state wasn't changed for the given time.
+    pub fn ttl(&mut self, ttl: std::time::Duration) -> &mut Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    fn key(&self, key: SessionKey) -> String {
+        format!("{}{}", self.prefix, key)
+    }
+}
+
+#[cfg(feature = "session-redis")]
+const REDIS_PREFIX: &str = "telegram-bot:session:";
+
+#[cfg(feature = "session-redis")]
+impl<S> SessionStorage<S> for RedisSessionStorage<S>
+where
+    S: Serialize + DeserializeOwned + Send + 'static,
+{
+    fn get(&self, key: SessionKey) -> BoxFuture<'_, Result<Option<S>, Error>> {
+        use redis::AsyncCommands;
+
+        let mut connection = self.connection.clone();
+        let key = self.key(key);
+        Box::pin(async move {
+            let value: Option<String> = connection.get(key).await.map_err(ErrorKind::from)?;
+            match value {
+                Some(value) => Ok(Some(serde_json::from_str(&value).map_err(ErrorKind::from)?)),
+                None => Ok(None),
+            }
+        })
+    }
+
+    fn set(&self, key: SessionKey, state: S) -> BoxFuture<'_, Result<(), Error>> {
+        use redis::AsyncCommands;
+
+        let mut connection = self.connection.clone();
+        let key = self.key(key);
+        let ttl = self.ttl;
+        let value = serde_json::to_string(&state);
+        Box::pin(async move {
+            let value = value.map_err(ErrorKind::from)?;
+            match ttl {
+                Some(ttl) => connection
+                    .set_ex::<_, _, ()>(key, value, ttl.as_secs().max(1))
+                    .await
+                    .map_err(ErrorKind::from)?,
+                None => connection
+                    .set::<_, _, ()>(key, value)
+                    .await
+                    .map_err(ErrorKind::from)?,
+            }
+            Ok(())
+        })
+    }
+
+    fn remove(&self, key: SessionKey) -> BoxFuture<'_, Result<(), Error>> {
+        use redis::AsyncCommands;
+
+        let mut connection = self.connection.clone();
+        let key = self.key(key);
+        Box::pin(async move {
+            connection
+                .del::<_, ()>(key)
+                .await
+                .map_err(ErrorKind::from)?;
+            Ok(())
+        })
+    }
+}
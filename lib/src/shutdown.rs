@@ -0,0 +1,95 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+use futures::future::poll_fn;
+
+/// Stops an `UpdatesStream` gracefully, created with `UpdatesStream::shutdown_handle`.
+///
+/// Once shutdown is requested, the stream stops yielding and fetching updates. It
+/// confirms the updates it has already yielded with a final `getUpdates` request, so
+/// that they are not delivered again to the next instance of the bot, and ends. An
+/// `AckUpdatesStream` first waits for all yielded updates to be acknowledged.
+///
+/// Updates which were received but not yielded yet are delivered again after a restart.
+#[derive(Clone)]
+pub struct ShutdownHandle(Arc<Shutdown>);
+
+impl ShutdownHandle {
+    pub(crate) fn new(shutdown: Arc<Shutdown>) -> Self {
+        ShutdownHandle(shutdown)
+    }
+
+    /// Requests the stream to shut down. The returned future resolves once the stream
+    /// has ended; the shutdown happens even if the future is not awaited.
+    ///
+    /// The stream must still be polled for the shutdown to make progress.
+    pub fn shutdown(&self) -> impl Future<Output = ()> + Send {
+        {
+            let mut state = self.0.lock();
+            state.requested = true;
+            if let Some(waker) = state.stream_waker.take() {
+                waker.wake();
+            }
+        }
+        self.completed()
+    }
+
+    /// True, if a shutdown was requested.
+    pub fn is_requested(&self) -> bool {
+        self.0.lock().requested
+    }
+
+    /// Resolves once the stream has ended, either because of a shutdown, because its
+    /// `ErrorPolicy` gave up or because it was dropped.
+    pub fn completed(&self) -> impl Future<Output = ()> + Send {
+        let shutdown = self.0.clone();
+        poll_fn(move |cx| {
+            let mut state = shutdown.lock();
+            if state.completed {
+                return Poll::Ready(());
+            }
+            state.completion_wakers.push(cx.waker().clone());
+            Poll::Pending
+        })
+    }
+}
+
+/// State shared between an `UpdatesStream` and its `ShutdownHandle`s.
+#[derive(Default)]
+pub(crate) struct Shutdown {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    requested: bool,
+    completed: bool,
+    stream_waker: Option<Waker>,
+    completion_wakers: Vec<Waker>,
+}
+
+impl Shutdown {
+    /// Checks whether a shutdown was requested, and wakes the stream if it is requested
+    /// later on.
+    pub(crate) fn poll_requested(&self, cx: &mut Context) -> bool {
+        let mut state = self.lock();
+        if !state.requested {
+            state.stream_waker = Some(cx.waker().clone());
+        }
+        state.requested
+    }
+
+    pub(crate) fn complete(&self) {
+        let mut state = self.lock();
+        state.completed = true;
+        state.stream_waker = None;
+        for waker in state.completion_wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+use futures::task::{waker, ArcWake};
+use futures::Stream;
+
+use telegram_bot_raw::{CallbackQuery, InlineQuery, Message, Update, UpdateKind};
+
+use crate::errors::Error;
+use crate::stream::UpdatesStream;
+
+const MESSAGES: usize = 0;
+const CALLBACK_QUERIES: usize = 1;
+const INLINE_QUERIES: usize = 2;
+const OTHERS: usize = 3;
+const KINDS: usize = 4;
+
+/// Streams of the updates of an `UpdatesStream`, grouped by their kind, created with
+/// `UpdatesStream::split_by_kind`.
+///
+/// Polling any of the streams fetches updates for all of them, so they can be consumed
+/// independently, e.g. in separate tasks. Updates are buffered until the stream of their
+/// kind is polled; drop the streams you are not interested in to discard their updates.
+#[derive(Debug)]
+pub struct SplitUpdates {
+    /// New incoming messages, see `UpdateKind::Message`.
+    pub messages: KindStream<Message>,
+    /// Callback queries, see `UpdateKind::CallbackQuery`.
+    pub callback_queries: KindStream<CallbackQuery>,
+    /// Inline queries, see `UpdateKind::InlineQuery`.
+    pub inline_queries: KindStream<InlineQuery>,
+    /// All other updates, as well as the errors of the underlying stream.
+    pub others: KindStream<Result<Update, Error>>,
+}
+
+impl SplitUpdates {
+    pub(crate) fn new(stream: UpdatesStream) -> Self {
+        let split = Arc::new(Mutex::new(Split {
+            stream,
+            finished: false,
+            messages: Queue::default(),
+            callback_queries: Queue::default(),
+            inline_queries: Queue::default(),
+            others: Queue::default(),
+        }));
+        let wakers = Arc::new(Wakers::default());
+
+        SplitUpdates {
+            messages: KindStream::new(&split, &wakers, MESSAGES, |split| &mut split.messages),
+            callback_queries: KindStream::new(&split, &wakers, CALLBACK_QUERIES, |split| {
+                &mut split.callback_queries
+            }),
+            inline_queries: KindStream::new(&split, &wakers, INLINE_QUERIES, |split| {
+                &mut split.inline_queries
+            }),
+            others: KindStream::new(&split, &wakers, OTHERS, |split| &mut split.others),
+        }
+    }
+}
+
+/// Stream of one kind of updates, see `SplitUpdates`.
+#[must_use = "streams do nothing unless polled"]
+pub struct KindStream<T> {
+    split: Arc<Mutex<Split>>,
+    wakers: Arc<Wakers>,
+    index: usize,
+    queue: fn(&mut Split) -> &mut Queue<T>,
+}
+
+impl<T> KindStream<T> {
+    fn new(
+        split: &Arc<Mutex<Split>>,
+        wakers: &Arc<Wakers>,
+        index: usize,
+        queue: fn(&mut Split) -> &mut Queue<T>,
+    ) -> Self {
+        KindStream {
+            split: split.clone(),
+            wakers: wakers.clone(),
+            index,
+            queue,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Split> {
+        self.split.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl<T> Stream for KindStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let ref_mut = self.get_mut();
+        let mut split = ref_mut.lock();
+
+        loop {
+            if let Some(item) = (ref_mut.queue)(&mut split).items.pop_front() {
+                return Poll::Ready(Some(item));
+            }
+            if split.finished {
+                return Poll::Ready(None);
+            }
+
+            // Whichever stream polls the underlying stream, all waiting streams are woken.
+            ref_mut.wakers.register(ref_mut.index, cx.waker());
+            let wake_all = waker(ref_mut.wakers.clone());
+            let polled = Pin::new(&mut split.stream).poll_next(&mut Context::from_waker(&wake_all));
+            match polled {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(None) => {
+                    split.finished = true;
+                    ref_mut.wakers.wake_all();
+                }
+                Poll::Ready(Some(item)) => {
+                    let index = split.route(item);
+                    if index != ref_mut.index {
+                        ref_mut.wakers.wake_one(index);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T> Drop for KindStream<T> {
+    fn drop(&mut self) {
+        let queue = self.queue;
+        let mut split = self.lock();
+        let queue = queue(&mut split);
+        queue.closed = true;
+        queue.items.clear();
+        drop(split);
+        self.wakers.unregister(self.index);
+    }
+}
+
+impl<T> fmt::Debug for KindStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KindStream")
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+struct Split {
+    stream: UpdatesStream,
+    finished: bool,
+    messages: Queue<Message>,
+    callback_queries: Queue<CallbackQuery>,
+    inline_queries: Queue<InlineQuery>,
+    others: Queue<Result<Update, Error>>,
+}
+
+impl Split {
+    /// Puts the item into the queue of its kind, returning the index of the queue.
+    fn route(&mut self, item: Result<Update, Error>) -> usize {
+        match item {
+            Ok(Update {
+                kind: UpdateKind::Message(message),
+                ..
+            }) => self.messages.push(message, MESSAGES),
+            Ok(Update {
+                kind: UpdateKind::CallbackQuery(query),
+                ..
+            }) => self.callback_queries.push(query, CALLBACK_QUERIES),
+            Ok(Update {
+                kind: UpdateKind::InlineQuery(query),
+                ..
+            }) => self.inline_queries.push(query, INLINE_QUERIES),
+            other => self.others.push(other, OTHERS),
+        }
+    }
+}
+
+struct Queue<T> {
+    items: VecDeque<T>,
+    closed: bool,
+}
+
+impl<T> Queue<T> {
+    fn push(&mut self, item: T, index: usize) -> usize {
+        if !self.closed {
+            self.items.push_back(item);
+        }
+        index
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Queue {
+            items: VecDeque::new(),
+            closed: false,
+        }
+    }
+}
+
+/// Wakers of the streams that are waiting for updates.
+#[derive(Default)]
+struct Wakers {
+    wakers: Mutex<[Option<Waker>; KINDS]>,
+}
+
+impl Wakers {
+    fn lock(&self) -> MutexGuard<'_, [Option<Waker>; KINDS]> {
+        self.wakers.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn register(&self, index: usize, waker: &Waker) {
+        self.lock()[index] = Some(waker.clone());
+    }
+
+    fn unregister(&self, index: usize) {
+        self.lock()[index] = None;
+    }
+
+    fn wake_one(&self, index: usize) {
+        let waker = self.lock()[index].take();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    fn wake_all(&self) {
+        let wakers: Vec<_> = self.lock().iter_mut().filter_map(Option::take).collect();
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl ArcWake for Wakers {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.wake_all();
+    }
+}
@@ -1,24 +1,53 @@
-use std::cmp::max;
+use std::cmp::{max, min};
 use std::collections::VecDeque;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
 use std::time::Duration;
 
 use futures::Stream;
 
-use telegram_bot_raw::{AllowedUpdate, GetUpdates, Integer, Update};
+use telegram_bot_raw::{AllowedUpdate, GetUpdates, Integer, Update, UpdateKind};
 
+use crate::acknowledge::{AckUpdatesStream, Acks};
 use crate::api::Api;
+use crate::error_policy::{ErrorPolicy, ExponentialBackoff, FixedDelay};
 use crate::errors::Error;
+use crate::offset_storage::OffsetStorage;
+use crate::shutdown::{Shutdown, ShutdownHandle};
+use crate::split::SplitUpdates;
 
 const TELEGRAM_LONG_POLL_TIMEOUT_SECONDS: u64 = 5;
 const TELEGRAM_LONG_POLL_LIMIT_MESSAGES: Integer = 100;
-const TELEGRAM_LONG_POLL_ERROR_DELAY_MILLISECONDS: u64 = 500;
+
+/// Updates which were never received, because the id of a received update does not directly
+/// follow the id of the update before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UpdateGap {
+    /// Id of the last update before the gap.
+    pub after: Integer,
+    /// Id of the first update after the gap.
+    pub before: Integer,
+}
+
+impl UpdateGap {
+    /// Number of missing updates.
+    pub fn missing(&self) -> Integer {
+        self.before - self.after - 1
+    }
+}
+
+/// An update that is being processed by the middleware.
+type ProcessedUpdate = Pin<Box<dyn Future<Output = Option<Update>> + Send>>;
 
 /// This type represents stream of Telegram API updates and uses
 /// long polling method under the hood.
+///
+/// Failed requests are yielded as errors, see `error_policy` for how the stream
+/// proceeds afterwards. Updates which can't be parsed are yielded with
+/// `UpdateKind::Error`, so they are neither lost silently nor block later updates.
 #[must_use = "streams do nothing unless polled"]
 pub struct UpdatesStream {
     api: Api,
@@ -26,10 +55,22 @@ pub struct UpdatesStream {
     buffer: VecDeque<Update>,
     current_request:
         Option<Pin<Box<dyn Future<Output = Result<Option<Vec<Update>>, Error>> + Send>>>,
+    prefetch: bool,
+    prefetched: Option<Result<Option<Vec<Update>>, Error>>,
+    processing: Option<(Integer, ProcessedUpdate)>,
     timeout: Duration,
     allowed_updates: Vec<AllowedUpdate>,
     limit: Integer,
-    error_delay: Duration,
+    error_policy: Box<dyn ErrorPolicy>,
+    failed_attempts: u32,
+    finished: bool,
+    offset_storage: Option<Box<dyn OffsetStorage>>,
+    stored_update: Option<Integer>,
+    pub(crate) acks: Option<Arc<Acks>>,
+    shutdown: Option<Arc<Shutdown>>,
+    shutting_down: bool,
+    last_seen: Option<Integer>,
+    gap_handler: Option<Box<dyn FnMut(UpdateGap) + Send>>,
     next_poll_id: usize,
 }
 
@@ -45,10 +86,75 @@ impl Stream for UpdatesStream {
 
         tracing::trace!("start stream polling");
 
+        if ref_mut.finished {
+            tracing::trace!("stream is finished");
+            return Poll::Ready(None);
+        }
+
+        if !ref_mut.shutting_down {
+            if let Some(ref shutdown) = ref_mut.shutdown {
+                if shutdown.poll_requested(cx) {
+                    tracing::trace!("shutdown requested");
+                    ref_mut.shutting_down = true;
+                    ref_mut.rewind_unyielded();
+                    ref_mut.buffer.clear();
+                    ref_mut.current_request = None;
+                    ref_mut.prefetched = None;
+                    ref_mut.processing = None;
+                }
+            }
+        }
+        if ref_mut.shutting_down {
+            return ref_mut.poll_shutdown(cx);
+        }
+
+        if let Some((_, ref mut processing)) = ref_mut.processing {
+            let processed = match processing.as_mut().poll(cx) {
+                Poll::Pending => {
+                    tracing::trace!("middleware is pending");
+                    return Poll::Pending;
+                }
+                Poll::Ready(processed) => processed,
+            };
+            ref_mut.processing = None;
+            match processed {
+                Some(value) => {
+                    tracing::trace!(update = ?value, "returning processed update");
+                    ref_mut.poll_prefetch(cx);
+                    return Poll::Ready(Some(Ok(value)));
+                }
+                None => {
+                    tracing::trace!("middleware dropped update");
+                    return Pin::new(ref_mut).poll_next(cx);
+                }
+            }
+        }
+
         if let Some(value) = ref_mut.buffer.pop_front() {
+            if ref_mut.api.has_middleware() {
+                ref_mut.processing = Some((value.id, ref_mut.api.process_update(value)));
+                return Pin::new(ref_mut).poll_next(cx);
+            }
             tracing::trace!(update = ?value, "returning buffered update");
+            ref_mut.poll_prefetch(cx);
             return Poll::Ready(Some(Ok(value)));
         }
+
+        if ref_mut.current_request.is_none() {
+            if let Some(ref acks) = ref_mut.acks {
+                match acks.poll_settled(cx) {
+                    Poll::Pending => {
+                        tracing::trace!("waiting for updates to be acknowledged");
+                        return Poll::Pending;
+                    }
+                    Poll::Ready(Some(update_id)) => {
+                        tracing::trace!(update_id = update_id, "redelivering updates");
+                        ref_mut.last_update = min(ref_mut.last_update, update_id - 1);
+                    }
+                    Poll::Ready(None) => {}
+                }
+            }
+        }
         tracing::trace!("processing request");
 
         let result = match ref_mut.current_request {
@@ -57,8 +163,10 @@ impl Stream for UpdatesStream {
                 Ok(false)
             }
             Some(ref mut current_request) => {
-                let cc = current_request.as_mut();
-                let polled_update = cc.poll(cx);
+                let polled_update = match ref_mut.prefetched.take() {
+                    Some(result) => Poll::Ready(result),
+                    None => current_request.as_mut().poll(cx),
+                };
                 match polled_update {
                     Poll::Pending => {
                         tracing::trace!("request is pending");
@@ -70,11 +178,17 @@ impl Stream for UpdatesStream {
                     }
                     Poll::Ready(Ok(Some(ref updates))) if updates.is_empty() => {
                         tracing::trace!("request resolved to empty update list");
+                        ref_mut.failed_attempts = 0;
                         Ok(false)
                     }
                     Poll::Ready(Ok(Some(updates))) => {
+                        ref_mut.failed_attempts = 0;
                         for update in updates {
                             tracing::trace!(update = ?update, "processing update");
+                            if let UpdateKind::Error(ref error) = update.kind {
+                                tracing::warn!(update_id = update.id, error = %error, "failed to parse update");
+                            }
+                            ref_mut.check_gap(update.id);
                             ref_mut.last_update = max(update.id, ref_mut.last_update);
                             tracing::trace!(last_update = ref_mut.last_update);
                             ref_mut.buffer.push_back(update)
@@ -92,31 +206,25 @@ impl Stream for UpdatesStream {
 
         match result {
             Err(err) => {
-                let timeout = ref_mut.timeout + Duration::from_secs(1);
-                let mut get_updates = GetUpdates::new();
-                get_updates
-                    .offset(ref_mut.last_update + 1)
-                    .timeout(ref_mut.error_delay.as_secs() as Integer)
-                    .limit(ref_mut.limit)
-                    .allowed_updates(&ref_mut.allowed_updates);
-                tracing::trace!(request = ?get_updates, timeout=?timeout, "preparing new request");
-
-                let request = ref_mut.api.send_timeout(get_updates, timeout);
-                ref_mut.current_request = Some(Box::pin(request));
+                ref_mut.failed_attempts = ref_mut.failed_attempts.saturating_add(1);
+                let delay = ref_mut
+                    .error_policy
+                    .retry_delay(&err, ref_mut.failed_attempts)
+                    .map(|delay| max(delay, err.retry_after().unwrap_or_default()));
+                match delay {
+                    Some(delay) => {
+                        tracing::trace!(delay = ?delay, attempt = ref_mut.failed_attempts, "retrying");
+                        ref_mut.request_updates(Some(delay));
+                    }
+                    None => {
+                        tracing::trace!("error policy gave up, finishing stream");
+                        ref_mut.finish();
+                    }
+                }
                 return Poll::Ready(Some(Err(err)));
             }
             Ok(false) => {
-                let timeout = ref_mut.timeout + Duration::from_secs(1);
-                let mut get_updates = GetUpdates::new();
-                get_updates
-                    .offset(ref_mut.last_update + 1)
-                    .timeout(ref_mut.error_delay.as_secs() as Integer)
-                    .limit(ref_mut.limit)
-                    .allowed_updates(&ref_mut.allowed_updates);
-                tracing::trace!(request = ?get_updates, timeout=?timeout, "preparing new request");
-
-                let request = ref_mut.api.send_timeout(get_updates, timeout);
-                ref_mut.current_request = Some(Box::pin(request));
+                ref_mut.request_updates(None);
 
                 tracing::trace!("executing recursive call");
                 Pin::new(ref_mut).poll_next(cx)
@@ -139,10 +247,22 @@ impl UpdatesStream {
             last_update: 0,
             buffer: VecDeque::new(),
             current_request: None,
+            prefetch: false,
+            prefetched: None,
+            processing: None,
             timeout: Duration::from_secs(TELEGRAM_LONG_POLL_TIMEOUT_SECONDS),
             allowed_updates: Vec::new(),
             limit: TELEGRAM_LONG_POLL_LIMIT_MESSAGES,
-            error_delay: Duration::from_millis(TELEGRAM_LONG_POLL_ERROR_DELAY_MILLISECONDS),
+            error_policy: Box::new(ExponentialBackoff::default()),
+            failed_attempts: 0,
+            finished: false,
+            offset_storage: None,
+            stored_update: None,
+            acks: None,
+            shutdown: None,
+            shutting_down: false,
+            last_seen: None,
+            gap_handler: None,
             next_poll_id: 0,
         }
     }
@@ -167,6 +287,18 @@ impl UpdatesStream {
     ///
     /// Please note that this parameter doesn't affect updates created before the call to the getUpdates,
     /// so unwanted updates may be received for a short period of time.
+    ///
+    /// Use `AllowedUpdate::all()` to also receive the kinds of updates that Telegram
+    /// only delivers on request, like `ChatMember`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::{AllowedUpdate, Api};
+    /// # let api: Api = Api::new("token");
+    /// let mut stream = api.stream();
+    /// stream.allowed_updates(&[AllowedUpdate::Message, AllowedUpdate::CallbackQuery]);
+    /// ```
     pub fn allowed_updates(&mut self, allowed_updates: &[AllowedUpdate]) -> &mut Self {
         self.allowed_updates = allowed_updates.to_vec();
         self
@@ -182,12 +314,387 @@ impl UpdatesStream {
         self
     }
 
-    /// Set a delay between erroneous request and next request.
-    /// This delay prevents busy looping in some cases.
-    ///
-    /// Default delay is 500 ms.
+    /// Set a fixed delay between erroneous request and next request.
+    /// This is a shorthand for `error_policy(FixedDelay(delay))`.
     pub fn error_delay(&mut self, delay: Duration) -> &mut Self {
-        self.error_delay = delay;
+        self.error_policy(FixedDelay(delay))
+    }
+
+    /// Set the policy that decides how long to wait after a failed request and
+    /// whether to retry at all. The stream yields every error, and ends after it
+    /// if the policy gives up.
+    ///
+    /// When Telegram asks to retry later because of flood control, the stream waits at
+    /// least as long as requested.
+    ///
+    /// Defaults to `ExponentialBackoff::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use telegram_bot::{Api, ExponentialBackoff};
+    /// # let api: Api = Api::new("token");
+    /// let mut backoff = ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(30));
+    /// backoff.max_attempts(10);
+    ///
+    /// let mut stream = api.stream();
+    /// stream.error_policy(backoff);
+    /// ```
+    pub fn error_policy<P>(&mut self, policy: P) -> &mut Self
+    where
+        P: ErrorPolicy + 'static,
+    {
+        self.error_policy = Box::new(policy);
+        self
+    }
+
+    /// Persist the id of the last confirmed update in `storage`, and continue after the
+    /// update stored there when the stream starts. Errors of the storage are yielded
+    /// like failed requests and handled according to the `error_policy`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::{Api, FileOffsetStorage};
+    /// # let api: Api = Api::new("token");
+    /// let mut stream = api.stream();
+    /// stream.offset_storage(FileOffsetStorage::new("offset.txt"));
+    /// ```
+    pub fn offset_storage<S>(&mut self, storage: S) -> &mut Self
+    where
+        S: OffsetStorage + 'static,
+    {
+        self.offset_storage = Some(Box::new(storage));
+        self.stored_update = None;
+        self
+    }
+
+    /// Turn this stream into one that yields an `AckHandle` with every update and only
+    /// advances the offset past updates that were acknowledged, for at-least-once
+    /// delivery. See `AckUpdatesStream` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::Api;
+    /// use futures::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api: Api = Api::new("token");
+    /// # if false {
+    /// let mut stream = api.stream().acknowledged();
+    /// while let Some(Ok((update, ack))) = stream.next().await {
+    ///     println!("{:?}", update);
+    ///     ack.ack();
+    /// }
+    /// # }
+    /// # }
+    /// ```
+    pub fn acknowledged(self) -> AckUpdatesStream {
+        AckUpdatesStream::new(self)
+    }
+
+    /// Request the next batch of updates while the current one is still being yielded,
+    /// so that there is no pause between batches. This helps bots that receive many updates.
+    ///
+    /// Note that Telegram considers the updates of a batch as confirmed once the next batch
+    /// is requested, so updates which were received but not yet yielded are lost if the bot
+    /// crashes. Prefetching is disabled for an `AckUpdatesStream`.
+    pub fn prefetch(&mut self) -> &mut Self {
+        self.prefetch = true;
+        self
+    }
+
+    /// Drives the request for the next batch while buffered updates are yielded.
+    fn poll_prefetch(&mut self, cx: &mut Context) {
+        if !self.prefetch || self.acks.is_some() || self.prefetched.is_some() {
+            return;
+        }
+        if self.current_request.is_none() {
+            tracing::trace!("prefetching next batch");
+            self.request_updates(None);
+        }
+        if let Some(ref mut current_request) = self.current_request {
+            if let Poll::Ready(result) = current_request.as_mut().poll(cx) {
+                tracing::trace!("prefetched batch is ready");
+                self.prefetched = Some(result);
+            }
+        }
+    }
+
+    /// Call `handler` whenever the ids of received updates are not consecutive, which means
+    /// that updates were lost, e.g. because another instance of the bot confirmed them or
+    /// because Telegram dropped them after 24 hours. With an `offset_storage`, gaps since the
+    /// last run are detected as well. Gaps are always logged as warnings.
+    ///
+    /// Telegram may skip ids of updates that are excluded by `allowed_updates`, which
+    /// is reported as a gap too.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::Api;
+    /// # let api: Api = Api::new("token");
+    /// let mut stream = api.stream();
+    /// stream.on_gap(|gap| eprintln!("lost {} updates after {}", gap.missing(), gap.after));
+    /// ```
+    pub fn on_gap<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(UpdateGap) + Send + 'static,
+    {
+        self.gap_handler = Some(Box::new(handler));
         self
     }
+
+    fn check_gap(&mut self, update_id: Integer) {
+        if let Some(last_seen) = self.last_seen {
+            if update_id > last_seen + 1 {
+                let gap = UpdateGap {
+                    after: last_seen,
+                    before: update_id,
+                };
+                tracing::warn!(
+                    after = gap.after,
+                    before = gap.before,
+                    missing = gap.missing(),
+                    "updates were lost"
+                );
+                if let Some(ref mut handler) = self.gap_handler {
+                    handler(gap);
+                }
+            }
+        }
+        self.last_seen = Some(
+            self.last_seen
+                .map_or(update_id, |seen| max(seen, update_id)),
+        );
+    }
+
+    /// Split this stream into separate streams of messages, callback queries, inline queries
+    /// and all other updates, see `SplitUpdates`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use telegram_bot::Api;
+    /// use futures::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api: Api = Api::new("token");
+    /// # if false {
+    /// let updates = api.stream().split_by_kind();
+    /// let mut callback_queries = updates.callback_queries;
+    /// tokio::spawn(async move {
+    ///     while let Some(query) = callback_queries.next().await {
+    ///         println!("{:?}", query);
+    ///     }
+    /// });
+    ///
+    /// let mut messages = updates.messages;
+    /// while let Some(message) = messages.next().await {
+    ///     println!("{:?}", message);
+    /// }
+    /// # }
+    /// # }
+    /// ```
+    pub fn split_by_kind(self) -> SplitUpdates {
+        SplitUpdates::new(self)
+    }
+
+    /// Returns a handle to shut the stream down gracefully, see `ShutdownHandle`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use telegram_bot::Api;
+    /// use futures::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api: Api = Api::new("token");
+    /// # if false {
+    /// let mut stream = api.stream();
+    /// let shutdown = stream.shutdown_handle();
+    /// tokio::spawn(async move {
+    ///     tokio::time::sleep(Duration::from_secs(60)).await;
+    ///     shutdown.shutdown().await;
+    /// });
+    ///
+    /// while let Some(update) = stream.next().await {
+    ///     println!("{:?}", update);
+    /// }
+    /// # }
+    /// # }
+    /// ```
+    pub fn shutdown_handle(&mut self) -> ShutdownHandle {
+        let shutdown = self.shutdown.get_or_insert_with(Default::default);
+        ShutdownHandle::new(shutdown.clone())
+    }
+
+    /// Moves the offset back before the received updates which were not yielded yet, so
+    /// that they are not confirmed and are delivered again.
+    fn rewind_unyielded(&mut self) {
+        let processing = self.processing.as_ref().map(|&(update_id, _)| update_id);
+        let buffered = self.buffer.iter().map(|update| update.id);
+        let prefetched = match self.prefetched {
+            Some(Ok(Some(ref updates))) => updates.iter().map(|update| update.id).min(),
+            _ => None,
+        };
+        let first = processing
+            .into_iter()
+            .chain(buffered)
+            .chain(prefetched)
+            .min();
+        if let Some(update_id) = first {
+            tracing::trace!(update_id = update_id, "rewinding to unyielded updates");
+            self.last_update = min(self.last_update, update_id - 1);
+        }
+    }
+
+    /// Confirms the yielded updates with a last request, then ends the stream.
+    fn poll_shutdown(&mut self, cx: &mut Context) -> Poll<Option<Result<Update, Error>>> {
+        if let Some(ref mut current_request) = self.current_request {
+            let result = match current_request.as_mut().poll(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(result) => result,
+            };
+            tracing::trace!("updates confirmed, finishing stream");
+            self.finish();
+            return Poll::Ready(result.err().map(Err));
+        }
+
+        if let Some(ref acks) = self.acks {
+            match acks.poll_settled(cx) {
+                Poll::Pending => {
+                    tracing::trace!("waiting for updates to be acknowledged");
+                    return Poll::Pending;
+                }
+                Poll::Ready(Some(update_id)) => {
+                    self.last_update = min(self.last_update, update_id - 1);
+                }
+                Poll::Ready(None) => {}
+            }
+        }
+
+        if self.last_update == 0 {
+            tracing::trace!("no updates to confirm, finishing stream");
+            self.finish();
+            return Poll::Ready(None);
+        }
+        self.request_updates(None);
+        self.poll_shutdown(cx)
+    }
+
+    fn finish(&mut self) {
+        self.finished = true;
+        self.current_request = None;
+        if let Some(ref shutdown) = self.shutdown {
+            shutdown.complete();
+        }
+    }
+
+    /// Loads the stored offset before the first request, and stores the id of the
+    /// last update that the next request is going to confirm.
+    fn sync_offset(&mut self) -> Result<(), Error> {
+        let storage = match self.offset_storage {
+            Some(ref mut storage) => storage,
+            None => return Ok(()),
+        };
+
+        let stored_update = match self.stored_update {
+            Some(stored_update) => stored_update,
+            None => {
+                let loaded = storage.load()?.unwrap_or(0);
+                tracing::trace!(stored_update = loaded, "loaded offset");
+                self.last_update = max(self.last_update, loaded);
+                self.stored_update = Some(loaded);
+                if loaded > 0 {
+                    self.last_seen = Some(self.last_seen.map_or(loaded, |seen| max(seen, loaded)));
+                }
+                loaded
+            }
+        };
+
+        if self.last_update > stored_update {
+            storage.store(self.last_update)?;
+            self.stored_update = Some(self.last_update);
+        }
+        Ok(())
+    }
+
+    /// Starts the next `getUpdates` request, optionally after waiting for `delay`.
+    fn request_updates(&mut self, delay: Option<Duration>) {
+        let synced = self.sync_offset();
+        // While shutting down, the request only confirms the offset and returns right away.
+        let (poll_timeout, limit) = if self.shutting_down {
+            (Duration::from_secs(0), 1)
+        } else {
+            (self.timeout, self.limit)
+        };
+        let timeout = poll_timeout + Duration::from_secs(1);
+        let mut get_updates = GetUpdates::new();
+        get_updates
+            .offset(self.last_update + 1)
+            .timeout(poll_timeout.as_secs() as Integer)
+            .limit(limit)
+            .allowed_updates(&self.allowed_updates);
+        tracing::trace!(request = ?get_updates, timeout=?timeout, "preparing new request");
+
+        let request = self.api.send_timeout(get_updates, timeout);
+        self.current_request = Some(Box::pin(async move {
+            if let Some(delay) = delay {
+                tokio::time::sleep(delay).await;
+            }
+            synced?;
+            request.await
+        }));
+    }
+}
+
+impl Drop for UpdatesStream {
+    fn drop(&mut self) {
+        if let Some(ref shutdown) = self.shutdown {
+            shutdown.complete();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::connector::mock::MockConnector;
+    use crate::offset_storage::MemoryOffsetStorage;
+
+    #[tokio::test]
+    async fn shutdown_keeps_unyielded_updates() {
+        let mock = MockConnector::new();
+        mock.respond_ok(
+            "getUpdates",
+            r#"[{"update_id": 1, "future_update": {}},
+                {"update_id": 2, "future_update": {}},
+                {"update_id": 3, "future_update": {}}]"#,
+        )
+        .respond_ok("getUpdates", "[]");
+        let api = Api::with_connector("token", Box::new(mock.clone()));
+        let storage = MemoryOffsetStorage::new();
+
+        let mut stream = api.stream();
+        stream.offset_storage(storage.clone());
+        let shutdown = stream.shutdown_handle();
+        assert_eq!(stream.next().await.unwrap().unwrap().id, 1);
+        drop(shutdown.shutdown());
+        assert!(stream.next().await.is_none());
+
+        assert_eq!(
+            mock.json_bodies("getUpdates").last().unwrap(),
+            r#"{"offset":2,"limit":1,"timeout":0,"allowed_updates":[]}"#
+        );
+        assert_eq!(storage.get(), Some(1));
+    }
 }
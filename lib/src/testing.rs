@@ -0,0 +1,293 @@
+//! Testing bots end to end, by pushing updates into the `UpdatesStream` of the bot and
+//! checking the requests it sends.
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use futures::{Future, FutureExt};
+use serde_json::Value;
+use telegram_bot_raw::{Body, HttpRequest, HttpResponse, Integer};
+use tokio::sync::Notify;
+
+use crate::api::Api;
+use crate::config::Config;
+use crate::connector::mock::MockConnector;
+use crate::connector::Connector;
+use crate::errors::Error;
+use crate::stream::UpdatesStream;
+
+/// An `Api` whose updates are pushed by the test, sending all other requests to a
+/// `MockConnector`.
+///
+/// `getUpdates` requests wait until updates are pushed, like long polling. Like on Telegram,
+/// pushed updates are delivered until a request confirms them with its `offset`, so updates
+/// a stream received but didn't confirm are delivered again to the next stream. Responses
+/// to other requests must be queued with `mock().respond_ok` and friends, otherwise the
+/// requests fail.
+///
+/// # Examples
+///
+/// ```rust
+/// use futures::StreamExt;
+/// use telegram_bot::prelude::*;
+/// use telegram_bot::testing::TestApi;
+/// use telegram_bot::{MessageKind, UpdateKind};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let test = TestApi::new();
+/// test.push_text(42, "/start");
+///
+/// let mut stream = test.stream();
+/// let update = stream.next().await.unwrap().unwrap();
+/// if let UpdateKind::Message(message) = update.kind {
+///     if let MessageKind::Text { ref data, .. } = message.kind {
+///         // No response was queued, so this fails, but the request is recorded.
+///         let _ = test.api().send(message.text_reply(format!("You said {}", data))).await;
+///     }
+/// }
+///
+/// assert_eq!(
+///     test.mock().json_bodies("sendMessage"),
+///     vec![r#"{"chat_id":42,"text":"You said /start","reply_parameters":{"message_id":1}}"#]
+/// );
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TestApi {
+    api: Api,
+    mock: MockConnector,
+    updates: Arc<TestUpdates>,
+}
+
+#[derive(Debug, Default)]
+struct TestUpdates {
+    state: Mutex<TestUpdatesState>,
+    pushed: Notify,
+}
+
+#[derive(Debug, Default)]
+struct TestUpdatesState {
+    last_id: Integer,
+    pending: VecDeque<(Integer, String)>,
+}
+
+impl TestApi {
+    pub fn new() -> Self {
+        Self::with_config(Config::new("test"))
+    }
+
+    /// Use the given configuration, e.g. with middleware, but the test connector.
+    pub fn with_config(mut config: Config) -> Self {
+        let mock = MockConnector::new();
+        let updates = Arc::new(TestUpdates::default());
+        config.connector(Box::new(TestConnector {
+            mock: mock.clone(),
+            updates: updates.clone(),
+        }));
+        TestApi {
+            api: Api::with_config(config),
+            mock,
+            updates,
+        }
+    }
+
+    /// The `Api` to pass to the bot.
+    pub fn api(&self) -> &Api {
+        &self.api
+    }
+
+    /// A stream of the pushed updates, the same as `Api::stream`.
+    pub fn stream(&self) -> UpdatesStream {
+        self.api.stream()
+    }
+
+    /// The connector that answers and records all requests but `getUpdates`.
+    pub fn mock(&self) -> &MockConnector {
+        &self.mock
+    }
+
+    /// Push an update, given as a JSON object without `update_id`, e.g.
+    /// `{"callback_query": {...}}`. Returns the id given to the update.
+    pub fn push_update(&self, update: &str) -> Integer {
+        let fields = update
+            .trim()
+            .strip_prefix('{')
+            .unwrap_or(update)
+            .trim_start();
+        let mut state = self.updates.lock();
+        state.last_id += 1;
+        let id = state.last_id;
+        let separator = if fields.starts_with('}') { "" } else { "," };
+        let update = format!(r#"{{"update_id":{}{}{}"#, id, separator, fields);
+        state.pending.push_back((id, update));
+        drop(state);
+        self.updates.pushed.notify_one();
+        id
+    }
+
+    /// Push a text message from the user with the given id in the private chat with the bot.
+    /// The update id is also used as the message id. Returns the id given to the update.
+    pub fn push_text(&self, user: Integer, text: &str) -> Integer {
+        let id = self.updates.lock().last_id + 1;
+        let text = json_string(text);
+        self.push_update(&format!(
+            r#"{{"message": {{"message_id": {id}, "date": 0, "text": {text},
+                "from": {{"id": {user}, "is_bot": false, "first_name": "Test"}},
+                "chat": {{"id": {user}, "type": "private", "first_name": "Test"}}}}}}"#,
+            id = id,
+            text = text,
+            user = user
+        ))
+    }
+}
+
+impl Default for TestApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestUpdates {
+    fn lock(&self) -> MutexGuard<'_, TestUpdatesState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[derive(Debug)]
+struct TestConnector {
+    mock: MockConnector,
+    updates: Arc<TestUpdates>,
+}
+
+impl Connector for TestConnector {
+    fn request(
+        &self,
+        token: &str,
+        req: HttpRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, Error>> + Send>> {
+        if req.name() != "getUpdates" {
+            return self.mock.request(token, req);
+        }
+        let params = match &req.body {
+            Body::Json(body) => serde_json::from_str(body).unwrap_or(Value::Null),
+            _ => Value::Null,
+        };
+        let param = |name: &str| params[name].as_i64();
+        let offset = param("offset").unwrap_or(0);
+        let limit = param("limit").unwrap_or(100).max(1) as usize;
+        let wait = param("timeout").unwrap_or(0) > 0;
+        let updates = self.updates.clone();
+
+        let future = async move {
+            loop {
+                let pushed = updates.pushed.notified();
+                let result = {
+                    let mut state = updates.lock();
+                    // Like Telegram, forget the updates confirmed by the offset, and keep
+                    // delivering the others.
+                    while matches!(state.pending.front(), Some(&(id, _)) if id < offset) {
+                        state.pending.pop_front();
+                    }
+                    let result: Vec<_> = state
+                        .pending
+                        .iter()
+                        .take(limit)
+                        .map(|(_, update)| update.as_str())
+                        .collect();
+                    result.join(",")
+                };
+                if !result.is_empty() || !wait {
+                    let body = format!(r#"{{"ok":true,"result":[{}]}}"#, result);
+                    return Ok(HttpResponse {
+                        status: 200,
+                        body: Some(body.into_bytes()),
+                    });
+                }
+                pushed.await;
+            }
+        };
+
+        future.boxed()
+    }
+}
+
+/// Quote a string for JSON.
+fn json_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use telegram_bot_raw::{MessageKind, UpdateKind};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn push_updates() {
+        let test = TestApi::new();
+        let mut stream = test.stream();
+
+        let receive = tokio::spawn(async move {
+            let mut texts = Vec::new();
+            while texts.len() < 2 {
+                let update = stream.next().await.unwrap().unwrap();
+                match update.kind {
+                    UpdateKind::Message(message) => match message.kind {
+                        MessageKind::Text { data, .. } => texts.push((update.id, data)),
+                        _ => panic!("unexpected message"),
+                    },
+                    UpdateKind::Unknown => texts.push((update.id, "unknown".to_string())),
+                    _ => panic!("unexpected update"),
+                }
+            }
+            texts
+        });
+        tokio::task::yield_now().await;
+        assert_eq!(test.push_text(7, "a \"quote\"\n"), 1);
+        assert_eq!(test.push_update(r#"{"future_update": {}}"#), 2);
+
+        assert_eq!(
+            receive.await.unwrap(),
+            vec![(1, "a \"quote\"\n".to_string()), (2, "unknown".to_string())]
+        );
+        assert!(test.mock().requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn redeliver_unconfirmed_updates() {
+        let test = TestApi::new();
+        test.push_text(7, "first");
+        test.push_text(7, "second");
+
+        let mut stream = test.stream();
+        assert_eq!(stream.next().await.unwrap().unwrap().id, 1);
+        drop(stream);
+
+        let mut stream = test.stream();
+        let shutdown = stream.shutdown_handle();
+        assert_eq!(stream.next().await.unwrap().unwrap().id, 1);
+        drop(shutdown.shutdown());
+        assert!(stream.next().await.is_none());
+
+        let mut stream = test.stream();
+        assert_eq!(stream.next().await.unwrap().unwrap().id, 2);
+        assert_eq!(test.updates.lock().pending.len(), 1);
+    }
+}
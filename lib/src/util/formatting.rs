@@ -0,0 +1,398 @@
+//! Escaping and composing formatted text for the `MarkdownV2` and `HTML` parse modes, and
+//! splitting texts that are too long for one message.
+
+use std::fmt::Write;
+
+use crate::types::{Integer, MessageEntity, MessageEntityKind, ParseMode};
+
+/// Maximum length of the text of a message, in UTF-16 code units.
+pub const MAX_MESSAGE_LENGTH: usize = 4096;
+
+/// Characters that must be escaped in `MarkdownV2` text.
+const MARKDOWN_V2_SPECIAL: &str = "_*[]()~`>#+-=|{}.!\\";
+
+/// Escape text for messages sent with `ParseMode::MarkdownV2`, so it is shown as is.
+pub fn escape_markdown_v2(text: &str) -> String {
+    escape_with(text, |c| MARKDOWN_V2_SPECIAL.contains(c))
+}
+
+/// Escape text for messages sent with `ParseMode::Html`, so it is shown as is.
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_with<F: Fn(char) -> bool>(text: &str, needs_escape: F) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if needs_escape(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Split a text with its entities into parts of at most `max_length` UTF-16 code units,
+/// e.g. `MAX_MESSAGE_LENGTH`, to be sent as separate messages.
+///
+/// Texts are split after the last line break that fits into a part, or after the last
+/// whitespace if there is none. Entities like URLs, mentions or commands are kept
+/// in one part if possible. Formatting entities like bold text are cut at the split and
+/// continued in the next part.
+pub fn split_text(
+    text: &str,
+    entities: &[MessageEntity],
+    max_length: usize,
+) -> Vec<(String, Vec<MessageEntity>)> {
+    let max_length = max_length.max(2);
+
+    // Byte index and UTF-16 offset of every char, and of the end of the text.
+    let mut positions = Vec::with_capacity(text.len() + 1);
+    let mut units = 0;
+    for (index, c) in text.char_indices() {
+        positions.push((index, units, c));
+        units += c.len_utf16();
+    }
+    positions.push((text.len(), units, '\0'));
+
+    let mut parts = Vec::new();
+    let mut start = 0;
+    while units - positions[start].1 > max_length {
+        let limit = positions[start].1 + max_length;
+        let fitting: Vec<usize> = (start + 1..positions.len())
+            .take_while(|&i| positions[i].1 <= limit)
+            .collect();
+        let splittable: Vec<usize> = fitting
+            .iter()
+            .copied()
+            .filter(|&i| !inside_atomic_entity(entities, positions[i].1))
+            .collect();
+        let after = |is_separator: fn(char) -> bool| {
+            splittable
+                .iter()
+                .rev()
+                .find(|&&i| is_separator(positions[i - 1].2))
+                .copied()
+        };
+        // Entities longer than a part have to be cut somewhere.
+        let end = after(|c| c == '\n')
+            .or_else(|| after(char::is_whitespace))
+            .or_else(|| splittable.last().copied())
+            .or_else(|| fitting.last().copied())
+            .unwrap_or(start + 1);
+
+        parts.push(part(text, entities, positions[start], positions[end]));
+        start = end;
+    }
+    if start + 1 < positions.len() || parts.is_empty() {
+        parts.push(part(
+            text,
+            entities,
+            positions[start],
+            positions[positions.len() - 1],
+        ));
+    }
+    parts
+}
+
+fn inside_atomic_entity(entities: &[MessageEntity], offset: usize) -> bool {
+    let offset = offset as Integer;
+    entities.iter().any(|entity| {
+        let atomic = matches!(
+            entity.kind,
+            MessageEntityKind::Mention
+                | MessageEntityKind::Hashtag
+                | MessageEntityKind::BotCommand
+                | MessageEntityKind::Url
+                | MessageEntityKind::Email
+                | MessageEntityKind::TextMention(_)
+                | MessageEntityKind::CustomEmoji(_)
+        );
+        atomic && entity.offset < offset && offset < entity.offset + entity.length
+    })
+}
+
+fn part(
+    text: &str,
+    entities: &[MessageEntity],
+    (start_index, start, _): (usize, usize, char),
+    (end_index, end, _): (usize, usize, char),
+) -> (String, Vec<MessageEntity>) {
+    let (start, end) = (start as Integer, end as Integer);
+    let entities = entities
+        .iter()
+        .filter_map(|entity| {
+            let entity_start = entity.offset.max(start);
+            let entity_end = (entity.offset + entity.length).min(end);
+            if entity_start >= entity_end {
+                return None;
+            }
+            Some(MessageEntity::new(
+                entity_start - start,
+                entity_end - entity_start,
+                entity.kind.clone(),
+            ))
+        })
+        .collect();
+    (text[start_index..end_index].to_string(), entities)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    MarkdownV2,
+    Html,
+}
+
+/// Composes formatted text, escaping every part, so user-supplied text can't break the
+/// formatting.
+///
+/// # Examples
+///
+/// ```rust
+/// use telegram_bot::TextBuilder;
+///
+/// let mut text = TextBuilder::html();
+/// text.text("Hello, ").bold("<Ann> & Bob").text("! See ").link("the docs", "https://example.org");
+/// assert_eq!(
+///     text.build(),
+///     "Hello, <b>&lt;Ann&gt; &amp; Bob</b>! See <a href=\"https://example.org\">the docs</a>"
+/// );
+///
+/// let mut text = TextBuilder::markdown_v2();
+/// text.text("Price: ").code("2.50").text(" (incl. tax)");
+/// assert_eq!(text.build(), "Price: `2.50` \\(incl\\. tax\\)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextBuilder {
+    format: Format,
+    text: String,
+}
+
+impl TextBuilder {
+    /// Compose text for `ParseMode::MarkdownV2`.
+    pub fn markdown_v2() -> Self {
+        TextBuilder {
+            format: Format::MarkdownV2,
+            text: String::new(),
+        }
+    }
+
+    /// Compose text for `ParseMode::Html`.
+    pub fn html() -> Self {
+        TextBuilder {
+            format: Format::Html,
+            text: String::new(),
+        }
+    }
+
+    /// The parse mode the text must be sent with.
+    pub fn parse_mode(&self) -> ParseMode {
+        match self.format {
+            Format::MarkdownV2 => ParseMode::MarkdownV2,
+            Format::Html => ParseMode::Html,
+        }
+    }
+
+    /// Append plain text.
+    pub fn text(&mut self, text: &str) -> &mut Self {
+        let escaped = self.escape(text);
+        self.text.push_str(&escaped);
+        self
+    }
+
+    /// Append bold text.
+    pub fn bold(&mut self, text: &str) -> &mut Self {
+        self.wrap(text, "*", "*", "<b>", "</b>")
+    }
+
+    /// Append italic text.
+    pub fn italic(&mut self, text: &str) -> &mut Self {
+        // Telegram ignores the `\r`, which keeps the closing underscore apart from the
+        // underscores of underlined text that may follow.
+        self.wrap(text, "_", "_\r", "<i>", "</i>")
+    }
+
+    /// Append underlined text.
+    pub fn underline(&mut self, text: &str) -> &mut Self {
+        self.wrap(text, "__", "__", "<u>", "</u>")
+    }
+
+    /// Append strikethrough text.
+    pub fn strikethrough(&mut self, text: &str) -> &mut Self {
+        self.wrap(text, "~", "~", "<s>", "</s>")
+    }
+
+    /// Append text that is hidden until the user taps on it.
+    pub fn spoiler(&mut self, text: &str) -> &mut Self {
+        self.wrap(text, "||", "||", "<tg-spoiler>", "</tg-spoiler>")
+    }
+
+    /// Append inline monospaced code.
+    pub fn code(&mut self, code: &str) -> &mut Self {
+        match self.format {
+            Format::MarkdownV2 => {
+                let escaped = escape_with(code, |c| c == '`' || c == '\\');
+                let _ = write!(self.text, "`{}`", escaped);
+            }
+            Format::Html => {
+                let _ = write!(self.text, "<code>{}</code>", escape_html(code));
+            }
+        }
+        self
+    }
+
+    /// Append a block of monospaced code, optionally highlighted as the given language.
+    pub fn pre(&mut self, code: &str, language: Option<&str>) -> &mut Self {
+        match self.format {
+            Format::MarkdownV2 => {
+                let escaped = escape_with(code, |c| c == '`' || c == '\\');
+                let language = language.unwrap_or_default();
+                let _ = write!(self.text, "```{}\n{}\n```", language, escaped);
+            }
+            Format::Html => match language {
+                Some(language) => {
+                    let _ = write!(
+                        self.text,
+                        "<pre><code class=\"language-{}\">{}</code></pre>",
+                        escape_html(language),
+                        escape_html(code)
+                    );
+                }
+                None => {
+                    let _ = write!(self.text, "<pre>{}</pre>", escape_html(code));
+                }
+            },
+        }
+        self
+    }
+
+    /// Append a link to the given URL.
+    pub fn link(&mut self, text: &str, url: &str) -> &mut Self {
+        match self.format {
+            Format::MarkdownV2 => {
+                let url = escape_with(url, |c| c == ')' || c == '\\');
+                let _ = write!(self.text, "[{}]({})", escape_markdown_v2(text), url);
+            }
+            Format::Html => {
+                let _ = write!(
+                    self.text,
+                    "<a href=\"{}\">{}</a>",
+                    escape_html(url),
+                    escape_html(text)
+                );
+            }
+        }
+        self
+    }
+
+    /// The composed text.
+    pub fn build(&self) -> String {
+        self.text.clone()
+    }
+
+    fn escape(&self, text: &str) -> String {
+        match self.format {
+            Format::MarkdownV2 => escape_markdown_v2(text),
+            Format::Html => escape_html(text),
+        }
+    }
+
+    fn wrap(
+        &mut self,
+        text: &str,
+        markdown_start: &str,
+        markdown_end: &str,
+        html_start: &str,
+        html_end: &str,
+    ) -> &mut Self {
+        let (start, end) = match self.format {
+            Format::MarkdownV2 => (markdown_start, markdown_end),
+            Format::Html => (html_start, html_end),
+        };
+        let escaped = self.escape(text);
+        let _ = write!(self.text, "{}{}{}", start, escaped, end);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape() {
+        assert_eq!(
+            escape_markdown_v2("*1* + [a](b) = c_d!\\"),
+            "\\*1\\* \\+ \\[a\\]\\(b\\) \\= c\\_d\\!\\\\"
+        );
+        assert_eq!(
+            escape_html("<a href=\"x\">&</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+
+        let mut text = TextBuilder::markdown_v2();
+        text.italic("a")
+            .underline("b")
+            .spoiler("c|d")
+            .link("e", "https://x.org/(f)");
+        assert_eq!(text.build(), "_a_\r__b__||c\\|d||[e](https://x.org/(f\\))");
+    }
+
+    #[test]
+    fn split() {
+        let text = "Hello world, see https://example.org\nand more";
+        let entities = vec![
+            MessageEntity::new(0, 11, MessageEntityKind::Bold),
+            MessageEntity::new(17, 19, MessageEntityKind::Url),
+        ];
+
+        let parts = split_text(text, &entities, 25);
+        assert_eq!(
+            parts,
+            vec![
+                (
+                    "Hello world, see ".to_string(),
+                    vec![MessageEntity::new(0, 11, MessageEntityKind::Bold)]
+                ),
+                (
+                    "https://example.org\n".to_string(),
+                    vec![MessageEntity::new(0, 19, MessageEntityKind::Url)]
+                ),
+                ("and more".to_string(), vec![]),
+            ]
+        );
+
+        let parts = split_text(
+            "🎉🎉🎉",
+            &[MessageEntity::new(0, 6, MessageEntityKind::Bold)],
+            3,
+        );
+        assert_eq!(
+            parts,
+            vec![
+                (
+                    "🎉".to_string(),
+                    vec![MessageEntity::new(0, 2, MessageEntityKind::Bold)]
+                ),
+                (
+                    "🎉".to_string(),
+                    vec![MessageEntity::new(0, 2, MessageEntityKind::Bold)]
+                ),
+                (
+                    "🎉".to_string(),
+                    vec![MessageEntity::new(0, 2, MessageEntityKind::Bold)]
+                ),
+            ]
+        );
+    }
+}
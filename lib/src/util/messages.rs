@@ -4,7 +4,8 @@
 
 use crate::prelude::CanGetFile;
 use crate::types::{
-    requests::get_file::GetFile, ChannelPost, Message, MessageKind, MessageOrChannelPost,
+    requests::get_file::GetFile, ChannelPost, Message, MessageEntity, MessageEntityKind,
+    MessageKind, MessageOrChannelPost, PaidMedia,
 };
 
 /// A trait to obtain text from a message.
@@ -35,6 +36,7 @@ impl MessageText for MessageKind {
         match self {
             MessageKind::Text { data, .. } => Some(data.to_owned()),
             MessageKind::Audio { data } => data.title.to_owned(),
+            MessageKind::Animation { caption, .. } => caption.to_owned(),
             MessageKind::Document { data, caption } => {
                 caption.clone().or_else(|| data.file_name.clone())
             }
@@ -47,6 +49,22 @@ impl MessageText for MessageKind {
             MessageKind::Location { .. } => None,
             MessageKind::Poll { data } => Some(data.question.to_owned()),
             MessageKind::Venue { data } => Some(data.title.to_owned()),
+            MessageKind::Dice { .. } => None,
+            MessageKind::Game { data } => Some(data.title.to_owned()),
+            MessageKind::Invoice { data } => Some(data.title.to_owned()),
+            MessageKind::SuccessfulPayment { .. } => None,
+            MessageKind::RefundedPayment { .. } => None,
+            MessageKind::WebAppData { data } => Some(data.data.to_owned()),
+            MessageKind::VideoChatScheduled { .. } => None,
+            MessageKind::VideoChatStarted { .. } => None,
+            MessageKind::VideoChatEnded { .. } => None,
+            MessageKind::VideoChatParticipantsInvited { .. } => None,
+            MessageKind::PaidMedia { caption, .. } => caption.to_owned(),
+            MessageKind::Giveaway { .. } => None,
+            MessageKind::GiveawayCreated { .. } => None,
+            MessageKind::GiveawayWinners { .. } => None,
+            MessageKind::GiveawayCompleted { .. } => None,
+            MessageKind::PassportData { .. } => None,
             MessageKind::NewChatMembers { .. } => None,
             MessageKind::LeftChatMember { .. } => None,
             MessageKind::NewChatTitle { data } => Some(data.to_owned()),
@@ -69,6 +87,97 @@ impl MessageText for ChannelPost {
     }
 }
 
+/// A trait to extract entities like commands, mentions or URLs from the text of a message.
+///
+/// Only text messages carry entities, other messages have none.
+pub trait MessageEntities {
+    /// Obtain the entities of the text, each with the part of the text it refers to.
+    fn entities_with_text(&self) -> Vec<(&MessageEntity, &str)>;
+
+    /// Bot commands including the slash and any bot username, e.g. `/start@my_bot`.
+    fn bot_commands(&self) -> Vec<&str> {
+        self.entities_with_text()
+            .into_iter()
+            .filter(|(entity, _)| entity.kind == MessageEntityKind::BotCommand)
+            .map(|(_, text)| text)
+            .collect()
+    }
+
+    /// Mentions of users by username, including the `@`.
+    fn mentions(&self) -> Vec<&str> {
+        self.entities_with_text()
+            .into_iter()
+            .filter(|(entity, _)| entity.kind == MessageEntityKind::Mention)
+            .map(|(_, text)| text)
+            .collect()
+    }
+
+    /// URLs in the text, and the URLs of text links.
+    fn urls(&self) -> Vec<&str> {
+        self.entities_with_text()
+            .into_iter()
+            .filter_map(|(entity, text)| match entity.kind {
+                MessageEntityKind::Url => Some(text),
+                MessageEntityKind::TextLink(ref url) => Some(url.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Hashtags including the `#`.
+    fn hashtags(&self) -> Vec<&str> {
+        self.entities_with_text()
+            .into_iter()
+            .filter(|(entity, _)| entity.kind == MessageEntityKind::Hashtag)
+            .map(|(_, text)| text)
+            .collect()
+    }
+
+    /// Identifiers of the custom emoji, to be used with `GetCustomEmojiStickers`.
+    fn custom_emoji(&self) -> Vec<&str> {
+        self.entities_with_text()
+            .into_iter()
+            .filter_map(|(entity, _)| match entity.kind {
+                MessageEntityKind::CustomEmoji(ref id) => Some(id.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl MessageEntities for MessageOrChannelPost {
+    fn entities_with_text(&self) -> Vec<(&MessageEntity, &str)> {
+        match self {
+            MessageOrChannelPost::Message(msg) => msg.entities_with_text(),
+            MessageOrChannelPost::ChannelPost(post) => post.entities_with_text(),
+        }
+    }
+}
+
+impl MessageEntities for Message {
+    fn entities_with_text(&self) -> Vec<(&MessageEntity, &str)> {
+        self.kind.entities_with_text()
+    }
+}
+
+impl MessageEntities for ChannelPost {
+    fn entities_with_text(&self) -> Vec<(&MessageEntity, &str)> {
+        self.kind.entities_with_text()
+    }
+}
+
+impl MessageEntities for MessageKind {
+    fn entities_with_text(&self) -> Vec<(&MessageEntity, &str)> {
+        match self {
+            MessageKind::Text { data, entities } => entities
+                .iter()
+                .filter_map(|entity| Some((entity, entity.text_in(data)?)))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
 /// A trait to obtain `GetFile` requests from a message.
 ///
 /// Many message kinds such as `Sticker` return a single `GetFile`.
@@ -99,6 +208,13 @@ impl MessageGetFiles for MessageKind {
         match self {
             MessageKind::Text { .. } => None,
             MessageKind::Audio { data } => Some(vec![data.get_file()]),
+            MessageKind::Animation { data, .. } => {
+                let mut files = vec![data.get_file()];
+                if let Some(thumb) = &data.thumb {
+                    files.push(thumb.get_file());
+                }
+                Some(files)
+            }
             MessageKind::Document { data, .. } => {
                 let mut files = vec![data.get_file()];
                 if let Some(thumb) = &data.thumb {
@@ -129,6 +245,54 @@ impl MessageGetFiles for MessageKind {
             MessageKind::Location { .. } => None,
             MessageKind::Poll { .. } => None,
             MessageKind::Venue { .. } => None,
+            MessageKind::Dice { .. } => None,
+            MessageKind::Game { data } => Some(data.photo.iter().map(|f| f.get_file()).collect()),
+            MessageKind::Invoice { .. } => None,
+            MessageKind::SuccessfulPayment { .. } => None,
+            MessageKind::RefundedPayment { .. } => None,
+            MessageKind::WebAppData { .. } => None,
+            MessageKind::VideoChatScheduled { .. } => None,
+            MessageKind::VideoChatStarted { .. } => None,
+            MessageKind::VideoChatEnded { .. } => None,
+            MessageKind::VideoChatParticipantsInvited { .. } => None,
+            MessageKind::PaidMedia { data, .. } => {
+                let mut files = Vec::new();
+                for media in &data.paid_media {
+                    match media {
+                        PaidMedia::Photo { photo } => {
+                            files.extend(photo.iter().map(|f| f.get_file()))
+                        }
+                        PaidMedia::Video { video } => {
+                            files.push(video.get_file());
+                            if let Some(thumb) = &video.thumb {
+                                files.push(thumb.get_file());
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+                Some(files)
+            }
+            MessageKind::Giveaway { .. } => None,
+            MessageKind::GiveawayCreated { .. } => None,
+            MessageKind::GiveawayWinners { .. } => None,
+            MessageKind::GiveawayCompleted { .. } => None,
+            MessageKind::PassportData { data } => Some(
+                data.data
+                    .iter()
+                    .flat_map(|element| {
+                        element
+                            .files
+                            .iter()
+                            .flatten()
+                            .chain(element.front_side.iter())
+                            .chain(element.reverse_side.iter())
+                            .chain(element.selfie.iter())
+                            .chain(element.translation.iter().flatten())
+                    })
+                    .map(|file| file.get_file())
+                    .collect(),
+            ),
             MessageKind::NewChatMembers { .. } => None,
             MessageKind::LeftChatMember { .. } => None,
             MessageKind::NewChatTitle { .. } => None,
@@ -152,3 +316,30 @@ impl MessageGetFiles for ChannelPost {
         self.kind.get_files()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entities_with_utf16_offsets() {
+        let kind = MessageKind::Text {
+            data: "Ä 🎉 #party with @fränk at https://example.org".to_string(),
+            entities: vec![
+                MessageEntity::new(5, 6, MessageEntityKind::Hashtag),
+                MessageEntity::new(17, 6, MessageEntityKind::Mention),
+                MessageEntity::new(27, 19, MessageEntityKind::Url),
+                MessageEntity::new(2, 2, MessageEntityKind::CustomEmoji("42".to_string())),
+                MessageEntity::new(2, 1, MessageEntityKind::Bold),
+                MessageEntity::new(40, 10, MessageEntityKind::Italic),
+            ],
+        };
+
+        assert_eq!(kind.hashtags(), vec!["#party"]);
+        assert_eq!(kind.mentions(), vec!["@fränk"]);
+        assert_eq!(kind.urls(), vec!["https://example.org"]);
+        assert_eq!(kind.custom_emoji(), vec!["42"]);
+        // Entities splitting a surrogate pair or exceeding the text are skipped.
+        assert_eq!(kind.entities_with_text().len(), 4);
+    }
+}
@@ -1,3 +1,5 @@
 //! Traits that extends raw types functionality
 
+pub mod formatting;
 pub mod messages;
+pub mod streams;
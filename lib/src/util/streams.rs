@@ -0,0 +1,262 @@
+//! Stream adapters for streams of updates, like `UpdatesStream`.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::Stream;
+
+use crate::errors::Error;
+use crate::types::{
+    ChatRef, Message, MessageEntityKind, MessageKind, ToChatRef, Update, UpdateKind,
+};
+
+/// A text message, see `UpdatesStreamExt::text_messages`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextMessage {
+    /// Text of the message.
+    pub text: String,
+    /// The whole message.
+    pub message: Message,
+}
+
+impl TextMessage {
+    /// Returns the text if the message is a text message.
+    pub fn from_message(message: Message) -> Option<Self> {
+        let text = match message.kind {
+            MessageKind::Text { ref data, .. } => data.clone(),
+            _ => return None,
+        };
+        Some(TextMessage { text, message })
+    }
+}
+
+/// A bot command like `/start@my_bot some arguments`, see `UpdatesStreamExt::commands`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Command {
+    /// Name of the command without the leading slash, e.g. `start`.
+    pub name: String,
+    /// Username of the bot the command is addressed to, e.g. `my_bot` for `/start@my_bot`.
+    pub bot_name: Option<String>,
+    /// Text following the command, without leading and trailing whitespace.
+    pub arguments: String,
+    /// The message containing the command.
+    pub message: Message,
+}
+
+impl Command {
+    /// Parses the command at the start of a text message.
+    pub fn from_message(message: Message) -> Option<Self> {
+        let (name, bot_name, arguments) = match message.kind {
+            MessageKind::Text {
+                ref data,
+                ref entities,
+            } => {
+                let is_command = entities.iter().any(|entity| {
+                    entity.offset == 0 && entity.kind == MessageEntityKind::BotCommand
+                });
+                if !is_command {
+                    return None;
+                }
+                parse_command(data)?
+            }
+            _ => return None,
+        };
+
+        Some(Command {
+            name,
+            bot_name,
+            arguments,
+            message,
+        })
+    }
+
+    /// The arguments split at whitespace.
+    pub fn args(&self) -> impl Iterator<Item = &str> {
+        self.arguments.split_whitespace()
+    }
+
+    /// True, if the command is addressed to the bot with the given username, or to no bot
+    /// in particular.
+    pub fn is_addressed_to(&self, bot_name: &str) -> bool {
+        match self.bot_name {
+            Some(ref name) => name.eq_ignore_ascii_case(bot_name.trim_start_matches('@')),
+            None => true,
+        }
+    }
+}
+
+fn parse_command(text: &str) -> Option<(String, Option<String>, String)> {
+    let text = text.strip_prefix('/')?;
+    let (command, arguments) = match text.find(char::is_whitespace) {
+        Some(end) => text.split_at(end),
+        None => (text, ""),
+    };
+    let (name, bot_name) = match command.find('@') {
+        Some(at) => (&command[..at], Some(command[at + 1..].to_string())),
+        None => (command, None),
+    };
+    if name.is_empty() {
+        return None;
+    }
+    Some((name.to_string(), bot_name, arguments.trim().to_string()))
+}
+
+/// Adapters for streams of updates, to reduce the boilerplate of matching on every update.
+///
+/// Errors of the underlying stream are passed through.
+///
+/// # Examples
+///
+/// ```rust
+/// # use telegram_bot::{Api, prelude::*};
+/// use futures::StreamExt;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let api: Api = Api::new("token");
+/// # if false {
+/// let mut commands = api.stream().commands();
+/// while let Some(Ok(command)) = commands.next().await {
+///     if command.name == "start" {
+///         api.spawn(command.message.text_reply("Hello!"));
+///     }
+/// }
+/// # }
+/// # }
+/// ```
+pub trait UpdatesStreamExt: Stream<Item = Result<Update, Error>> + Sized {
+    /// Only yield new text messages.
+    fn text_messages(self) -> TextMessages<Self> {
+        TextMessages { stream: self }
+    }
+
+    /// Only yield new messages which start with a bot command.
+    fn commands(self) -> Commands<Self> {
+        Commands { stream: self }
+    }
+
+    /// Only yield updates that belong to the given chat, see `UpdateKind::chat`.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_chat<C: ToChatRef>(self, chat: C) -> FromChat<Self> {
+        FromChat {
+            stream: self,
+            chat: chat.to_chat_ref(),
+        }
+    }
+}
+
+impl<S> UpdatesStreamExt for S where S: Stream<Item = Result<Update, Error>> {}
+
+/// Polls `stream` until `f` maps an update to an item.
+fn poll_filter_map<S, T, F>(
+    stream: &mut S,
+    cx: &mut Context,
+    mut f: F,
+) -> Poll<Option<Result<T, Error>>>
+where
+    S: Stream<Item = Result<Update, Error>> + Unpin,
+    F: FnMut(Update) -> Option<T>,
+{
+    loop {
+        match Pin::new(&mut *stream).poll_next(cx) {
+            Poll::Ready(Some(Ok(update))) => {
+                if let Some(item) = f(update) {
+                    return Poll::Ready(Some(Ok(item)));
+                }
+            }
+            Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+            Poll::Ready(None) => return Poll::Ready(None),
+            Poll::Pending => return Poll::Pending,
+        }
+    }
+}
+
+fn new_message(update: Update) -> Option<Message> {
+    match update.kind {
+        UpdateKind::Message(message) => Some(message),
+        _ => None,
+    }
+}
+
+/// Stream returned by `UpdatesStreamExt::text_messages`.
+#[must_use = "streams do nothing unless polled"]
+pub struct TextMessages<S> {
+    stream: S,
+}
+
+impl<S> Stream for TextMessages<S>
+where
+    S: Stream<Item = Result<Update, Error>> + Unpin,
+{
+    type Item = Result<TextMessage, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        poll_filter_map(&mut self.get_mut().stream, cx, |update| {
+            new_message(update).and_then(TextMessage::from_message)
+        })
+    }
+}
+
+/// Stream returned by `UpdatesStreamExt::commands`.
+#[must_use = "streams do nothing unless polled"]
+pub struct Commands<S> {
+    stream: S,
+}
+
+impl<S> Stream for Commands<S>
+where
+    S: Stream<Item = Result<Update, Error>> + Unpin,
+{
+    type Item = Result<Command, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        poll_filter_map(&mut self.get_mut().stream, cx, |update| {
+            new_message(update).and_then(Command::from_message)
+        })
+    }
+}
+
+/// Stream returned by `UpdatesStreamExt::from_chat`.
+#[must_use = "streams do nothing unless polled"]
+pub struct FromChat<S> {
+    stream: S,
+    chat: ChatRef,
+}
+
+impl<S> Stream for FromChat<S>
+where
+    S: Stream<Item = Result<Update, Error>> + Unpin,
+{
+    type Item = Result<Update, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let ref_mut = self.get_mut();
+        let chat = &ref_mut.chat;
+        poll_filter_map(&mut ref_mut.stream, cx, |update| {
+            if update.kind.chat().as_ref() == Some(chat) {
+                Some(update)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!(
+            parse_command("/start@my_bot  a b "),
+            Some(("start".into(), Some("my_bot".into()), "a b".into()))
+        );
+        assert_eq!(
+            parse_command("/help"),
+            Some(("help".into(), None, "".into()))
+        );
+        assert_eq!(parse_command("/ start"), None);
+        assert_eq!(parse_command("start"), None);
+    }
+}
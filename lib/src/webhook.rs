@@ -0,0 +1,299 @@
+//! Helpers for receiving updates with a webhook, set up with `SetWebhook`.
+//!
+//! The crate doesn't include a webhook server, these helpers are meant to be used in the
+//! HTTP server of the bot.
+
+use std::collections::{HashSet, VecDeque};
+use std::net::{IpAddr, Ipv4Addr};
+
+use hyper::HeaderMap;
+use telegram_bot_raw::{Body, Integer, Request, RequestUrl, Update};
+
+use crate::errors::{Error, ErrorKind};
+
+/// The header in which Telegram sends the `secret_token` set with `SetWebhook`.
+pub const SECRET_TOKEN_HEADER: &str = "X-Telegram-Bot-Api-Secret-Token";
+
+/// The subnets Telegram sends webhook requests from, as addresses and prefix lengths.
+pub const TELEGRAM_SUBNETS: &[(Ipv4Addr, u8)] = &[
+    (Ipv4Addr::new(149, 154, 160, 0), 20),
+    (Ipv4Addr::new(91, 108, 4, 0), 22),
+];
+
+/// Check that a webhook request carries the `secret_token` set with `SetWebhook`, so it was
+/// sent by Telegram. Requests failing the check should be answered with `403 Forbidden`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hyper::HeaderMap;
+/// use telegram_bot::webhook::{verify_secret_token, SECRET_TOKEN_HEADER};
+///
+/// let mut headers = HeaderMap::new();
+/// assert!(!verify_secret_token(&headers, "s3cr3t"));
+/// headers.insert(SECRET_TOKEN_HEADER, "s3cr3t".parse().unwrap());
+/// assert!(verify_secret_token(&headers, "s3cr3t"));
+/// ```
+pub fn verify_secret_token(headers: &HeaderMap, secret: &str) -> bool {
+    match headers.get(SECRET_TOKEN_HEADER) {
+        Some(token) => constant_time_eq(token.as_bytes(), secret.as_bytes()),
+        None => false,
+    }
+}
+
+/// Serialize a request as the body of the response to a webhook request, so Telegram
+/// executes it without another round trip, e.g. to answer an update. The body must be
+/// sent with `Content-Type: application/json`.
+///
+/// Telegram doesn't report the result of such a request, so requests whose response is
+/// needed have to be sent with `Api::send`. Requests uploading files can't be sent this way.
+///
+/// # Examples
+///
+/// ```rust
+/// use telegram_bot::webhook::reply_body;
+/// use telegram_bot::{ChatId, SendMessage};
+///
+/// let body = reply_body(&SendMessage::new(ChatId::new(42), "Hi")).unwrap();
+/// assert_eq!(body, r#"{"method":"sendMessage","chat_id":42,"text":"Hi"}"#);
+/// ```
+pub fn reply_body<Req: Request>(request: &Req) -> Result<String, Error> {
+    let request = request.serialize().map_err(ErrorKind::from)?;
+    let method = match request.url {
+        RequestUrl::Method(method) => method,
+        _ => return Err(ErrorKind::UnsupportedWebhookReply.into()),
+    };
+    match request.body {
+        Body::Json(body) => {
+            // Requests without parameters are serialized as `null`.
+            let fields = body
+                .strip_prefix('{')
+                .and_then(|body| body.strip_suffix('}'))
+                .unwrap_or_default();
+            if fields.is_empty() {
+                Ok(format!("{{\"method\":\"{}\"}}", method))
+            } else {
+                Ok(format!("{{\"method\":\"{}\",{}}}", method, fields))
+            }
+        }
+        Body::Empty => Ok(format!("{{\"method\":\"{}\"}}", method)),
+        _ => Err(ErrorKind::UnsupportedWebhookReply.into()),
+    }
+}
+
+/// The subnets webhook requests are accepted from, by default `TELEGRAM_SUBNETS`.
+///
+/// Requests from other addresses should be rejected, as a second line of defense next to
+/// the secret token. IPv4 addresses mapped to IPv6, as reported by dual-stack sockets, are
+/// checked as IPv4 addresses.
+///
+/// # Examples
+///
+/// ```rust
+/// use telegram_bot::webhook::IpAllowlist;
+///
+/// let mut allowlist = IpAllowlist::telegram();
+/// assert!(allowlist.contains("149.154.167.220".parse().unwrap()));
+/// assert!(!allowlist.contains("10.0.0.1".parse().unwrap()));
+///
+/// // Allow requests forwarded by a local proxy.
+/// allowlist.allow("127.0.0.1".parse().unwrap(), 32);
+/// assert!(allowlist.contains("127.0.0.1".parse().unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpAllowlist {
+    subnets: Vec<(IpAddr, u8)>,
+}
+
+impl IpAllowlist {
+    /// Allow the subnets of Telegram.
+    pub fn telegram() -> Self {
+        IpAllowlist {
+            subnets: TELEGRAM_SUBNETS
+                .iter()
+                .map(|&(address, prefix_len)| (IpAddr::V4(address), prefix_len))
+                .collect(),
+        }
+    }
+
+    /// Allow no addresses, to list the subnets with `allow`.
+    pub fn empty() -> Self {
+        IpAllowlist {
+            subnets: Vec::new(),
+        }
+    }
+
+    /// Allow the subnet of the addresses sharing the first `prefix_len` bits with `address`.
+    pub fn allow(&mut self, address: IpAddr, prefix_len: u8) -> &mut Self {
+        self.subnets.push((address, prefix_len));
+        self
+    }
+
+    /// True, if requests from `address` are accepted.
+    pub fn contains(&self, address: IpAddr) -> bool {
+        let address = match address {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(address, IpAddr::V4),
+            address => address,
+        };
+        self.subnets
+            .iter()
+            .any(|&(subnet, prefix_len)| match (subnet, address) {
+                (IpAddr::V4(subnet), IpAddr::V4(address)) => prefix_eq(
+                    u32::from(subnet).into(),
+                    u32::from(address).into(),
+                    32,
+                    prefix_len,
+                ),
+                (IpAddr::V6(subnet), IpAddr::V6(address)) => {
+                    prefix_eq(subnet.into(), address.into(), 128, prefix_len)
+                }
+                _ => false,
+            })
+    }
+}
+
+impl Default for IpAllowlist {
+    fn default() -> Self {
+        Self::telegram()
+    }
+}
+
+/// True, if the first `prefix_len` of the `bits` low bits of `a` and `b` are equal.
+fn prefix_eq(a: u128, b: u128, bits: u32, prefix_len: u8) -> bool {
+    let prefix_len = u32::from(prefix_len).min(bits);
+    if prefix_len == 0 {
+        return true;
+    }
+    let shift = bits - prefix_len;
+    a >> shift == b >> shift
+}
+
+/// Remembers the ids of the most recently received updates, to drop updates Telegram
+/// delivers again because the response to their webhook request got lost or was too slow.
+///
+/// # Examples
+///
+/// ```rust
+/// use telegram_bot::webhook::RecentUpdates;
+///
+/// let mut recent = RecentUpdates::new(2);
+/// assert!(recent.insert_id(1));
+/// assert!(recent.insert_id(2));
+/// assert!(!recent.insert_id(1));
+///
+/// // Only the last two update ids are remembered.
+/// assert!(recent.insert_id(3));
+/// assert!(recent.insert_id(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecentUpdates {
+    capacity: usize,
+    order: VecDeque<Integer>,
+    ids: HashSet<Integer>,
+}
+
+impl RecentUpdates {
+    /// Remember the ids of the last `capacity` updates, at least one.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        RecentUpdates {
+            capacity,
+            order: VecDeque::with_capacity(capacity),
+            ids: HashSet::with_capacity(capacity),
+        }
+    }
+
+    /// Record that the update was received. Returns false, if it is a duplicate and
+    /// should be dropped.
+    pub fn insert(&mut self, update: &Update) -> bool {
+        self.insert_id(update.id)
+    }
+
+    /// Record that the update with the given id was received. Returns false, if it is
+    /// a duplicate and should be dropped.
+    pub fn insert_id(&mut self, id: Integer) -> bool {
+        if !self.ids.insert(id) {
+            return false;
+        }
+        if self.order.len() == self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+        self.order.push_back(id);
+        true
+    }
+}
+
+impl Default for RecentUpdates {
+    /// Remembers the last 1000 update ids.
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+/// Compares without returning early, so the time taken doesn't reveal how much of the
+/// token was guessed correctly.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secret_token() {
+        let mut headers = HeaderMap::new();
+        headers.insert(SECRET_TOKEN_HEADER, "abc".parse().unwrap());
+        assert!(verify_secret_token(&headers, "abc"));
+        assert!(!verify_secret_token(&headers, "abd"));
+        assert!(!verify_secret_token(&headers, "ab"));
+        assert!(!verify_secret_token(&headers, ""));
+        assert!(!verify_secret_token(&HeaderMap::new(), "abc"));
+    }
+
+    #[test]
+    fn reply() {
+        use telegram_bot_raw::{
+            ChatId, ForceReply, GetMe, InputFileUpload, SendDocument, SendMessage,
+        };
+
+        assert_eq!(reply_body(&GetMe).unwrap(), r#"{"method":"getMe"}"#);
+        let mut message = SendMessage::new(ChatId::new(1), "a");
+        message.reply_markup(ForceReply::new());
+        assert_eq!(
+            reply_body(&message).unwrap(),
+            r#"{"method":"sendMessage","chat_id":1,"text":"a","reply_markup":{"force_reply":true}}"#
+        );
+        let upload = SendDocument::new(ChatId::new(1), InputFileUpload::with_path("a.txt"));
+        assert!(reply_body(&upload).is_err());
+    }
+
+    #[test]
+    fn ip_allowlist() {
+        let allowlist = IpAllowlist::default();
+        for address in &[
+            "149.154.160.0",
+            "149.154.175.255",
+            "91.108.7.1",
+            "::ffff:91.108.4.9",
+        ] {
+            assert!(allowlist.contains(address.parse().unwrap()), "{}", address);
+        }
+        for address in &["149.154.176.0", "91.108.8.0", "::1", "2001:db8::1"] {
+            assert!(!allowlist.contains(address.parse().unwrap()), "{}", address);
+        }
+
+        let mut allowlist = IpAllowlist::empty();
+        assert!(!allowlist.contains("127.0.0.1".parse().unwrap()));
+        allowlist.allow("2001:db8::".parse().unwrap(), 32);
+        assert!(allowlist.contains("2001:db8:1::1".parse().unwrap()));
+        assert!(!allowlist.contains("2001:db9::1".parse().unwrap()));
+        allowlist.allow("0.0.0.0".parse().unwrap(), 0);
+        assert!(allowlist.contains("127.0.0.1".parse().unwrap()));
+    }
+}
@@ -10,10 +10,13 @@ pub struct Error(ErrorKind);
 pub(crate) enum ErrorKind {
     EmptyBody,
     TelegramError {
+        error_code: Option<Integer>,
         description: String,
         parameters: Option<ResponseParameters>,
     },
     DetachedError(String),
+    TooManyAttachments,
+    CallbackDataTooLong(usize),
     Json(::serde_json::Error),
 }
 
@@ -23,6 +26,29 @@ impl From<::serde_json::Error> for ErrorKind {
     }
 }
 
+impl Error {
+    /// Error code of a request rejected by Telegram, which corresponds to the HTTP
+    /// status code.
+    pub fn error_code(&self) -> Option<Integer> {
+        match &self.0 {
+            ErrorKind::TelegramError { error_code, .. } => *error_code,
+            _ => None,
+        }
+    }
+
+    /// In case of exceeding flood control, the number of seconds left to wait
+    /// before the request can be repeated.
+    pub fn retry_after(&self) -> Option<Integer> {
+        match &self.0 {
+            ErrorKind::TelegramError {
+                parameters: Some(parameters),
+                ..
+            } => parameters.retry_after,
+            _ => None,
+        }
+    }
+}
+
 impl From<ErrorKind> for Error {
     fn from(kind: ErrorKind) -> Self {
         Error(kind)
@@ -36,6 +62,7 @@ impl fmt::Display for Error {
             ErrorKind::TelegramError {
                 description,
                 parameters,
+                ..
             } => {
                 f.write_str(&description)?;
                 if let Some(parameters) = parameters {
@@ -49,6 +76,12 @@ impl fmt::Display for Error {
                 Ok(())
             }
             ErrorKind::DetachedError(s) => f.write_str(&s),
+            ErrorKind::TooManyAttachments => write!(f, "too many files to attach"),
+            ErrorKind::CallbackDataTooLong(length) => write!(
+                f,
+                "callback data is {} bytes long, at most 64 bytes are allowed",
+                length
+            ),
             ErrorKind::Json(error) => write!(f, "{}", error),
         }
     }
@@ -2,12 +2,15 @@ use std::fmt;
 
 use bytes::Bytes;
 
-use crate::types::Text;
+use crate::types::{ChatId, ChatRef, Integer, Text};
 use crate::url::telegram_api_url;
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum RequestUrl {
     Method(&'static str),
+    File(String),
+    /// Complete URL of a method or file, e.g. on a self-hosted Bot API server.
+    Url(String),
 }
 
 impl RequestUrl {
@@ -15,9 +18,20 @@ impl RequestUrl {
         RequestUrl::Method(method)
     }
 
+    pub fn file<P: Into<String>>(file_path: P) -> Self {
+        RequestUrl::File(file_path.into())
+    }
+
     pub fn url(&self, token: &str) -> String {
+        self.url_on(&telegram_api_url(), token)
+    }
+
+    /// URL on the Bot API server at `server`, which must end with a `/`.
+    pub fn url_on(&self, server: &str, token: &str) -> String {
         match self {
-            &RequestUrl::Method(method) => format!("{}bot{}/{}", telegram_api_url(), token, method),
+            &RequestUrl::Method(method) => format!("{}bot{}/{}", server, token, method),
+            RequestUrl::File(path) => format!("{}file/bot{}/{}", server, token, path),
+            RequestUrl::Url(url) => url.clone(),
         }
     }
 }
@@ -68,11 +82,86 @@ impl HttpRequest {
     pub fn name(&self) -> &'static str {
         match self.url {
             RequestUrl::Method(method) => method,
+            RequestUrl::File(_) => "file",
+            RequestUrl::Url(_) => "url",
         }
     }
+
+    /// Chat the request is addressed to, read from its `chat_id` parameter.
+    pub fn chat_id(&self) -> Option<ChatRef> {
+        let chat_id = match &self.body {
+            Body::Json(json) => {
+                let value: serde_json::Value = serde_json::from_str(json).ok()?;
+                match value.get("chat_id")? {
+                    serde_json::Value::Number(id) => return id.as_i64().map(chat_ref_from_id),
+                    serde_json::Value::String(username) => username.clone(),
+                    _ => return None,
+                }
+            }
+            Body::Multipart(multipart) => multipart
+                .iter()
+                .find_map(|(name, value)| match (*name, value) {
+                    ("chat_id", MultipartValue::Text(text)) => Some(text.as_str()),
+                    _ => None,
+                })?
+                .to_owned(),
+            Body::Empty | Body::__Nonexhaustive => return None,
+        };
+
+        Some(match chat_id.parse::<Integer>() {
+            Ok(id) => chat_ref_from_id(id),
+            Err(_) => ChatRef::ChannelUsername(chat_id),
+        })
+    }
+
+    /// Sets a parameter of the request, replacing its previous value. Returns `false` if
+    /// the request has no parameters that could be changed.
+    ///
+    /// This allows to adjust requests of any type after they were serialized, e.g. to
+    /// set `disable_notification` on every message.
+    pub fn set_param(&mut self, name: &'static str, value: serde_json::Value) -> bool {
+        match &mut self.body {
+            Body::Json(json) => {
+                let mut params: serde_json::Map<String, serde_json::Value> =
+                    match serde_json::from_str(json) {
+                        Ok(params) => params,
+                        Err(_) => return false,
+                    };
+                params.insert(name.to_owned(), value);
+                match serde_json::to_string(&params) {
+                    Ok(params) => *json = params,
+                    Err(_) => return false,
+                }
+                true
+            }
+            Body::Multipart(multipart) => {
+                let text = match value {
+                    serde_json::Value::String(text) => text,
+                    value => value.to_string(),
+                };
+                multipart.retain(|(param, _)| *param != name);
+                multipart.push((name, MultipartValue::Text(text.into())));
+                true
+            }
+            Body::Empty | Body::__Nonexhaustive => false,
+        }
+    }
+}
+
+fn chat_ref_from_id(id: Integer) -> ChatRef {
+    ChatRef::from_chat_id(ChatId::new(id))
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct HttpResponse {
+    /// HTTP status code, e.g. 200.
+    pub status: u16,
     pub body: Option<Vec<u8>>,
 }
+
+impl HttpResponse {
+    /// True, if the status code is in the 2xx range.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
@@ -34,6 +34,34 @@ impl JsonResponse for JsonTrueToUnitResponse {
     }
 }
 
+/// Raw response of methods which return only the identifier of a message.
+#[derive(Debug, Deserialize)]
+pub struct RawMessageId {
+    message_id: MessageId,
+}
+
+pub struct JsonMessageIdResponse;
+
+impl JsonResponse for JsonMessageIdResponse {
+    type Raw = RawMessageId;
+    type Type = MessageId;
+
+    fn map(raw: Self::Raw) -> Self::Type {
+        raw.message_id
+    }
+}
+
+pub struct JsonMessageIdsResponse;
+
+impl JsonResponse for JsonMessageIdsResponse {
+    type Raw = Vec<RawMessageId>;
+    type Type = Vec<MessageId>;
+
+    fn map(raw: Self::Raw) -> Self::Type {
+        raw.into_iter().map(|raw| raw.message_id).collect()
+    }
+}
+
 impl<Resp: JsonResponse> ResponseType for Resp
 where
     <Resp as JsonResponse>::Raw: DeserializeOwned,
@@ -46,9 +74,11 @@ where
             match raw {
                 ResponseWrapper::Success { result } => Ok(<Self as JsonResponse>::map(result)),
                 ResponseWrapper::Error {
+                    error_code,
                     description,
                     parameters,
                 } => Err(ErrorKind::TelegramError {
+                    error_code,
                     description,
                     parameters,
                 }
@@ -0,0 +1,49 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to add a new sticker to a set created by the bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[must_use = "requests do nothing unless sent"]
+pub struct AddStickerToSet {
+    user_id: UserId,
+    name: String,
+    sticker: InputSticker,
+}
+
+impl ToMultipart for AddStickerToSet {
+    fn to_multipart(&self) -> Result<Multipart, Error> {
+        let mut attachments = Vec::new();
+        let sticker = self.sticker.to_json(&mut attachments)?;
+
+        let mut result = Vec::new();
+        multipart_field!(self, result, user_id(text));
+        multipart_field!(self, result, name(text));
+        multipart_field!(self, result, sticker(text) => sticker);
+        result.extend(attachments);
+        Ok(result)
+    }
+}
+
+impl Request for AddStickerToSet {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("addStickerToSet"), self)
+    }
+}
+
+impl AddStickerToSet {
+    pub fn new<U, N, S>(user: U, name: N, sticker: S) -> Self
+    where
+        U: ToUserId,
+        N: Into<String>,
+        S: Into<InputSticker>,
+    {
+        Self {
+            user_id: user.to_user_id(),
+            name: name.into(),
+            sticker: sticker.into(),
+        }
+    }
+}
@@ -31,7 +31,7 @@ impl<'i, 't> Request for AnswerCallbackQuery<'t> {
 }
 
 impl<'t> AnswerCallbackQuery<'t> {
-    fn new<Q, T>(query: Q, text: T) -> Self
+    pub fn new<Q, T>(query: Q, text: T) -> Self
     where
         Q: ToCallbackQueryId,
         T: Into<Cow<'t, str>>,
@@ -45,7 +45,7 @@ impl<'t> AnswerCallbackQuery<'t> {
         }
     }
 
-    fn acknowledge<Q>(query: Q) -> Self
+    pub fn acknowledge<Q>(query: Q) -> Self
     where
         Q: ToCallbackQueryId,
     {
@@ -58,6 +58,16 @@ impl<'t> AnswerCallbackQuery<'t> {
         }
     }
 
+    /// Text of the notification. If not specified, nothing will be shown to the user,
+    /// 0-200 characters.
+    pub fn text<T>(&mut self, text: T) -> &mut Self
+    where
+        T: Into<Cow<'t, str>>,
+    {
+        self.text = Some(text.into());
+        self
+    }
+
     /// An alert will be shown by the client instead of a notification
     /// at the top of the chat screen.
     pub fn show_alert(&mut self) -> &mut Self {
@@ -90,9 +100,11 @@ impl<'t> AnswerCallbackQuery<'t> {
 
 /// Send answers to callback queries sent from inline keyboards.
 pub trait CanAnswerCallbackQuery {
+    /// Answer the callback query with a notification.
     fn answer<'t, T>(&self, text: T) -> AnswerCallbackQuery<'t>
     where
         T: Into<Cow<'t, str>>;
+    /// Answer the callback query without showing anything to the user.
     fn acknowledge<'t>(&self) -> AnswerCallbackQuery<'t>;
 }
 
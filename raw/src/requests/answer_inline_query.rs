@@ -3,7 +3,10 @@ use std::ops::Not;
 use crate::requests::*;
 use crate::types::*;
 
+/// Use this method to send answers to an inline query.
+/// No more than 50 results per query are allowed.
 #[derive(Serialize, Debug)]
+#[must_use = "requests do nothing unless sent"]
 pub struct AnswerInlineQuery {
     inline_query_id: InlineQueryId,
     results: Vec<InlineQueryResult>,
@@ -28,6 +31,7 @@ impl Request for AnswerInlineQuery {
     }
 }
 
+/// Send answers to an inline query.
 pub trait CanAnswerInlineQuery {
     fn answer(self, results: Vec<InlineQueryResult>) -> AnswerInlineQuery;
 }
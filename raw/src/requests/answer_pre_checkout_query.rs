@@ -0,0 +1,65 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Once the user has confirmed their payment and shipping details, the Bot API sends
+/// the final confirmation in the form of an Update with the field pre_checkout_query.
+/// Use this method to respond to such pre-checkout queries.
+/// Note: The Bot API must receive an answer within 10 seconds after the pre-checkout
+/// query was sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct AnswerPreCheckoutQuery {
+    pre_checkout_query_id: CallbackQueryId,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_message: Option<String>,
+}
+
+impl Request for AnswerPreCheckoutQuery {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("answerPreCheckoutQuery"), self)
+    }
+}
+
+impl AnswerPreCheckoutQuery {
+    /// Everything is alright (goods are available, etc.) and the bot is ready to proceed
+    /// with the order.
+    pub fn ok(query_id: CallbackQueryId) -> Self {
+        AnswerPreCheckoutQuery {
+            pre_checkout_query_id: query_id,
+            ok: true,
+            error_message: None,
+        }
+    }
+
+    /// The checkout can't be completed, `error_message` explains the reason to the user.
+    pub fn error<E: Into<String>>(query_id: CallbackQueryId, error_message: E) -> Self {
+        AnswerPreCheckoutQuery {
+            pre_checkout_query_id: query_id,
+            ok: false,
+            error_message: Some(error_message.into()),
+        }
+    }
+}
+
+/// Respond to pre-checkout queries.
+pub trait CanAnswerPreCheckoutQuery {
+    /// Confirm that the bot is ready to proceed with the order.
+    fn answer_ok(&self) -> AnswerPreCheckoutQuery;
+
+    /// Decline the order with a human readable explanation.
+    fn answer_error<E: Into<String>>(&self, error_message: E) -> AnswerPreCheckoutQuery;
+}
+
+impl CanAnswerPreCheckoutQuery for PreCheckoutQuery {
+    fn answer_ok(&self) -> AnswerPreCheckoutQuery {
+        AnswerPreCheckoutQuery::ok(self.id.clone())
+    }
+
+    fn answer_error<E: Into<String>>(&self, error_message: E) -> AnswerPreCheckoutQuery {
+        AnswerPreCheckoutQuery::error(self.id.clone(), error_message)
+    }
+}
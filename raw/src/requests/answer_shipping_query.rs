@@ -0,0 +1,66 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// If you sent an invoice requesting a shipping address and the parameter is_flexible
+/// was specified, the Bot API will send an Update with a shipping_query field to the bot.
+/// Use this method to reply to shipping queries.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct AnswerShippingQuery {
+    shipping_query_id: CallbackQueryId,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping_options: Option<Vec<ShippingOption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_message: Option<String>,
+}
+
+impl Request for AnswerShippingQuery {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("answerShippingQuery"), self)
+    }
+}
+
+impl AnswerShippingQuery {
+    /// Delivery to the specified address is possible with the given shipping options.
+    pub fn ok(query_id: CallbackQueryId, shipping_options: Vec<ShippingOption>) -> Self {
+        AnswerShippingQuery {
+            shipping_query_id: query_id,
+            ok: true,
+            shipping_options: Some(shipping_options),
+            error_message: None,
+        }
+    }
+
+    /// Delivery is not possible, `error_message` explains the reason to the user.
+    pub fn error<E: Into<String>>(query_id: CallbackQueryId, error_message: E) -> Self {
+        AnswerShippingQuery {
+            shipping_query_id: query_id,
+            ok: false,
+            shipping_options: None,
+            error_message: Some(error_message.into()),
+        }
+    }
+}
+
+/// Reply to shipping queries.
+pub trait CanAnswerShippingQuery {
+    /// Reply with the available shipping options.
+    fn answer_ok(&self, shipping_options: Vec<ShippingOption>) -> AnswerShippingQuery;
+
+    /// Reply that delivery to the address is not possible.
+    fn answer_error<E: Into<String>>(&self, error_message: E) -> AnswerShippingQuery;
+}
+
+impl CanAnswerShippingQuery for ShippingQuery {
+    fn answer_ok(&self, shipping_options: Vec<ShippingOption>) -> AnswerShippingQuery {
+        AnswerShippingQuery::ok(self.id.clone(), shipping_options)
+    }
+
+    fn answer_error<E: Into<String>>(&self, error_message: E) -> AnswerShippingQuery {
+        AnswerShippingQuery::error(self.id.clone(), error_message)
+    }
+}
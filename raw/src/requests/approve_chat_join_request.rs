@@ -0,0 +1,45 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to approve a chat join request.
+/// The bot must be an administrator in the chat for this to work and must have
+/// the can_invite_users administrator right.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct ApproveChatJoinRequest {
+    chat_id: ChatRef,
+    user_id: UserId,
+}
+
+impl Request for ApproveChatJoinRequest {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("approveChatJoinRequest"), self)
+    }
+}
+
+impl ApproveChatJoinRequest {
+    pub fn new<C, U>(chat: C, user: U) -> Self
+    where
+        C: ToChatRef,
+        U: ToUserId,
+    {
+        ApproveChatJoinRequest {
+            chat_id: chat.to_chat_ref(),
+            user_id: user.to_user_id(),
+        }
+    }
+}
+
+/// Approve a chat join request.
+pub trait CanApproveChatJoinRequest {
+    fn approve(&self) -> ApproveChatJoinRequest;
+}
+
+impl CanApproveChatJoinRequest for ChatJoinRequest {
+    fn approve(&self) -> ApproveChatJoinRequest {
+        ApproveChatJoinRequest::new(&self.chat, &self.from)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to ban a channel chat in a supergroup or a channel. Until the chat is
+/// unbanned, the owner of the banned chat won't be able to send messages on behalf of
+/// any of their channels. The bot must be an administrator in the supergroup or channel
+/// for this to work and must have the appropriate administrator rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct BanChatSenderChat {
+    chat_id: ChatRef,
+    sender_chat_id: ChatId,
+}
+
+impl Request for BanChatSenderChat {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("banChatSenderChat"), self)
+    }
+}
+
+impl BanChatSenderChat {
+    pub fn new<C, S>(chat: C, sender_chat: S) -> Self
+    where
+        C: ToChatRef,
+        S: Into<ChatId>,
+    {
+        BanChatSenderChat {
+            chat_id: chat.to_chat_ref(),
+            sender_chat_id: sender_chat.into(),
+        }
+    }
+}
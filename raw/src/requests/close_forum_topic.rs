@@ -0,0 +1,47 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to close an open topic in a forum supergroup chat. The bot must be
+/// an administrator in the chat for this to work and must have the `can_manage_topics`
+/// administrator rights, unless it is the creator of the topic.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct CloseForumTopic {
+    chat_id: ChatRef,
+    message_thread_id: Integer,
+}
+
+impl Request for CloseForumTopic {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("closeForumTopic"), self)
+    }
+}
+
+impl CloseForumTopic {
+    pub fn new<C>(chat: C, message_thread_id: Integer) -> Self
+    where
+        C: ToChatRef,
+    {
+        CloseForumTopic {
+            chat_id: chat.to_chat_ref(),
+            message_thread_id,
+        }
+    }
+}
+
+/// Close an open forum topic.
+pub trait CanCloseForumTopic {
+    fn close_forum_topic(&self, message_thread_id: Integer) -> CloseForumTopic;
+}
+
+impl<C> CanCloseForumTopic for C
+where
+    C: ToChatRef,
+{
+    fn close_forum_topic(&self, message_thread_id: Integer) -> CloseForumTopic {
+        CloseForumTopic::new(self, message_thread_id)
+    }
+}
@@ -0,0 +1,145 @@
+use std::borrow::Cow;
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to copy messages of any kind. The method is analogous to the method
+/// forwardMessage, but the copied message doesn't have a link to the original message.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct CopyMessage<'c> {
+    chat_id: ChatRef,
+    from_chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_thread_id: Option<Integer>,
+    message_id: MessageId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<Cow<'c, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    protect_content: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_parameters: Option<ReplyParameters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'c> Request for CopyMessage<'c> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonMessageIdResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("copyMessage"), self)
+    }
+}
+
+impl<'c> CopyMessage<'c> {
+    pub fn new<M, F, T>(message: M, from: F, to: T) -> Self
+    where
+        M: ToMessageId,
+        F: ToChatRef,
+        T: ToChatRef,
+    {
+        CopyMessage {
+            chat_id: to.to_chat_ref(),
+            from_chat_id: from.to_chat_ref(),
+            message_thread_id: None,
+            message_id: message.to_message_id(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            disable_notification: false,
+            protect_content: false,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// New caption for media, 0-1024 characters. If not specified, the original caption is kept.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<Cow<'c, str>>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Entities of the new caption, which can be specified instead of `parse_mode`.
+    pub fn caption_entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(entities);
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+/// Copy message.
+pub trait CanCopyMessage {
+    fn copy<'c, T>(&self, to: T) -> CopyMessage<'c>
+    where
+        T: ToChatRef;
+}
+
+impl<M> CanCopyMessage for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn copy<'c, T>(&self, to: T) -> CopyMessage<'c>
+    where
+        T: ToChatRef,
+    {
+        CopyMessage::new(self.to_message_id(), self.to_source_chat(), to)
+    }
+}
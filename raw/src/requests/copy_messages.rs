@@ -0,0 +1,78 @@
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to copy multiple messages of any kind, without a link to the original
+/// messages. Messages that can't be found or copied are skipped. Album grouping is kept
+/// for copied messages.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct CopyMessages {
+    chat_id: ChatRef,
+    from_chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_thread_id: Option<Integer>,
+    message_ids: Vec<MessageId>,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    protect_content: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    remove_caption: bool,
+}
+
+impl Request for CopyMessages {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonMessageIdsResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("copyMessages"), self)
+    }
+}
+
+impl CopyMessages {
+    /// Copy 1-100 messages. Telegram expects the identifiers in increasing order, so
+    /// they are sorted.
+    pub fn new<I, F, T>(messages: I, from: F, to: T) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToMessageId,
+        F: ToChatRef,
+        T: ToChatRef,
+    {
+        CopyMessages {
+            chat_id: to.to_chat_ref(),
+            from_chat_id: from.to_chat_ref(),
+            message_thread_id: None,
+            message_ids: sorted_message_ids(messages),
+            disable_notification: false,
+            protect_content: false,
+            remove_caption: false,
+        }
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    /// Protects the contents of the sent messages from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Copy the messages without their captions.
+    pub fn remove_caption(&mut self) -> &mut Self {
+        self.remove_caption = true;
+        self
+    }
+}
@@ -0,0 +1,73 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to create a topic in a forum supergroup chat. The bot must be
+/// an administrator in the chat for this to work and must have the `can_manage_topics`
+/// administrator rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct CreateForumTopic {
+    chat_id: ChatRef,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon_color: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon_custom_emoji_id: Option<String>,
+}
+
+impl Request for CreateForumTopic {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<ForumTopic>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("createForumTopic"), self)
+    }
+}
+
+impl CreateForumTopic {
+    pub fn new<C, N>(chat: C, name: N) -> Self
+    where
+        C: ToChatRef,
+        N: Into<String>,
+    {
+        CreateForumTopic {
+            chat_id: chat.to_chat_ref(),
+            name: name.into(),
+            icon_color: None,
+            icon_custom_emoji_id: None,
+        }
+    }
+
+    /// Color of the topic icon in RGB format. Currently, must be one of 7322096 (0x6FB9F0),
+    /// 16766590 (0xFFD67E), 13338331 (0xCB86DB), 9367192 (0x8EEE98), 16749490 (0xFF93B2),
+    /// or 16478047 (0xFB6F5F).
+    pub fn icon_color(&mut self, color: Integer) -> &mut Self {
+        self.icon_color = Some(color);
+        self
+    }
+
+    /// Unique identifier of the custom emoji shown as the topic icon.
+    pub fn icon_custom_emoji_id<E: Into<String>>(&mut self, id: E) -> &mut Self {
+        self.icon_custom_emoji_id = Some(id.into());
+        self
+    }
+}
+
+/// Create a topic in a forum supergroup chat.
+pub trait CanCreateForumTopic {
+    fn create_forum_topic<N>(&self, name: N) -> CreateForumTopic
+    where
+        N: Into<String>;
+}
+
+impl<C> CanCreateForumTopic for C
+where
+    C: ToChatRef,
+{
+    fn create_forum_topic<N>(&self, name: N) -> CreateForumTopic
+    where
+        N: Into<String>,
+    {
+        CreateForumTopic::new(self, name)
+    }
+}
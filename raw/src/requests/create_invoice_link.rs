@@ -0,0 +1,179 @@
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to create a link for an invoice.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct CreateInvoiceLink {
+    title: String,
+    description: String,
+    payload: String,
+    provider_token: String,
+    currency: String,
+    prices: Vec<LabeledPrice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tip_amount: Option<Integer>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggested_tip_amounts: Vec<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provider_data: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_size: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_height: Option<Integer>,
+    #[serde(skip_serializing_if = "Not::not")]
+    need_name: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    need_phone_number: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    need_email: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    need_shipping_address: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    send_phone_number_to_provider: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    send_email_to_provider: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    is_flexible: bool,
+}
+
+impl Request for CreateInvoiceLink {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<String>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("createInvoiceLink"), self)
+    }
+}
+
+impl CreateInvoiceLink {
+    /// `payload` is not displayed to the user, use it for your internal processes.
+    /// `prices` is a price breakdown (e.g. product price, tax, discount, delivery cost).
+    pub fn new<T, D, P, K, R>(
+        title: T,
+        description: D,
+        payload: P,
+        provider_token: K,
+        currency: R,
+        prices: Vec<LabeledPrice>,
+    ) -> Self
+    where
+        T: Into<String>,
+        D: Into<String>,
+        P: Into<String>,
+        K: Into<String>,
+        R: Into<String>,
+    {
+        CreateInvoiceLink {
+            title: title.into(),
+            description: description.into(),
+            payload: payload.into(),
+            provider_token: provider_token.into(),
+            currency: currency.into(),
+            prices,
+            max_tip_amount: None,
+            suggested_tip_amounts: Vec::new(),
+            provider_data: None,
+            photo_url: None,
+            photo_size: None,
+            photo_width: None,
+            photo_height: None,
+            need_name: false,
+            need_phone_number: false,
+            need_email: false,
+            need_shipping_address: false,
+            send_phone_number_to_provider: false,
+            send_email_to_provider: false,
+            is_flexible: false,
+        }
+    }
+
+    /// The maximum accepted amount for tips in the smallest units of the currency.
+    pub fn max_tip_amount(&mut self, amount: Integer) -> &mut Self {
+        self.max_tip_amount = Some(amount);
+        self
+    }
+
+    /// Suggested amounts of tips in the smallest units of the currency. At most 4 suggested
+    /// tip amounts can be specified, they must be positive, passed in a strictly increased
+    /// order and must not exceed max_tip_amount.
+    pub fn suggested_tip_amounts(&mut self, amounts: Vec<Integer>) -> &mut Self {
+        self.suggested_tip_amounts = amounts;
+        self
+    }
+
+    /// JSON-serialized data about the invoice, which will be shared with the payment provider.
+    pub fn provider_data<S: Into<String>>(&mut self, provider_data: S) -> &mut Self {
+        self.provider_data = Some(provider_data.into());
+        self
+    }
+
+    /// URL of the product photo for the invoice.
+    pub fn photo_url<S: Into<String>>(&mut self, photo_url: S) -> &mut Self {
+        self.photo_url = Some(photo_url.into());
+        self
+    }
+
+    pub fn photo_size(&mut self, size: Integer) -> &mut Self {
+        self.photo_size = Some(size);
+        self
+    }
+
+    pub fn photo_width(&mut self, width: Integer) -> &mut Self {
+        self.photo_width = Some(width);
+        self
+    }
+
+    pub fn photo_height(&mut self, height: Integer) -> &mut Self {
+        self.photo_height = Some(height);
+        self
+    }
+
+    /// Require the user's full name to complete the order.
+    pub fn need_name(&mut self) -> &mut Self {
+        self.need_name = true;
+        self
+    }
+
+    /// Require the user's phone number to complete the order.
+    pub fn need_phone_number(&mut self) -> &mut Self {
+        self.need_phone_number = true;
+        self
+    }
+
+    /// Require the user's email address to complete the order.
+    pub fn need_email(&mut self) -> &mut Self {
+        self.need_email = true;
+        self
+    }
+
+    /// Require the user's shipping address to complete the order.
+    pub fn need_shipping_address(&mut self) -> &mut Self {
+        self.need_shipping_address = true;
+        self
+    }
+
+    /// Send the user's phone number to the provider.
+    pub fn send_phone_number_to_provider(&mut self) -> &mut Self {
+        self.send_phone_number_to_provider = true;
+        self
+    }
+
+    /// Send the user's email address to the provider.
+    pub fn send_email_to_provider(&mut self) -> &mut Self {
+        self.send_email_to_provider = true;
+        self
+    }
+
+    /// The final price depends on the shipping method.
+    pub fn flexible(&mut self) -> &mut Self {
+        self.is_flexible = true;
+        self
+    }
+}
@@ -0,0 +1,79 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to create a new sticker set owned by a user.
+/// The bot will be able to edit the sticker set thus created.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[must_use = "requests do nothing unless sent"]
+pub struct CreateNewStickerSet {
+    user_id: UserId,
+    name: String,
+    title: String,
+    stickers: Vec<InputSticker>,
+    sticker_type: Option<StickerType>,
+    needs_repainting: bool,
+}
+
+impl ToMultipart for CreateNewStickerSet {
+    fn to_multipart(&self) -> Result<Multipart, Error> {
+        let mut attachments = Vec::new();
+        let stickers = InputSticker::list_to_json(&self.stickers, &mut attachments)?;
+
+        let mut result = Vec::new();
+        multipart_field!(self, result, user_id(text));
+        multipart_field!(self, result, name(text));
+        multipart_field!(self, result, title(text));
+        multipart_field!(self, result, stickers(text) => stickers);
+        multipart_field!(self, result, sticker_type(text), optional);
+        multipart_field!(self, result, needs_repainting(text), when_true);
+        result.extend(attachments);
+        Ok(result)
+    }
+}
+
+impl Request for CreateNewStickerSet {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("createNewStickerSet"), self)
+    }
+}
+
+impl CreateNewStickerSet {
+    /// Name of the set can contain only English letters, digits and underscores,
+    /// and must end in `_by_<bot_username>`.
+    pub fn new<U, N, T>(user: U, name: N, title: T, stickers: Vec<InputSticker>) -> Self
+    where
+        U: ToUserId,
+        N: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            user_id: user.to_user_id(),
+            name: name.into(),
+            title: title.into(),
+            stickers,
+            sticker_type: None,
+            needs_repainting: false,
+        }
+    }
+
+    pub fn add_sticker<S: Into<InputSticker>>(&mut self, sticker: S) -> &mut Self {
+        self.stickers.push(sticker.into());
+        self
+    }
+
+    /// Type of stickers in the set. By default, a regular sticker set is created.
+    pub fn sticker_type(&mut self, sticker_type: StickerType) -> &mut Self {
+        self.sticker_type = Some(sticker_type);
+        self
+    }
+
+    /// Stickers in the set must be repainted to the color of text when used in messages.
+    /// For custom emoji sticker sets only.
+    pub fn needs_repainting(&mut self) -> &mut Self {
+        self.needs_repainting = true;
+        self
+    }
+}
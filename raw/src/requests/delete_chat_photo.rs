@@ -0,0 +1,45 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to delete a chat photo. Photos can't be changed for private chats.
+/// The bot must be an administrator in the chat for this to work and must have
+/// the appropriate admin rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct DeleteChatPhoto {
+    chat_id: ChatRef,
+}
+
+impl Request for DeleteChatPhoto {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("deleteChatPhoto"), self)
+    }
+}
+
+impl DeleteChatPhoto {
+    pub fn new<C>(chat: C) -> Self
+    where
+        C: ToChatRef,
+    {
+        DeleteChatPhoto {
+            chat_id: chat.to_chat_ref(),
+        }
+    }
+}
+
+/// Delete a chat photo.
+pub trait CanDeleteChatPhoto {
+    fn delete_photo(&self) -> DeleteChatPhoto;
+}
+
+impl<C> CanDeleteChatPhoto for C
+where
+    C: ToChatRef,
+{
+    fn delete_photo(&self) -> DeleteChatPhoto {
+        DeleteChatPhoto::new(self)
+    }
+}
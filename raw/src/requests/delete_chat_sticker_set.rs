@@ -0,0 +1,32 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to delete a group sticker set from a supergroup. The bot must be an
+/// administrator in the chat for this to work and must have the appropriate admin rights.
+/// Use the field `can_set_sticker_set` returned by `GetChat` to check if the bot can use
+/// this method.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct DeleteChatStickerSet {
+    chat_id: ChatRef,
+}
+
+impl Request for DeleteChatStickerSet {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("deleteChatStickerSet"), self)
+    }
+}
+
+impl DeleteChatStickerSet {
+    pub fn new<C>(chat: C) -> Self
+    where
+        C: ToChatRef,
+    {
+        DeleteChatStickerSet {
+            chat_id: chat.to_chat_ref(),
+        }
+    }
+}
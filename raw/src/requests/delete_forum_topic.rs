@@ -0,0 +1,47 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to delete a forum topic along with all its messages in a forum
+/// supergroup chat. The bot must be an administrator in the chat for this to work
+/// and must have the `can_delete_messages` administrator rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct DeleteForumTopic {
+    chat_id: ChatRef,
+    message_thread_id: Integer,
+}
+
+impl Request for DeleteForumTopic {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("deleteForumTopic"), self)
+    }
+}
+
+impl DeleteForumTopic {
+    pub fn new<C>(chat: C, message_thread_id: Integer) -> Self
+    where
+        C: ToChatRef,
+    {
+        DeleteForumTopic {
+            chat_id: chat.to_chat_ref(),
+            message_thread_id,
+        }
+    }
+}
+
+/// Delete a forum topic along with all its messages.
+pub trait CanDeleteForumTopic {
+    fn delete_forum_topic(&self, message_thread_id: Integer) -> DeleteForumTopic;
+}
+
+impl<C> CanDeleteForumTopic for C
+where
+    C: ToChatRef,
+{
+    fn delete_forum_topic(&self, message_thread_id: Integer) -> DeleteForumTopic {
+        DeleteForumTopic::new(self, message_thread_id)
+    }
+}
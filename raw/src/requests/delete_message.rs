@@ -1,14 +1,14 @@
 use crate::requests::*;
 use crate::types::*;
 
-// Use this method to delete a message.
-// A message can only be deleted if it was sent less than 48 hours ago.
-// Any such recently sent outgoing message may be deleted.
-// Additionally, if the bot is an administrator in a group chat, it can delete any message.
-// If the bot is an administrator in a supergroup, it can delete messages from any
-// other user and service messages about people joining or leaving the
-// group (other types of service messages may only be removed by the group creator).
-// In channels, bots can only remove their own messages.
+/// Use this method to delete a message.
+/// A message can only be deleted if it was sent less than 48 hours ago.
+/// Any such recently sent outgoing message may be deleted.
+/// Additionally, if the bot is an administrator in a group chat, it can delete any message.
+/// If the bot is an administrator in a supergroup, it can delete messages from any
+/// other user and service messages about people joining or leaving the
+/// group (other types of service messages may only be removed by the group creator).
+/// In channels, bots can only remove their own messages.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
 #[must_use = "requests do nothing unless sent"]
 pub struct DeleteMessage {
@@ -38,7 +38,7 @@ impl DeleteMessage {
     }
 }
 
-/// Delete messages..
+/// Delete messages.
 pub trait CanDeleteMessage {
     fn delete(&self) -> DeleteMessage;
 }
@@ -0,0 +1,39 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to delete multiple messages simultaneously. Messages that can't be
+/// found are skipped. See `DeleteMessage` for the limitations on which messages can be
+/// deleted.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct DeleteMessages {
+    chat_id: ChatRef,
+    message_ids: Vec<MessageId>,
+}
+
+impl Request for DeleteMessages {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("deleteMessages"), self)
+    }
+}
+
+impl DeleteMessages {
+    /// Delete 1-100 messages of the chat.
+    pub fn new<C, I>(chat: C, messages: I) -> Self
+    where
+        C: ToChatRef,
+        I: IntoIterator,
+        I::Item: ToMessageId,
+    {
+        DeleteMessages {
+            chat_id: chat.to_chat_ref(),
+            message_ids: messages
+                .into_iter()
+                .map(|message| message.to_message_id())
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to delete the list of the bot's commands for the given scope
+/// and user language. After deletion, higher level commands will be shown to affected users.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct DeleteMyCommands {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<BotCommandScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<String>,
+}
+
+impl Request for DeleteMyCommands {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("deleteMyCommands"), self)
+    }
+}
+
+impl DeleteMyCommands {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Scope of users for which the commands are relevant. Defaults to `BotCommandScope::Default`.
+    pub fn scope(&mut self, scope: BotCommandScope) -> &mut Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// A two-letter ISO 639-1 language code. If empty, commands will be applied to all users
+    /// from the given scope, for whose language there are no dedicated commands.
+    pub fn language_code<L: Into<String>>(&mut self, language_code: L) -> &mut Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
@@ -0,0 +1,40 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to delete a sticker from a set created by the bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct DeleteStickerFromSet {
+    sticker: FileRef,
+}
+
+impl Request for DeleteStickerFromSet {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("deleteStickerFromSet"), self)
+    }
+}
+
+impl DeleteStickerFromSet {
+    pub fn new<F>(sticker: F) -> Self
+    where
+        F: ToFileRef,
+    {
+        Self {
+            sticker: sticker.to_file_ref(),
+        }
+    }
+}
+
+/// Delete a sticker from a set created by the bot.
+pub trait CanDeleteStickerFromSet {
+    fn delete_from_set(&self) -> DeleteStickerFromSet;
+}
+
+impl CanDeleteStickerFromSet for Sticker {
+    fn delete_from_set(&self) -> DeleteStickerFromSet {
+        DeleteStickerFromSet::new(self)
+    }
+}
@@ -0,0 +1,35 @@
+use std::ops::Not;
+
+use crate::requests::*;
+
+/// Use this method to remove webhook integration if you decide to switch back
+/// to `GetUpdates`.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct DeleteWebhook {
+    #[serde(skip_serializing_if = "Not::not")]
+    drop_pending_updates: bool,
+}
+
+impl Request for DeleteWebhook {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("deleteWebhook"), self)
+    }
+}
+
+impl DeleteWebhook {
+    pub fn new() -> Self {
+        DeleteWebhook {
+            drop_pending_updates: false,
+        }
+    }
+
+    /// Drop all pending updates.
+    pub fn drop_pending_updates(&mut self) -> &mut Self {
+        self.drop_pending_updates = true;
+        self
+    }
+}
@@ -0,0 +1,67 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to edit name and icon of a topic in a forum supergroup chat. The bot must be
+/// an administrator in the chat for this to work and must have the `can_manage_topics`
+/// administrator rights, unless it is the creator of the topic.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct EditForumTopic {
+    chat_id: ChatRef,
+    message_thread_id: Integer,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon_custom_emoji_id: Option<String>,
+}
+
+impl Request for EditForumTopic {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("editForumTopic"), self)
+    }
+}
+
+impl EditForumTopic {
+    /// Without a name or icon, the topic is left unchanged.
+    pub fn new<C>(chat: C, message_thread_id: Integer) -> Self
+    where
+        C: ToChatRef,
+    {
+        EditForumTopic {
+            chat_id: chat.to_chat_ref(),
+            message_thread_id,
+            name: None,
+            icon_custom_emoji_id: None,
+        }
+    }
+
+    /// New topic name, 1-128 characters.
+    pub fn name<N: Into<String>>(&mut self, name: N) -> &mut Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// New unique identifier of the custom emoji shown as the topic icon.
+    /// Pass an empty string to remove the icon.
+    pub fn icon_custom_emoji_id<E: Into<String>>(&mut self, id: E) -> &mut Self {
+        self.icon_custom_emoji_id = Some(id.into());
+        self
+    }
+}
+
+/// Edit name and icon of a forum topic.
+pub trait CanEditForumTopic {
+    fn edit_forum_topic(&self, message_thread_id: Integer) -> EditForumTopic;
+}
+
+impl<C> CanEditForumTopic for C
+where
+    C: ToChatRef,
+{
+    fn edit_forum_topic(&self, message_thread_id: Integer) -> EditForumTopic {
+        EditForumTopic::new(self, message_thread_id)
+    }
+}
@@ -11,6 +11,10 @@ pub struct EditMessageCaption<'s> {
     message_id: MessageId,
     caption: Cow<'s, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     reply_markup: Option<ReplyMarkup>,
 }
 
@@ -34,10 +38,23 @@ impl<'s> EditMessageCaption<'s> {
             chat_id: chat.to_chat_ref(),
             message_id: message_id.to_message_id(),
             caption: caption.into(),
+            parse_mode: None,
+            caption_entities: None,
             reply_markup: None,
         }
     }
 
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Entities of the caption, which can be specified instead of `parse_mode`.
+    pub fn caption_entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(entities);
+        self
+    }
+
     pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
     where
         R: Into<ReplyMarkup>,
@@ -65,3 +82,61 @@ where
         EditMessageCaption::new(self.to_source_chat(), self.to_message_id(), caption)
     }
 }
+
+/// Use this method to edit captions of messages sent via the bot (for inline bots).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct EditInlineMessageCaption<'s> {
+    inline_message_id: String,
+    caption: Cow<'s, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'s> Request for EditInlineMessageCaption<'s> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("editMessageCaption"), self)
+    }
+}
+
+impl<'s> EditInlineMessageCaption<'s> {
+    pub fn new<I, T>(inline_message_id: I, caption: T) -> Self
+    where
+        I: Into<String>,
+        T: Into<Cow<'s, str>>,
+    {
+        EditInlineMessageCaption {
+            inline_message_id: inline_message_id.into(),
+            caption: caption.into(),
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Entities of the caption, which can be specified instead of `parse_mode`.
+    pub fn caption_entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(entities);
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
@@ -12,6 +12,12 @@ pub struct EditMessageLiveLocation {
     latitude: Float,
     longitude: Float,
     #[serde(skip_serializing_if = "Option::is_none")]
+    horizontal_accuracy: Option<Float>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    heading: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proximity_alert_radius: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     reply_markup: Option<ReplyMarkup>,
 }
 
@@ -35,10 +41,32 @@ impl EditMessageLiveLocation {
             message_id: message_id.to_message_id(),
             latitude: latitude,
             longitude: longitude,
+            horizontal_accuracy: None,
+            heading: None,
+            proximity_alert_radius: None,
             reply_markup: None,
         }
     }
 
+    /// The radius of uncertainty for the location, measured in meters; 0-1500.
+    pub fn horizontal_accuracy(&mut self, accuracy: Float) -> &mut Self {
+        self.horizontal_accuracy = Some(accuracy);
+        self
+    }
+
+    /// Direction in which the user is moving, in degrees. Must be between 1 and 360 if specified.
+    pub fn heading(&mut self, heading: Integer) -> &mut Self {
+        self.heading = Some(heading);
+        self
+    }
+
+    /// Maximum distance for proximity alerts about approaching another chat member,
+    /// in meters. Must be between 1 and 100000 if specified.
+    pub fn proximity_alert_radius(&mut self, radius: Integer) -> &mut Self {
+        self.proximity_alert_radius = Some(radius);
+        self
+    }
+
     pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
     where
         R: Into<ReplyMarkup>,
@@ -66,3 +94,73 @@ where
         )
     }
 }
+
+/// Use this method to edit live location messages sent via the bot (for inline bots).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct EditInlineMessageLiveLocation {
+    inline_message_id: String,
+    latitude: Float,
+    longitude: Float,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    horizontal_accuracy: Option<Float>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    heading: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proximity_alert_radius: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl Request for EditInlineMessageLiveLocation {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("editMessageLiveLocation"), self)
+    }
+}
+
+impl EditInlineMessageLiveLocation {
+    pub fn new<I>(inline_message_id: I, latitude: Float, longitude: Float) -> Self
+    where
+        I: Into<String>,
+    {
+        EditInlineMessageLiveLocation {
+            inline_message_id: inline_message_id.into(),
+            latitude,
+            longitude,
+            horizontal_accuracy: None,
+            heading: None,
+            proximity_alert_radius: None,
+            reply_markup: None,
+        }
+    }
+
+    /// The radius of uncertainty for the location, measured in meters; 0-1500.
+    pub fn horizontal_accuracy(&mut self, accuracy: Float) -> &mut Self {
+        self.horizontal_accuracy = Some(accuracy);
+        self
+    }
+
+    /// Direction in which the user is moving, in degrees. Must be between 1 and 360 if specified.
+    pub fn heading(&mut self, heading: Integer) -> &mut Self {
+        self.heading = Some(heading);
+        self
+    }
+
+    /// Maximum distance for proximity alerts about approaching another chat member,
+    /// in meters. Must be between 1 and 100000 if specified.
+    pub fn proximity_alert_radius(&mut self, radius: Integer) -> &mut Self {
+        self.proximity_alert_radius = Some(radius);
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
@@ -0,0 +1,135 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to edit animation, audio, document, photo, or video messages.
+/// A new file can't be uploaded when the message is a part of a message album.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[must_use = "requests do nothing unless sent"]
+pub struct EditMessageMedia {
+    chat_id: ChatRef,
+    message_id: MessageId,
+    media: InputMedia,
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl ToMultipart for EditMessageMedia {
+    fn to_multipart(&self) -> Result<Multipart, Error> {
+        let mut attachments = Vec::new();
+        let media = self.media.to_json(&mut attachments)?;
+
+        let mut result = Vec::new();
+        multipart_field!(self, result, chat_id(text));
+        multipart_field!(self, result, message_id(text));
+        multipart_field!(self, result, media(text) => media);
+        multipart_field!(self, result, reply_markup(json), optional);
+        result.extend(attachments);
+        Ok(result)
+    }
+}
+
+impl Request for EditMessageMedia {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("editMessageMedia"), self)
+    }
+}
+
+impl EditMessageMedia {
+    pub fn new<C, M, V>(chat: C, message_id: M, media: V) -> Self
+    where
+        C: ToChatRef,
+        M: ToMessageId,
+        V: Into<InputMedia>,
+    {
+        EditMessageMedia {
+            chat_id: chat.to_chat_ref(),
+            message_id: message_id.to_message_id(),
+            media: media.into(),
+            reply_markup: None,
+        }
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+/// Edit media of messages sent by the bot.
+pub trait CanEditMessageMedia {
+    fn edit_media<V>(&self, media: V) -> EditMessageMedia
+    where
+        V: Into<InputMedia>;
+}
+
+impl<M> CanEditMessageMedia for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn edit_media<V>(&self, media: V) -> EditMessageMedia
+    where
+        V: Into<InputMedia>,
+    {
+        EditMessageMedia::new(self.to_source_chat(), self.to_message_id(), media)
+    }
+}
+
+/// Use this method to edit media of messages sent via the bot (for inline bots).
+/// A new file can't be uploaded for inline messages, use a file id or URL.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[must_use = "requests do nothing unless sent"]
+pub struct EditInlineMessageMedia {
+    inline_message_id: String,
+    media: InputMedia,
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl ToMultipart for EditInlineMessageMedia {
+    fn to_multipart(&self) -> Result<Multipart, Error> {
+        let mut attachments = Vec::new();
+        let media = self.media.to_json(&mut attachments)?;
+
+        let mut result = Vec::new();
+        multipart_field!(self, result, inline_message_id(text));
+        multipart_field!(self, result, media(text) => media);
+        multipart_field!(self, result, reply_markup(json), optional);
+        result.extend(attachments);
+        Ok(result)
+    }
+}
+
+impl Request for EditInlineMessageMedia {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("editMessageMedia"), self)
+    }
+}
+
+impl EditInlineMessageMedia {
+    pub fn new<I, V>(inline_message_id: I, media: V) -> Self
+    where
+        I: Into<String>,
+        V: Into<InputMedia>,
+    {
+        EditInlineMessageMedia {
+            inline_message_id: inline_message_id.into(),
+            media: media.into(),
+            reply_markup: None,
+        }
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
@@ -53,3 +53,34 @@ where
         EditMessageReplyMarkup::new(self.to_source_chat(), self.to_message_id(), reply_markup)
     }
 }
+
+/// Use this method to edit only the reply markup of messages sent via the bot (for inline bots).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct EditInlineMessageReplyMarkup {
+    inline_message_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl Request for EditInlineMessageReplyMarkup {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("editMessageReplyMarkup"), self)
+    }
+}
+
+impl EditInlineMessageReplyMarkup {
+    pub fn new<I, R>(inline_message_id: I, reply_markup: Option<R>) -> Self
+    where
+        I: Into<String>,
+        R: Into<ReplyMarkup>,
+    {
+        EditInlineMessageReplyMarkup {
+            inline_message_id: inline_message_id.into(),
+            reply_markup: reply_markup.map(|r| r.into()),
+        }
+    }
+}
@@ -1,5 +1,4 @@
 use std::borrow::Cow;
-use std::ops::Not;
 
 use crate::requests::*;
 use crate::types::*;
@@ -13,8 +12,10 @@ pub struct EditMessageText<'s> {
     text: Cow<'s, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     parse_mode: Option<ParseMode>,
-    #[serde(skip_serializing_if = "Not::not")]
-    disable_web_page_preview: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    link_preview_options: Option<LinkPreviewOptions>,
     #[serde(skip_serializing_if = "Option::is_none")]
     reply_markup: Option<ReplyMarkup>,
 }
@@ -40,7 +41,8 @@ impl<'s> EditMessageText<'s> {
             message_id: message_id.to_message_id(),
             text: text.into(),
             parse_mode: None,
-            disable_web_page_preview: false,
+            entities: None,
+            link_preview_options: None,
             reply_markup: None,
         }
     }
@@ -50,8 +52,20 @@ impl<'s> EditMessageText<'s> {
         self
     }
 
+    /// Entities of the text, which can be specified instead of `parse_mode`.
+    pub fn entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.entities = Some(entities);
+        self
+    }
+
+    /// Disable the link preview, a shorthand for `LinkPreviewOptions::disabled`.
     pub fn disable_preview(&mut self) -> &mut Self {
-        self.disable_web_page_preview = true;
+        self.link_preview_options = Some(LinkPreviewOptions::disabled());
+        self
+    }
+
+    pub fn link_preview_options(&mut self, options: LinkPreviewOptions) -> &mut Self {
+        self.link_preview_options = Some(options);
         self
     }
 
@@ -82,3 +96,75 @@ where
         EditMessageText::new(self.to_source_chat(), self.to_message_id(), text)
     }
 }
+
+/// Use this method to edit text of messages sent via the bot (for inline bots).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct EditInlineMessageText<'s> {
+    inline_message_id: String,
+    text: Cow<'s, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    link_preview_options: Option<LinkPreviewOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'s> Request for EditInlineMessageText<'s> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("editMessageText"), self)
+    }
+}
+
+impl<'s> EditInlineMessageText<'s> {
+    pub fn new<I, T>(inline_message_id: I, text: T) -> Self
+    where
+        I: Into<String>,
+        T: Into<Cow<'s, str>>,
+    {
+        EditInlineMessageText {
+            inline_message_id: inline_message_id.into(),
+            text: text.into(),
+            parse_mode: None,
+            entities: None,
+            link_preview_options: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Entities of the text, which can be specified instead of `parse_mode`.
+    pub fn entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.entities = Some(entities);
+        self
+    }
+
+    /// Disable the link preview, a shorthand for `LinkPreviewOptions::disabled`.
+    pub fn disable_preview(&mut self) -> &mut Self {
+        self.link_preview_options = Some(LinkPreviewOptions::disabled());
+        self
+    }
+
+    pub fn link_preview_options(&mut self, options: LinkPreviewOptions) -> &mut Self {
+        self.link_preview_options = Some(options);
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
@@ -9,8 +9,12 @@ use crate::types::*;
 pub struct ForwardMessage {
     chat_id: ChatRef,
     from_chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_thread_id: Option<Integer>,
     #[serde(skip_serializing_if = "Not::not")]
     disable_notification: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    protect_content: bool,
     message_id: MessageId,
 }
 
@@ -33,15 +37,30 @@ impl ForwardMessage {
         ForwardMessage {
             chat_id: to.to_chat_ref(),
             from_chat_id: from.to_chat_ref(),
+            message_thread_id: None,
             disable_notification: false,
+            protect_content: false,
             message_id: message.to_message_id(),
         }
     }
 
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
     pub fn disable_notification(&mut self) -> &mut Self {
         self.disable_notification = true;
         self
     }
+
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
 }
 
 /// Forward message.
@@ -0,0 +1,83 @@
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to forward multiple messages of any kind. Messages that can't be
+/// found or forwarded are skipped. Album grouping is kept for forwarded messages.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct ForwardMessages {
+    chat_id: ChatRef,
+    from_chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_thread_id: Option<Integer>,
+    message_ids: Vec<MessageId>,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    protect_content: bool,
+}
+
+impl Request for ForwardMessages {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonMessageIdsResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("forwardMessages"), self)
+    }
+}
+
+impl ForwardMessages {
+    /// Forward 1-100 messages. Telegram expects the identifiers in increasing order, so
+    /// they are sorted.
+    pub fn new<I, F, T>(messages: I, from: F, to: T) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToMessageId,
+        F: ToChatRef,
+        T: ToChatRef,
+    {
+        ForwardMessages {
+            chat_id: to.to_chat_ref(),
+            from_chat_id: from.to_chat_ref(),
+            message_thread_id: None,
+            message_ids: sorted_message_ids(messages),
+            disable_notification: false,
+            protect_content: false,
+        }
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    /// Protects the contents of the sent messages from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+}
+
+/// Message identifiers in the strictly increasing order Telegram expects.
+pub(crate) fn sorted_message_ids<I>(messages: I) -> Vec<MessageId>
+where
+    I: IntoIterator,
+    I::Item: ToMessageId,
+{
+    let mut message_ids: Vec<MessageId> = messages
+        .into_iter()
+        .map(|message| message.to_message_id())
+        .collect();
+    message_ids.sort();
+    message_ids.dedup();
+    message_ids
+}
@@ -0,0 +1,29 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to get information about the connection of the bot with a business account.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct GetBusinessConnection {
+    business_connection_id: String,
+}
+
+impl Request for GetBusinessConnection {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<BusinessConnection>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("getBusinessConnection"), self)
+    }
+}
+
+impl GetBusinessConnection {
+    pub fn new<B>(business_connection_id: B) -> Self
+    where
+        B: Into<String>,
+    {
+        GetBusinessConnection {
+            business_connection_id: business_connection_id.into(),
+        }
+    }
+}
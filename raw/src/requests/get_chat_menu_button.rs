@@ -0,0 +1,49 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to get the current value of the bot's menu button in a private chat,
+/// or the default menu button.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct GetChatMenuButton {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chat_id: Option<ChatRef>,
+}
+
+impl Request for GetChatMenuButton {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<MenuButton>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("getChatMenuButton"), self)
+    }
+}
+
+impl GetChatMenuButton {
+    /// Returns the default menu button, unless a chat is specified.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Private chat for which the menu button will be returned.
+    pub fn chat<C: ToChatRef>(&mut self, chat: C) -> &mut Self {
+        self.chat_id = Some(chat.to_chat_ref());
+        self
+    }
+}
+
+/// Get the bot's menu button in a private chat.
+pub trait CanGetChatMenuButton {
+    fn get_menu_button(&self) -> GetChatMenuButton;
+}
+
+impl<C> CanGetChatMenuButton for C
+where
+    C: ToChatRef,
+{
+    fn get_menu_button(&self) -> GetChatMenuButton {
+        let mut rq = GetChatMenuButton::new();
+        rq.chat(self);
+        rq
+    }
+}
@@ -0,0 +1,42 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to get information about custom emoji stickers by their identifiers.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct GetCustomEmojiStickers {
+    custom_emoji_ids: Vec<String>,
+}
+
+impl Request for GetCustomEmojiStickers {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<Vec<Sticker>>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("getCustomEmojiStickers"), self)
+    }
+}
+
+impl GetCustomEmojiStickers {
+    /// At most 200 custom emoji identifiers can be specified.
+    pub fn new<I, T>(custom_emoji_ids: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self {
+            custom_emoji_ids: custom_emoji_ids.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Collects the identifiers of all custom emoji used in the given entities.
+    pub fn from_entities<'a, I>(entities: I) -> Self
+    where
+        I: IntoIterator<Item = &'a MessageEntity>,
+    {
+        Self::new(entities.into_iter().filter_map(|entity| match entity.kind {
+            MessageEntityKind::CustomEmoji(ref id) => Some(id.clone()),
+            _ => None,
+        }))
+    }
+}
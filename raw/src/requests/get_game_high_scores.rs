@@ -0,0 +1,86 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to get data for high score tables. Will return the score of the
+/// specified user and several of their neighbors in a game.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct GetGameHighScores {
+    user_id: UserId,
+    chat_id: ChatRef,
+    message_id: MessageId,
+}
+
+impl Request for GetGameHighScores {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<Vec<GameHighScore>>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("getGameHighScores"), self)
+    }
+}
+
+impl GetGameHighScores {
+    pub fn new<C, M, U>(chat: C, message_id: M, user: U) -> Self
+    where
+        C: ToChatRef,
+        M: ToMessageId,
+        U: ToUserId,
+    {
+        GetGameHighScores {
+            user_id: user.to_user_id(),
+            chat_id: chat.to_chat_ref(),
+            message_id: message_id.to_message_id(),
+        }
+    }
+}
+
+/// Get high scores of a game message around the given user.
+pub trait CanGetGameHighScores {
+    fn game_high_scores<U>(&self, user: U) -> GetGameHighScores
+    where
+        U: ToUserId;
+}
+
+impl<M> CanGetGameHighScores for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn game_high_scores<U>(&self, user: U) -> GetGameHighScores
+    where
+        U: ToUserId,
+    {
+        GetGameHighScores::new(self.to_source_chat(), self.to_message_id(), user)
+    }
+}
+
+/// Use this method to get data for high score tables of a game message
+/// sent via the bot (for inline bots).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct GetInlineGameHighScores {
+    user_id: UserId,
+    inline_message_id: String,
+}
+
+impl Request for GetInlineGameHighScores {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<Vec<GameHighScore>>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("getGameHighScores"), self)
+    }
+}
+
+impl GetInlineGameHighScores {
+    pub fn new<I, U>(inline_message_id: I, user: U) -> Self
+    where
+        I: Into<String>,
+        U: ToUserId,
+    {
+        GetInlineGameHighScores {
+            user_id: user.to_user_id(),
+            inline_message_id: inline_message_id.into(),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to get the current list of the bot's commands for the given scope
+/// and user language.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct GetMyCommands {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<BotCommandScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<String>,
+}
+
+impl Request for GetMyCommands {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<Vec<BotCommand>>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("getMyCommands"), self)
+    }
+}
+
+impl GetMyCommands {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Scope of users. Defaults to `BotCommandScope::Default`.
+    pub fn scope(&mut self, scope: BotCommandScope) -> &mut Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// A two-letter ISO 639-1 language code or an empty string.
+    pub fn language_code<L: Into<String>>(&mut self, language_code: L) -> &mut Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
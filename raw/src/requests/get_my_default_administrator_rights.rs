@@ -0,0 +1,34 @@
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to get the current default administrator rights of the bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct GetMyDefaultAdministratorRights {
+    #[serde(skip_serializing_if = "Not::not")]
+    for_channels: bool,
+}
+
+impl Request for GetMyDefaultAdministratorRights {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<ChatAdministratorRights>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("getMyDefaultAdministratorRights"), self)
+    }
+}
+
+impl GetMyDefaultAdministratorRights {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Get the default administrator rights of the bot in channels,
+    /// instead of groups and supergroups.
+    pub fn for_channels(&mut self) -> &mut Self {
+        self.for_channels = true;
+        self
+    }
+}
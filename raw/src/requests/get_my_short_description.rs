@@ -0,0 +1,32 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to get the current short description of the bot
+/// for the given user language.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct GetMyShortDescription {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<String>,
+}
+
+impl Request for GetMyShortDescription {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<BotShortDescription>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("getMyShortDescription"), self)
+    }
+}
+
+impl GetMyShortDescription {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// A two-letter ISO 639-1 language code or an empty string.
+    pub fn language_code<L: Into<String>>(&mut self, language_code: L) -> &mut Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
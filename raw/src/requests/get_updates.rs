@@ -54,7 +54,8 @@ impl GetUpdates {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+/// Kind of update the bot wants to receive, see `UpdateKind`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize)]
 pub enum AllowedUpdate {
     #[serde(rename = "message")]
     Message,
@@ -66,7 +67,7 @@ pub enum AllowedUpdate {
     EditedChannelPost,
     #[serde(rename = "inline_query")]
     InlineQuery,
-    #[serde(rename = "chosen_inline_query")]
+    #[serde(rename = "chosen_inline_result")]
     ChosenInlineResult,
     #[serde(rename = "callback_query")]
     CallbackQuery,
@@ -74,4 +75,63 @@ pub enum AllowedUpdate {
     ShippingQuery,
     #[serde(rename = "pre_checkout_query")]
     PreCheckoutQuery,
+    #[serde(rename = "poll")]
+    Poll,
+    #[serde(rename = "poll_answer")]
+    PollAnswer,
+    #[serde(rename = "my_chat_member")]
+    MyChatMember,
+    #[serde(rename = "chat_member")]
+    ChatMember,
+    #[serde(rename = "chat_join_request")]
+    ChatJoinRequest,
+    #[serde(rename = "message_reaction")]
+    MessageReaction,
+    #[serde(rename = "message_reaction_count")]
+    MessageReactionCount,
+    #[serde(rename = "chat_boost")]
+    ChatBoost,
+    #[serde(rename = "removed_chat_boost")]
+    RemovedChatBoost,
+    #[serde(rename = "business_connection")]
+    BusinessConnection,
+    #[serde(rename = "business_message")]
+    BusinessMessage,
+    #[serde(rename = "edited_business_message")]
+    EditedBusinessMessage,
+    #[serde(rename = "deleted_business_messages")]
+    DeletedBusinessMessages,
+}
+
+impl AllowedUpdate {
+    /// All kinds of updates, including `ChatMember`, `MessageReaction` and
+    /// `MessageReactionCount`, which are only delivered when they are explicitly allowed.
+    pub fn all() -> &'static [AllowedUpdate] {
+        use self::AllowedUpdate::*;
+
+        &[
+            Message,
+            EditedMessage,
+            ChannelPost,
+            EditedChannelPost,
+            InlineQuery,
+            ChosenInlineResult,
+            CallbackQuery,
+            ShippingQuery,
+            PreCheckoutQuery,
+            Poll,
+            PollAnswer,
+            MyChatMember,
+            ChatMember,
+            ChatJoinRequest,
+            MessageReaction,
+            MessageReactionCount,
+            ChatBoost,
+            RemovedChatBoost,
+            BusinessConnection,
+            BusinessMessage,
+            EditedBusinessMessage,
+            DeletedBusinessMessages,
+        ]
+    }
 }
@@ -0,0 +1,52 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to get the list of boosts added to a chat by a user.
+/// Requires administrator rights in the chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct GetUserChatBoosts {
+    chat_id: ChatRef,
+    user_id: UserId,
+}
+
+impl Request for GetUserChatBoosts {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<UserChatBoosts>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("getUserChatBoosts"), self)
+    }
+}
+
+impl GetUserChatBoosts {
+    pub fn new<C, U>(chat: C, user: U) -> Self
+    where
+        C: ToChatRef,
+        U: ToUserId,
+    {
+        GetUserChatBoosts {
+            chat_id: chat.to_chat_ref(),
+            user_id: user.to_user_id(),
+        }
+    }
+}
+
+/// Get the list of boosts added to a chat by a user.
+pub trait CanGetUserChatBoosts {
+    fn user_boosts<U>(&self, user: U) -> GetUserChatBoosts
+    where
+        U: ToUserId;
+}
+
+impl<C> CanGetUserChatBoosts for C
+where
+    C: ToChatRef,
+{
+    fn user_boosts<U>(&self, user: U) -> GetUserChatBoosts
+    where
+        U: ToUserId,
+    {
+        GetUserChatBoosts::new(self, user)
+    }
+}
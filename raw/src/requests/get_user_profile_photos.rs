@@ -6,7 +6,9 @@ use crate::types::*;
 #[must_use = "requests do nothing unless sent"]
 pub struct GetUserProfilePhotos {
     user_id: UserId,
+    #[serde(skip_serializing_if = "Option::is_none")]
     offset: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     limit: Option<Integer>,
 }
 
@@ -31,11 +33,13 @@ impl GetUserProfilePhotos {
         }
     }
 
+    /// Sequential number of the first photo to be returned. By default, all photos are returned.
     pub fn offset(&mut self, offset: Integer) -> &mut Self {
         self.offset = Some(offset);
         self
     }
 
+    /// Limits the number of photos to be retrieved. Values between 1-100 are accepted.
     pub fn limit(&mut self, limit: Integer) -> &mut Self {
         self.limit = Some(limit);
         self
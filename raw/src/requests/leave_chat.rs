@@ -29,6 +29,9 @@ impl LeaveChat {
 }
 
 /// Leave a group, supergroup or channel.
+///
+/// Combine it with `Api::send` to make the bot remove itself from a chat,
+/// e.g. `api.send(message.chat.leave())`.
 pub trait CanLeaveChat {
     fn leave(&self) -> LeaveChat;
 }
@@ -0,0 +1,147 @@
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to promote or demote a user in a supergroup or a channel.
+/// The bot must be an administrator in the chat for this to work and must have
+/// the appropriate admin rights. Send the request without any rights to demote a user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct PromoteChatMember {
+    chat_id: ChatRef,
+    user_id: UserId,
+    #[serde(skip_serializing_if = "Not::not")]
+    can_change_info: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    can_post_messages: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    can_edit_messages: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    can_delete_messages: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    can_invite_users: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    can_restrict_members: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    can_pin_messages: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    can_promote_members: bool,
+}
+
+impl Request for PromoteChatMember {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("promoteChatMember"), self)
+    }
+}
+
+impl PromoteChatMember {
+    pub fn new<C, U>(chat: C, user: U) -> Self
+    where
+        C: ToChatRef,
+        U: ToUserId,
+    {
+        PromoteChatMember {
+            chat_id: chat.to_chat_ref(),
+            user_id: user.to_user_id(),
+            can_change_info: false,
+            can_post_messages: false,
+            can_edit_messages: false,
+            can_delete_messages: false,
+            can_invite_users: false,
+            can_restrict_members: false,
+            can_pin_messages: false,
+            can_promote_members: false,
+        }
+    }
+
+    /// Allow the administrator to change chat title, photo and other settings.
+    pub fn can_change_info(&mut self) -> &mut Self {
+        self.can_change_info = true;
+        self
+    }
+
+    /// Allow the administrator to create channel posts, channels only.
+    pub fn can_post_messages(&mut self) -> &mut Self {
+        self.can_post_messages = true;
+        self
+    }
+
+    /// Allow the administrator to edit messages of other users and pin messages, channels only.
+    pub fn can_edit_messages(&mut self) -> &mut Self {
+        self.can_edit_messages = true;
+        self
+    }
+
+    /// Allow the administrator to delete messages of other users.
+    pub fn can_delete_messages(&mut self) -> &mut Self {
+        self.can_delete_messages = true;
+        self
+    }
+
+    /// Allow the administrator to invite new users to the chat.
+    pub fn can_invite_users(&mut self) -> &mut Self {
+        self.can_invite_users = true;
+        self
+    }
+
+    /// Allow the administrator to restrict, ban or unban chat members.
+    pub fn can_restrict_members(&mut self) -> &mut Self {
+        self.can_restrict_members = true;
+        self
+    }
+
+    /// Allow the administrator to pin messages, supergroups only.
+    pub fn can_pin_messages(&mut self) -> &mut Self {
+        self.can_pin_messages = true;
+        self
+    }
+
+    /// Allow the administrator to add new administrators with a subset of their own
+    /// privileges or demote administrators that they have promoted.
+    pub fn can_promote_members(&mut self) -> &mut Self {
+        self.can_promote_members = true;
+        self
+    }
+}
+
+/// Promote or demote a user in a supergroup or a channel.
+pub trait CanPromoteChatMemberForChat {
+    fn promote<O>(&self, other: O) -> PromoteChatMember
+    where
+        O: ToUserId;
+}
+
+impl<C> CanPromoteChatMemberForChat for C
+where
+    C: ToChatRef,
+{
+    fn promote<O>(&self, other: O) -> PromoteChatMember
+    where
+        O: ToUserId,
+    {
+        PromoteChatMember::new(self, other)
+    }
+}
+
+/// Promote or demote a user in a supergroup or a channel.
+pub trait CanPromoteChatMemberForUser {
+    fn promote_in<O>(&self, other: O) -> PromoteChatMember
+    where
+        O: ToChatRef;
+}
+
+impl<U> CanPromoteChatMemberForUser for U
+where
+    U: ToUserId,
+{
+    fn promote_in<O>(&self, other: O) -> PromoteChatMember
+    where
+        O: ToChatRef,
+    {
+        PromoteChatMember::new(other, self)
+    }
+}
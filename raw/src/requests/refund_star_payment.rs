@@ -0,0 +1,32 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Refunds a successful payment in Telegram Stars.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct RefundStarPayment {
+    user_id: UserId,
+    telegram_payment_charge_id: String,
+}
+
+impl Request for RefundStarPayment {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("refundStarPayment"), self)
+    }
+}
+
+impl RefundStarPayment {
+    pub fn new<U, C>(user: U, telegram_payment_charge_id: C) -> Self
+    where
+        U: ToUserId,
+        C: Into<String>,
+    {
+        RefundStarPayment {
+            user_id: user.to_user_id(),
+            telegram_payment_charge_id: telegram_payment_charge_id.into(),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to reopen a closed topic in a forum supergroup chat. The bot must be
+/// an administrator in the chat for this to work and must have the `can_manage_topics`
+/// administrator rights, unless it is the creator of the topic.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct ReopenForumTopic {
+    chat_id: ChatRef,
+    message_thread_id: Integer,
+}
+
+impl Request for ReopenForumTopic {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("reopenForumTopic"), self)
+    }
+}
+
+impl ReopenForumTopic {
+    pub fn new<C>(chat: C, message_thread_id: Integer) -> Self
+    where
+        C: ToChatRef,
+    {
+        ReopenForumTopic {
+            chat_id: chat.to_chat_ref(),
+            message_thread_id,
+        }
+    }
+}
+
+/// Reopen a closed forum topic.
+pub trait CanReopenForumTopic {
+    fn reopen_forum_topic(&self, message_thread_id: Integer) -> ReopenForumTopic;
+}
+
+impl<C> CanReopenForumTopic for C
+where
+    C: ToChatRef,
+{
+    fn reopen_forum_topic(&self, message_thread_id: Integer) -> ReopenForumTopic {
+        ReopenForumTopic::new(self, message_thread_id)
+    }
+}
@@ -0,0 +1,54 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to revoke an invite link created by the bot.
+/// If the primary link is revoked, a new link is automatically generated.
+/// The bot must be an administrator in the chat for this to work and must have
+/// the appropriate admin rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct RevokeChatInviteLink {
+    chat_id: ChatRef,
+    invite_link: String,
+}
+
+impl Request for RevokeChatInviteLink {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<ChatInviteLink>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("revokeChatInviteLink"), self)
+    }
+}
+
+impl RevokeChatInviteLink {
+    pub fn new<C, L>(chat: C, invite_link: L) -> Self
+    where
+        C: ToChatRef,
+        L: Into<String>,
+    {
+        RevokeChatInviteLink {
+            chat_id: chat.to_chat_ref(),
+            invite_link: invite_link.into(),
+        }
+    }
+}
+
+/// Revoke an invite link created by the bot.
+pub trait CanRevokeChatInviteLink {
+    fn revoke_invite_link<L>(&self, invite_link: L) -> RevokeChatInviteLink
+    where
+        L: Into<String>;
+}
+
+impl<C> CanRevokeChatInviteLink for C
+where
+    C: ToChatRef,
+{
+    fn revoke_invite_link<L>(&self, invite_link: L) -> RevokeChatInviteLink
+    where
+        L: Into<String>,
+    {
+        RevokeChatInviteLink::new(self, invite_link)
+    }
+}
@@ -0,0 +1,225 @@
+use std::borrow::Cow;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to send animation files (GIF or H.264/MPEG-4 AVC video without sound).
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendAnimation<'c> {
+    business_connection_id: Option<String>,
+    chat_id: ChatRef,
+    message_thread_id: Option<Integer>,
+    animation: InputFile,
+    caption: Option<Cow<'c, str>>,
+    parse_mode: Option<ParseMode>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    duration: Option<Integer>,
+    width: Option<Integer>,
+    height: Option<Integer>,
+    thumbnail: Option<InputFile>,
+    reply_parameters: Option<ReplyParameters>,
+    disable_notification: bool,
+    protect_content: bool,
+    message_effect_id: Option<String>,
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'c> ToMultipart for SendAnimation<'c> {
+    fn to_multipart(&self) -> Result<Multipart, Error> {
+        multipart_map! {
+            self,
+            (business_connection_id (text), optional);
+            (chat_id (text));
+            (message_thread_id (text), optional);
+            (animation (raw));
+            (caption (text), optional);
+            (parse_mode (text), optional);
+            (caption_entities (json), optional);
+            (duration (text), optional);
+            (width (text), optional);
+            (height (text), optional);
+            (thumbnail (raw), optional);
+            (reply_parameters (json), optional);
+            (disable_notification (text), when_true);
+            (protect_content (text), when_true);
+            (message_effect_id (text), optional);
+            (reply_markup (json), optional);
+        }
+    }
+}
+
+impl<'c> Request for SendAnimation<'c> {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendAnimation"), self)
+    }
+}
+
+impl<'c> SendAnimation<'c> {
+    pub fn new<C, V>(chat: C, animation: V) -> Self
+    where
+        C: ToChatRef,
+        V: Into<InputFile>,
+    {
+        Self {
+            business_connection_id: None,
+            chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
+            animation: animation.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            duration: None,
+            width: None,
+            height: None,
+            thumbnail: None,
+            reply_parameters: None,
+            reply_markup: None,
+            disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
+        }
+    }
+
+    pub fn thumb<V>(&mut self, thumb: V) -> &mut Self
+    where
+        V: Into<InputFileUpload>,
+    {
+        self.thumbnail = Some(thumb.into().into());
+        self
+    }
+
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<Cow<'c, str>>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Entities of the caption, which can be specified instead of `parse_mode`.
+    pub fn caption_entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(entities);
+        self
+    }
+
+    pub fn duration(&mut self, duration: Integer) -> &mut Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    pub fn width(&mut self, width: Integer) -> &mut Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(&mut self, height: Integer) -> &mut Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
+}
+
+/// Can reply with an animation
+pub trait CanReplySendAnimation {
+    fn animation_reply<'c, T>(&self, animation: T) -> SendAnimation<'c>
+    where
+        T: Into<InputFile>;
+}
+
+impl<M> CanReplySendAnimation for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn animation_reply<'c, T>(&self, animation: T) -> SendAnimation<'c>
+    where
+        T: Into<InputFile>,
+    {
+        let mut req = SendAnimation::new(self.to_source_chat(), animation);
+        req.reply_to(self);
+        req
+    }
+}
+
+/// Send an animation
+pub trait CanSendAnimation {
+    fn animation<'c, T>(&self, animation: T) -> SendAnimation<'c>
+    where
+        T: Into<InputFile>;
+}
+
+impl<M> CanSendAnimation for M
+where
+    M: ToChatRef,
+{
+    fn animation<'c, T>(&self, animation: T) -> SendAnimation<'c>
+    where
+        T: Into<InputFile>,
+    {
+        SendAnimation::new(self.to_chat_ref(), animation)
+    }
+}
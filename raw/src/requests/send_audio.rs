@@ -7,16 +7,21 @@ use crate::types::*;
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 #[must_use = "requests do nothing unless sent"]
 pub struct SendAudio<'c, 'p, 't> {
+    business_connection_id: Option<String>,
     chat_id: ChatRef,
+    message_thread_id: Option<Integer>,
     audio: InputFile,
     caption: Option<Cow<'c, str>>,
     parse_mode: Option<ParseMode>,
+    caption_entities: Option<Vec<MessageEntity>>,
     duration: Option<Integer>,
     performer: Option<Cow<'p, str>>,
     title: Option<Cow<'t, str>>,
-    thumb: Option<InputFile>,
-    reply_to_message_id: Option<MessageId>,
+    thumbnail: Option<InputFile>,
+    reply_parameters: Option<ReplyParameters>,
     disable_notification: bool,
+    protect_content: bool,
+    message_effect_id: Option<String>,
     reply_markup: Option<ReplyMarkup>,
 }
 
@@ -24,16 +29,21 @@ impl<'c, 'p, 't> ToMultipart for SendAudio<'c, 'p, 't> {
     fn to_multipart(&self) -> Result<Multipart, Error> {
         multipart_map! {
             self,
+            (business_connection_id (text), optional);
             (chat_id (text));
+            (message_thread_id (text), optional);
             (audio (raw));
             (caption (text), optional);
             (parse_mode (text), optional);
+            (caption_entities (json), optional);
             (duration (text), optional);
             (performer (text), optional);
             (title (text), optional);
-            (thumb (raw), optional);
-            (reply_to_message_id (text), optional);
+            (thumbnail (raw), optional);
+            (reply_parameters (json), optional);
             (disable_notification (text), when_true);
+            (protect_content (text), when_true);
+            (message_effect_id (text), optional);
             (reply_markup (json), optional);
         }
     }
@@ -55,17 +65,22 @@ impl<'c, 'p, 't> SendAudio<'c, 'p, 't> {
         V: Into<InputFile>,
     {
         Self {
+            business_connection_id: None,
             chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
             audio: audio.into(),
             caption: None,
             parse_mode: None,
+            caption_entities: None,
             duration: None,
             performer: None,
             title: None,
-            thumb: None,
-            reply_to_message_id: None,
+            thumbnail: None,
+            reply_parameters: None,
             reply_markup: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
         }
     }
 
@@ -73,7 +88,7 @@ impl<'c, 'p, 't> SendAudio<'c, 'p, 't> {
     where
         V: Into<InputFileUpload>,
     {
-        self.thumb = Some(thumb.into().into());
+        self.thumbnail = Some(thumb.into().into());
         self
     }
 
@@ -90,6 +105,12 @@ impl<'c, 'p, 't> SendAudio<'c, 'p, 't> {
         self
     }
 
+    /// Entities of the caption, which can be specified instead of `parse_mode`.
+    pub fn caption_entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(entities);
+        self
+    }
+
     pub fn duration(&mut self, duration: Integer) -> &mut Self {
         self.duration = Some(duration);
         self
@@ -111,11 +132,34 @@ impl<'c, 'p, 't> SendAudio<'c, 'p, 't> {
         self
     }
 
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
     pub fn reply_to<R>(&mut self, to: R) -> &mut Self
     where
         R: ToMessageId,
     {
-        self.reply_to_message_id = Some(to.to_message_id());
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
         self
     }
 
@@ -131,6 +175,19 @@ impl<'c, 'p, 't> SendAudio<'c, 'p, 't> {
         self.disable_notification = true;
         self
     }
+
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
 }
 
 /// Can reply with an audio
@@ -18,10 +18,20 @@ pub enum ChatAction {
     RecordAudio,
     #[serde(rename = "upload_audio")]
     UploadAudio,
+    #[serde(rename = "record_voice")]
+    RecordVoice,
+    #[serde(rename = "upload_voice")]
+    UploadVoice,
     #[serde(rename = "upload_document")]
     UploadDocument,
+    #[serde(rename = "choose_sticker")]
+    ChooseSticker,
     #[serde(rename = "find_location")]
     FindLocation,
+    #[serde(rename = "record_video_note")]
+    RecordVideoNote,
+    #[serde(rename = "upload_video_note")]
+    UploadVideoNote,
 }
 
 /// Use this method when you need to tell the user that something is happening on the bot's side.
@@ -29,7 +39,11 @@ pub enum ChatAction {
 /// Telegram clients clear its typing status).
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
 pub struct SendChatAction {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    business_connection_id: Option<String>,
     chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_thread_id: Option<Integer>,
     action: ChatAction,
 }
 
@@ -48,10 +62,26 @@ impl SendChatAction {
         C: ToChatRef,
     {
         SendChatAction {
+            business_connection_id: None,
             chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
             action: action,
         }
     }
+
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
 }
 
 /// Send `action` to a chat.
@@ -8,15 +8,25 @@ use crate::types::*;
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
 #[must_use = "requests do nothing unless sent"]
 pub struct SendContact<'p, 'f, 'l> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    business_connection_id: Option<String>,
     chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_thread_id: Option<Integer>,
     phone_number: Cow<'p, str>,
     first_name: Cow<'f, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     last_name: Option<Cow<'l, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vcard: Option<String>,
     #[serde(skip_serializing_if = "Not::not")]
     disable_notification: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    protect_content: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    reply_to_message_id: Option<MessageId>,
+    message_effect_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_parameters: Option<ReplyParameters>,
     #[serde(skip_serializing_if = "Option::is_none")]
     reply_markup: Option<ReplyMarkup>,
 }
@@ -38,12 +48,17 @@ impl<'p, 'f, 'l> SendContact<'p, 'f, 'l> {
         F: Into<Cow<'f, str>>,
     {
         SendContact {
+            business_connection_id: None,
             chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
             phone_number: phone_number.into(),
             first_name: first_name.into(),
             last_name: None,
+            vcard: None,
             disable_notification: false,
-            reply_to_message_id: None,
+            protect_content: false,
+            message_effect_id: None,
+            reply_parameters: None,
             reply_markup: None,
         }
     }
@@ -56,16 +71,61 @@ impl<'p, 'f, 'l> SendContact<'p, 'f, 'l> {
         self
     }
 
+    /// Additional data about the contact in the form of a vCard, 0-2048 bytes.
+    pub fn vcard<V>(&mut self, vcard: V) -> &mut Self
+    where
+        V: Into<String>,
+    {
+        self.vcard = Some(vcard.into());
+        self
+    }
+
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
     pub fn disable_notification(&mut self) -> &mut Self {
         self.disable_notification = true;
         self
     }
 
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
+
     pub fn reply_to<R>(&mut self, to: R) -> &mut Self
     where
         R: ToMessageId,
     {
-        self.reply_to_message_id = Some(to.to_message_id());
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
         self
     }
 
@@ -141,6 +201,9 @@ impl<'b> ToRequest<'b> for Contact {
         if let Some(ref last_name) = self.last_name {
             rq.last_name(last_name.as_str());
         }
+        if let Some(ref vcard) = self.vcard {
+            rq.vcard(vcard.as_str());
+        }
         rq
     }
 }
@@ -156,6 +219,9 @@ impl<'b> ToReplyRequest<'b> for Contact {
         if let Some(ref last_name) = self.last_name {
             rq.last_name(last_name.as_str());
         }
+        if let Some(ref vcard) = self.vcard {
+            rq.vcard(vcard.as_str());
+        }
         rq
     }
 }
@@ -0,0 +1,150 @@
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to send an animated emoji that will display a random value.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendDice {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    business_connection_id: Option<String>,
+    chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_thread_id: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji: Option<DiceEmoji>,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    protect_content: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_effect_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_parameters: Option<ReplyParameters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl Request for SendDice {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<MessageOrChannelPost>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendDice"), self)
+    }
+}
+
+impl SendDice {
+    pub fn new<C>(chat: C) -> Self
+    where
+        C: ToChatRef,
+    {
+        SendDice {
+            business_connection_id: None,
+            chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
+            emoji: None,
+            disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Emoji on which the dice throw animation is based. Defaults to “🎲”.
+    pub fn emoji(&mut self, emoji: DiceEmoji) -> &mut Self {
+        self.emoji = Some(emoji);
+        self
+    }
+
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+/// Send an animated emoji with a random value.
+pub trait CanSendDice {
+    fn dice(&self, emoji: DiceEmoji) -> SendDice;
+}
+
+impl<C> CanSendDice for C
+where
+    C: ToChatRef,
+{
+    fn dice(&self, emoji: DiceEmoji) -> SendDice {
+        let mut rq = SendDice::new(self);
+        rq.emoji(emoji);
+        rq
+    }
+}
+
+/// Reply with an animated emoji with a random value.
+pub trait CanReplySendDice {
+    fn dice_reply(&self, emoji: DiceEmoji) -> SendDice;
+}
+
+impl<M> CanReplySendDice for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn dice_reply(&self, emoji: DiceEmoji) -> SendDice {
+        let mut rq = self.to_source_chat().dice(emoji);
+        rq.reply_to(self.to_message_id());
+        rq
+    }
+}
@@ -8,13 +8,18 @@ use crate::types::*;
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 #[must_use = "requests do nothing unless sent"]
 pub struct SendDocument<'c> {
+    business_connection_id: Option<String>,
     chat_id: ChatRef,
+    message_thread_id: Option<Integer>,
     document: InputFile,
     thumb: Option<InputFile>,
     caption: Option<Cow<'c, str>>,
     parse_mode: Option<ParseMode>,
-    reply_to_message_id: Option<MessageId>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    reply_parameters: Option<ReplyParameters>,
     disable_notification: bool,
+    protect_content: bool,
+    message_effect_id: Option<String>,
     reply_markup: Option<ReplyMarkup>,
 }
 
@@ -22,13 +27,18 @@ impl<'c> ToMultipart for SendDocument<'c> {
     fn to_multipart(&self) -> Result<Multipart, Error> {
         multipart_map! {
             self,
+            (business_connection_id (text), optional);
             (chat_id (text));
+            (message_thread_id (text), optional);
             (document (raw));
             (thumb (raw), optional);
             (caption (text), optional);
             (parse_mode (text), optional);
-            (reply_to_message_id (text), optional);
+            (caption_entities (json), optional);
+            (reply_parameters (json), optional);
             (disable_notification (text), when_true);
+            (protect_content (text), when_true);
+            (message_effect_id (text), optional);
             (reply_markup (json), optional);
         }
     }
@@ -50,14 +60,19 @@ impl<'c> SendDocument<'c> {
         V: Into<InputFile>,
     {
         Self {
+            business_connection_id: None,
             chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
             document: document.into(),
             thumb: None,
             caption: None,
             parse_mode: None,
-            reply_to_message_id: None,
+            caption_entities: None,
+            reply_parameters: None,
             reply_markup: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
         }
     }
 
@@ -82,11 +97,40 @@ impl<'c> SendDocument<'c> {
         self
     }
 
+    /// Entities of the caption, which can be specified instead of `parse_mode`.
+    pub fn caption_entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(entities);
+        self
+    }
+
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
     pub fn reply_to<R>(&mut self, to: R) -> &mut Self
     where
         R: ToMessageId,
     {
-        self.reply_to_message_id = Some(to.to_message_id());
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
         self
     }
 
@@ -95,6 +139,19 @@ impl<'c> SendDocument<'c> {
         self
     }
 
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
+
     pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
     where
         R: Into<ReplyMarkup>,
@@ -0,0 +1,263 @@
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to send invoices.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendInvoice {
+    chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_thread_id: Option<Integer>,
+    title: String,
+    description: String,
+    payload: String,
+    provider_token: String,
+    currency: String,
+    prices: Vec<LabeledPrice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tip_amount: Option<Integer>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggested_tip_amounts: Vec<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_parameter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provider_data: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_size: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_height: Option<Integer>,
+    #[serde(skip_serializing_if = "Not::not")]
+    need_name: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    need_phone_number: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    need_email: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    need_shipping_address: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    send_phone_number_to_provider: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    send_email_to_provider: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    is_flexible: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    protect_content: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_effect_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_parameters: Option<ReplyParameters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl Request for SendInvoice {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendInvoice"), self)
+    }
+}
+
+impl SendInvoice {
+    /// `payload` is not displayed to the user, use it for your internal processes.
+    /// `prices` is a price breakdown (e.g. product price, tax, discount, delivery cost).
+    pub fn new<C, T, D, P, K, R>(
+        chat: C,
+        title: T,
+        description: D,
+        payload: P,
+        provider_token: K,
+        currency: R,
+        prices: Vec<LabeledPrice>,
+    ) -> Self
+    where
+        C: ToChatRef,
+        T: Into<String>,
+        D: Into<String>,
+        P: Into<String>,
+        K: Into<String>,
+        R: Into<String>,
+    {
+        SendInvoice {
+            chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
+            title: title.into(),
+            description: description.into(),
+            payload: payload.into(),
+            provider_token: provider_token.into(),
+            currency: currency.into(),
+            prices,
+            max_tip_amount: None,
+            suggested_tip_amounts: Vec::new(),
+            start_parameter: None,
+            provider_data: None,
+            photo_url: None,
+            photo_size: None,
+            photo_width: None,
+            photo_height: None,
+            need_name: false,
+            need_phone_number: false,
+            need_email: false,
+            need_shipping_address: false,
+            send_phone_number_to_provider: false,
+            send_email_to_provider: false,
+            is_flexible: false,
+            disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    /// The maximum accepted amount for tips in the smallest units of the currency.
+    pub fn max_tip_amount(&mut self, amount: Integer) -> &mut Self {
+        self.max_tip_amount = Some(amount);
+        self
+    }
+
+    /// Suggested amounts of tips in the smallest units of the currency. At most 4 suggested
+    /// tip amounts can be specified, they must be positive, passed in a strictly increased
+    /// order and must not exceed max_tip_amount.
+    pub fn suggested_tip_amounts(&mut self, amounts: Vec<Integer>) -> &mut Self {
+        self.suggested_tip_amounts = amounts;
+        self
+    }
+
+    /// Unique deep-linking parameter. If left empty, forwarded copies of the sent message
+    /// will have a Pay button, allowing multiple users to pay directly from the forwarded message.
+    pub fn start_parameter<S: Into<String>>(&mut self, start_parameter: S) -> &mut Self {
+        self.start_parameter = Some(start_parameter.into());
+        self
+    }
+
+    /// JSON-serialized data about the invoice, which will be shared with the payment provider.
+    pub fn provider_data<S: Into<String>>(&mut self, provider_data: S) -> &mut Self {
+        self.provider_data = Some(provider_data.into());
+        self
+    }
+
+    /// URL of the product photo for the invoice.
+    pub fn photo_url<S: Into<String>>(&mut self, photo_url: S) -> &mut Self {
+        self.photo_url = Some(photo_url.into());
+        self
+    }
+
+    pub fn photo_size(&mut self, size: Integer) -> &mut Self {
+        self.photo_size = Some(size);
+        self
+    }
+
+    pub fn photo_width(&mut self, width: Integer) -> &mut Self {
+        self.photo_width = Some(width);
+        self
+    }
+
+    pub fn photo_height(&mut self, height: Integer) -> &mut Self {
+        self.photo_height = Some(height);
+        self
+    }
+
+    /// Require the user's full name to complete the order.
+    pub fn need_name(&mut self) -> &mut Self {
+        self.need_name = true;
+        self
+    }
+
+    /// Require the user's phone number to complete the order.
+    pub fn need_phone_number(&mut self) -> &mut Self {
+        self.need_phone_number = true;
+        self
+    }
+
+    /// Require the user's email address to complete the order.
+    pub fn need_email(&mut self) -> &mut Self {
+        self.need_email = true;
+        self
+    }
+
+    /// Require the user's shipping address to complete the order.
+    pub fn need_shipping_address(&mut self) -> &mut Self {
+        self.need_shipping_address = true;
+        self
+    }
+
+    /// Send the user's phone number to the provider.
+    pub fn send_phone_number_to_provider(&mut self) -> &mut Self {
+        self.send_phone_number_to_provider = true;
+        self
+    }
+
+    /// Send the user's email address to the provider.
+    pub fn send_email_to_provider(&mut self) -> &mut Self {
+        self.send_email_to_provider = true;
+        self
+    }
+
+    /// The final price depends on the shipping method.
+    pub fn flexible(&mut self) -> &mut Self {
+        self.is_flexible = true;
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
+        self
+    }
+
+    /// If empty, one 'Pay total price' button will be shown.
+    /// If not empty, the first button must be a Pay button.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
@@ -7,15 +7,29 @@ use crate::types::*;
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
 #[must_use = "requests do nothing unless sent"]
 pub struct SendLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    business_connection_id: Option<String>,
     chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_thread_id: Option<Integer>,
     latitude: Float,
     longitude: Float,
     #[serde(skip_serializing_if = "Option::is_none")]
+    horizontal_accuracy: Option<Float>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     live_period: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    heading: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proximity_alert_radius: Option<Integer>,
     #[serde(skip_serializing_if = "Not::not")]
     disable_notification: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    protect_content: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_effect_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    reply_to_message_id: Option<MessageId>,
+    reply_parameters: Option<ReplyParameters>,
     #[serde(skip_serializing_if = "Option::is_none")]
     reply_markup: Option<ReplyMarkup>,
 }
@@ -35,12 +49,19 @@ impl SendLocation {
         C: ToChatRef,
     {
         SendLocation {
+            business_connection_id: None,
             chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
             latitude: latitude,
             longitude: longitude,
+            horizontal_accuracy: None,
             live_period: None,
+            heading: None,
+            proximity_alert_radius: None,
             disable_notification: false,
-            reply_to_message_id: None,
+            protect_content: false,
+            message_effect_id: None,
+            reply_parameters: None,
             reply_markup: None,
         }
     }
@@ -51,16 +72,72 @@ impl SendLocation {
         self
     }
 
+    /// The radius of uncertainty for the location, measured in meters; 0-1500.
+    pub fn horizontal_accuracy(&mut self, accuracy: Float) -> &mut Self {
+        self.horizontal_accuracy = Some(accuracy);
+        self
+    }
+
+    /// For live locations, a direction in which the user is moving, in degrees.
+    /// Must be between 1 and 360 if specified.
+    pub fn heading(&mut self, heading: Integer) -> &mut Self {
+        self.heading = Some(heading);
+        self
+    }
+
+    /// For live locations, a maximum distance for proximity alerts about approaching
+    /// another chat member, in meters. Must be between 1 and 100000 if specified.
+    pub fn proximity_alert_radius(&mut self, radius: Integer) -> &mut Self {
+        self.proximity_alert_radius = Some(radius);
+        self
+    }
+
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
     pub fn disable_notification(&mut self) -> &mut Self {
         self.disable_notification = true;
         self
     }
 
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
+
     pub fn reply_to<R>(&mut self, to: R) -> &mut Self
     where
         R: ToMessageId,
     {
-        self.reply_to_message_id = Some(to.to_message_id());
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
         self
     }
 
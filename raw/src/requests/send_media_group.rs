@@ -0,0 +1,146 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to send a group of photos or videos as an album.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendMediaGroup {
+    business_connection_id: Option<String>,
+    chat_id: ChatRef,
+    message_thread_id: Option<Integer>,
+    media: Vec<InputMedia>,
+    reply_parameters: Option<ReplyParameters>,
+    disable_notification: bool,
+    protect_content: bool,
+    message_effect_id: Option<String>,
+}
+
+impl ToMultipart for SendMediaGroup {
+    fn to_multipart(&self) -> Result<Multipart, Error> {
+        let mut attachments = Vec::new();
+        let media = InputMedia::list_to_json(&self.media, &mut attachments)?;
+
+        let mut result = Vec::new();
+        multipart_field!(self, result, business_connection_id(text), optional);
+        multipart_field!(self, result, chat_id(text));
+        multipart_field!(self, result, message_thread_id(text), optional);
+        multipart_field!(self, result, media(text) => media);
+        multipart_field!(self, result, reply_parameters(json), optional);
+        multipart_field!(self, result, disable_notification(text), when_true);
+        multipart_field!(self, result, protect_content(text), when_true);
+        multipart_field!(self, result, message_effect_id(text), optional);
+        result.extend(attachments);
+        Ok(result)
+    }
+}
+
+impl Request for SendMediaGroup {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonIdResponse<Vec<Message>>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendMediaGroup"), self)
+    }
+}
+
+impl SendMediaGroup {
+    pub fn new<C>(chat: C, media: Vec<InputMedia>) -> Self
+    where
+        C: ToChatRef,
+    {
+        Self {
+            business_connection_id: None,
+            chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
+            media,
+            reply_parameters: None,
+            disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
+        }
+    }
+
+    pub fn add_media<T: Into<InputMedia>>(&mut self, media: T) -> &mut Self {
+        self.media.push(media.into());
+        self
+    }
+
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
+}
+
+/// Can reply with a group of photos or videos
+pub trait CanReplySendMediaGroup {
+    fn media_group_reply(&self, media: Vec<InputMedia>) -> SendMediaGroup;
+}
+
+impl<M> CanReplySendMediaGroup for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn media_group_reply(&self, media: Vec<InputMedia>) -> SendMediaGroup {
+        let mut req = SendMediaGroup::new(self.to_source_chat(), media);
+        req.reply_to(self);
+        req
+    }
+}
+
+/// Send a group of photos or videos
+pub trait CanSendMediaGroup {
+    fn media_group(&self, media: Vec<InputMedia>) -> SendMediaGroup;
+}
+
+impl<C> CanSendMediaGroup for C
+where
+    C: ToChatRef,
+{
+    fn media_group(&self, media: Vec<InputMedia>) -> SendMediaGroup {
+        SendMediaGroup::new(self, media)
+    }
+}
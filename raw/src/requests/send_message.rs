@@ -8,16 +8,26 @@ use crate::types::*;
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
 #[must_use = "requests do nothing unless sent"]
 pub struct SendMessage<'s> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    business_connection_id: Option<String>,
     chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_thread_id: Option<Integer>,
     text: Cow<'s, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     parse_mode: Option<ParseMode>,
-    #[serde(skip_serializing_if = "Not::not")]
-    disable_web_page_preview: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    link_preview_options: Option<LinkPreviewOptions>,
     #[serde(skip_serializing_if = "Not::not")]
     disable_notification: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    protect_content: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    reply_to_message_id: Option<MessageId>,
+    message_effect_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_parameters: Option<ReplyParameters>,
     #[serde(skip_serializing_if = "Option::is_none")]
     reply_markup: Option<ReplyMarkup>,
 }
@@ -38,12 +48,17 @@ impl<'s> SendMessage<'s> {
         T: Into<Cow<'s, str>>,
     {
         SendMessage {
+            business_connection_id: None,
             chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
             text: text.into(),
             parse_mode: None,
-            disable_web_page_preview: false,
+            entities: None,
+            link_preview_options: None,
             disable_notification: false,
-            reply_to_message_id: None,
+            protect_content: false,
+            message_effect_id: None,
+            reply_parameters: None,
             reply_markup: None,
         }
     }
@@ -53,8 +68,34 @@ impl<'s> SendMessage<'s> {
         self
     }
 
+    /// Entities of the text, which can be specified instead of `parse_mode`.
+    pub fn entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.entities = Some(entities);
+        self
+    }
+
+    /// Disable the link preview, a shorthand for `LinkPreviewOptions::disabled`.
     pub fn disable_preview(&mut self) -> &mut Self {
-        self.disable_web_page_preview = true;
+        self.link_preview_options = Some(LinkPreviewOptions::disabled());
+        self
+    }
+
+    pub fn link_preview_options(&mut self, options: LinkPreviewOptions) -> &mut Self {
+        self.link_preview_options = Some(options);
+        self
+    }
+
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
         self
     }
 
@@ -63,11 +104,33 @@ impl<'s> SendMessage<'s> {
         self
     }
 
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
+
     pub fn reply_to<R>(&mut self, to: R) -> &mut Self
     where
         R: ToMessageId,
     {
-        self.reply_to_message_id = Some(to.to_message_id());
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
         self
     }
 
@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to send paid media, which users can see after paying the given
+/// number of Telegram Stars.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendPaidMedia<'c> {
+    business_connection_id: Option<String>,
+    chat_id: ChatRef,
+    star_count: Integer,
+    media: Vec<InputPaidMedia>,
+    payload: Option<String>,
+    caption: Option<Cow<'c, str>>,
+    parse_mode: Option<ParseMode>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    show_caption_above_media: bool,
+    disable_notification: bool,
+    protect_content: bool,
+    reply_parameters: Option<ReplyParameters>,
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'c> ToMultipart for SendPaidMedia<'c> {
+    fn to_multipart(&self) -> Result<Multipart, Error> {
+        let mut attachments = Vec::new();
+        let media = InputPaidMedia::list_to_json(&self.media, &mut attachments)?;
+
+        let mut result = Vec::new();
+        multipart_field!(self, result, business_connection_id(text), optional);
+        multipart_field!(self, result, chat_id(text));
+        multipart_field!(self, result, star_count(text));
+        multipart_field!(self, result, media(text) => media);
+        multipart_field!(self, result, payload(text), optional);
+        multipart_field!(self, result, caption(text), optional);
+        multipart_field!(self, result, parse_mode(text), optional);
+        multipart_field!(self, result, caption_entities(json), optional);
+        multipart_field!(self, result, show_caption_above_media(text), when_true);
+        multipart_field!(self, result, disable_notification(text), when_true);
+        multipart_field!(self, result, protect_content(text), when_true);
+        multipart_field!(self, result, reply_parameters(json), optional);
+        multipart_field!(self, result, reply_markup(json), optional);
+        result.extend(attachments);
+        Ok(result)
+    }
+}
+
+impl<'c> Request for SendPaidMedia<'c> {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendPaidMedia"), self)
+    }
+}
+
+impl<'c> SendPaidMedia<'c> {
+    pub fn new<C>(chat: C, star_count: Integer, media: Vec<InputPaidMedia>) -> Self
+    where
+        C: ToChatRef,
+    {
+        Self {
+            business_connection_id: None,
+            chat_id: chat.to_chat_ref(),
+            star_count,
+            media,
+            payload: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: false,
+            disable_notification: false,
+            protect_content: false,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn add_media<T: Into<InputPaidMedia>>(&mut self, media: T) -> &mut Self {
+        self.media.push(media.into());
+        self
+    }
+
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Bot-defined paid media payload, 0-128 bytes. This will not be displayed to the
+    /// user, use it for your internal processes.
+    pub fn payload<P: Into<String>>(&mut self, payload: P) -> &mut Self {
+        self.payload = Some(payload.into());
+        self
+    }
+
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<Cow<'c, str>>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Entities of the caption, which can be specified instead of `parse_mode`.
+    pub fn caption_entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(entities);
+        self
+    }
+
+    pub fn show_caption_above_media(&mut self) -> &mut Self {
+        self.show_caption_above_media = true;
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
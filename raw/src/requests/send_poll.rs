@@ -9,8 +9,14 @@ use crate::types::*;
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
 #[must_use = "requests do nothing unless sent"]
 pub struct SendPoll<'q, 'o, 'e> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    business_connection_id: Option<String>,
     chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_thread_id: Option<Integer>,
     question: Cow<'q, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    question_parse_mode: Option<ParseMode>,
     options: Vec<Cow<'o, str>>,
     #[serde(skip_serializing_if = "Clone::clone")]
     // This defaults to true, so don't skip serializing if false.
@@ -20,7 +26,6 @@ pub struct SendPoll<'q, 'o, 'e> {
     #[serde(skip_serializing_if = "Not::not")]
     allows_multiple_answers: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    // TODO: required for quiz polls
     correct_option_id: Option<Integer>,
     #[serde(skip_serializing_if = "Option::is_none")]
     explanation: Option<Cow<'e, str>>,
@@ -32,8 +37,14 @@ pub struct SendPoll<'q, 'o, 'e> {
     close_date: Option<Integer>,
     #[serde(skip_serializing_if = "Not::not")]
     is_closed: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    protect_content: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    reply_to_message_id: Option<MessageId>,
+    message_effect_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_parameters: Option<ReplyParameters>,
     #[serde(skip_serializing_if = "Option::is_none")]
     reply_markup: Option<ReplyMarkup>,
 }
@@ -62,8 +73,11 @@ impl<'q, 'o, 'e> SendPoll<'q, 'o, 'e> {
         }
 
         SendPoll {
+            business_connection_id: None,
             chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
             question: question.into(),
+            question_parse_mode: None,
             options: req_options,
             is_anonymous: true,
             type_: None,
@@ -74,11 +88,32 @@ impl<'q, 'o, 'e> SendPoll<'q, 'o, 'e> {
             open_period: None,
             close_date: None,
             is_closed: false,
-            reply_to_message_id: None,
+            disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
+            reply_parameters: None,
             reply_markup: None,
         }
     }
 
+    /// Create a quiz poll. Telegram requires the 0-based identifier of the correct answer
+    /// option for polls in the quiz mode.
+    pub fn new_quiz<C, Q, O>(
+        chat: C,
+        question: Q,
+        options: Vec<O>,
+        correct_option_id: Integer,
+    ) -> Self
+    where
+        C: ToChatRef,
+        Q: Into<Cow<'q, str>>,
+        O: Into<Cow<'o, str>>,
+    {
+        let mut poll = Self::new(chat, question, options);
+        poll.quiz().correct_option_id(correct_option_id);
+        poll
+    }
+
     pub fn add_option<O>(&mut self, option: O) -> &mut Self
     where
         O: Into<Cow<'o, str>>,
@@ -102,6 +137,12 @@ impl<'q, 'o, 'e> SendPoll<'q, 'o, 'e> {
         self
     }
 
+    /// Mode for parsing entities in the question. Only custom emoji are allowed.
+    pub fn question_parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.question_parse_mode = Some(parse_mode);
+        self
+    }
+
     pub fn allows_multiple_answers(&mut self) -> &mut Self {
         self.allows_multiple_answers = true;
         self
@@ -141,11 +182,52 @@ impl<'q, 'o, 'e> SendPoll<'q, 'o, 'e> {
         self
     }
 
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
+
     pub fn reply_to<R>(&mut self, to: R) -> &mut Self
     where
         R: ToMessageId,
     {
-        self.reply_to_message_id = Some(to.to_message_id());
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
         self
     }
 
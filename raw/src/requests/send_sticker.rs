@@ -0,0 +1,169 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to send static .WEBP, animated .TGS, or video .WEBM stickers.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendSticker {
+    business_connection_id: Option<String>,
+    chat_id: ChatRef,
+    message_thread_id: Option<Integer>,
+    sticker: InputFile,
+    emoji: Option<String>,
+    reply_parameters: Option<ReplyParameters>,
+    disable_notification: bool,
+    protect_content: bool,
+    message_effect_id: Option<String>,
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl ToMultipart for SendSticker {
+    fn to_multipart(&self) -> Result<Multipart, Error> {
+        multipart_map! {
+            self,
+            (business_connection_id (text), optional);
+            (chat_id (text));
+            (message_thread_id (text), optional);
+            (sticker (raw));
+            (emoji (text), optional);
+            (reply_parameters (json), optional);
+            (disable_notification (text), when_true);
+            (protect_content (text), when_true);
+            (message_effect_id (text), optional);
+            (reply_markup (json), optional);
+        }
+    }
+}
+
+impl Request for SendSticker {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonIdResponse<MessageOrChannelPost>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendSticker"), self)
+    }
+}
+
+impl SendSticker {
+    pub fn new<C, V>(chat: C, sticker: V) -> Self
+    where
+        C: ToChatRef,
+        V: Into<InputFile>,
+    {
+        Self {
+            business_connection_id: None,
+            chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
+            sticker: sticker.into(),
+            emoji: None,
+            reply_parameters: None,
+            disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Emoji associated with the sticker; only for just uploaded stickers.
+    pub fn emoji<E: Into<String>>(&mut self, emoji: E) -> &mut Self {
+        self.emoji = Some(emoji.into());
+        self
+    }
+
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+/// Can reply with a sticker
+pub trait CanReplySendSticker {
+    fn sticker_reply<T>(&self, sticker: T) -> SendSticker
+    where
+        T: Into<InputFile>;
+}
+
+impl<M> CanReplySendSticker for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn sticker_reply<T>(&self, sticker: T) -> SendSticker
+    where
+        T: Into<InputFile>,
+    {
+        let mut req = SendSticker::new(self.to_source_chat(), sticker);
+        req.reply_to(self);
+        req
+    }
+}
+
+/// Send a sticker
+pub trait CanSendSticker {
+    fn sticker<T>(&self, sticker: T) -> SendSticker
+    where
+        T: Into<InputFile>;
+}
+
+impl<C> CanSendSticker for C
+where
+    C: ToChatRef,
+{
+    fn sticker<T>(&self, sticker: T) -> SendSticker
+    where
+        T: Into<InputFile>,
+    {
+        SendSticker::new(self, sticker)
+    }
+}
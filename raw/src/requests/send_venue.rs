@@ -8,17 +8,31 @@ use crate::types::*;
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
 #[must_use = "requests do nothing unless sent"]
 pub struct SendVenue<'t, 'a, 'f> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    business_connection_id: Option<String>,
     chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_thread_id: Option<Integer>,
     latitude: Float,
     longitude: Float,
     title: Cow<'t, str>,
     address: Cow<'a, str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     foursquare_id: Option<Cow<'f, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foursquare_type: Option<Cow<'f, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    google_place_id: Option<Cow<'f, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    google_place_type: Option<Cow<'f, str>>,
     #[serde(skip_serializing_if = "Not::not")]
     disable_notification: bool,
+    #[serde(skip_serializing_if = "Not::not")]
+    protect_content: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_effect_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    reply_to_message_id: Option<MessageId>,
+    reply_parameters: Option<ReplyParameters>,
     #[serde(skip_serializing_if = "Option::is_none")]
     reply_markup: Option<ReplyMarkup>,
 }
@@ -40,23 +54,57 @@ impl<'t, 'a, 'f> SendVenue<'t, 'a, 'f> {
         A: Into<Cow<'a, str>>,
     {
         SendVenue {
+            business_connection_id: None,
             chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
             latitude: latitude,
             longitude: longitude,
             title: title.into(),
             address: address.into(),
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
             foursquare_id: None,
-            reply_to_message_id: None,
+            foursquare_type: None,
+            google_place_id: None,
+            google_place_type: None,
+            reply_parameters: None,
             reply_markup: None,
         }
     }
 
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
     pub fn disable_notification(&mut self) -> &mut Self {
         self.disable_notification = true;
         self
     }
 
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
+
     pub fn foursquare_id<F>(&mut self, id: F) -> &mut Self
     where
         F: Into<Cow<'f, str>>,
@@ -65,11 +113,48 @@ impl<'t, 'a, 'f> SendVenue<'t, 'a, 'f> {
         self
     }
 
+    /// Foursquare type of the venue, if known. For example, “arts_entertainment/default”,
+    /// “arts_entertainment/aquarium” or “food/icecream”.
+    pub fn foursquare_type<F>(&mut self, kind: F) -> &mut Self
+    where
+        F: Into<Cow<'f, str>>,
+    {
+        self.foursquare_type = Some(kind.into());
+        self
+    }
+
+    /// Google Places identifier of the venue.
+    pub fn google_place_id<F>(&mut self, id: F) -> &mut Self
+    where
+        F: Into<Cow<'f, str>>,
+    {
+        self.google_place_id = Some(id.into());
+        self
+    }
+
+    /// Google Places type of the venue.
+    pub fn google_place_type<F>(&mut self, kind: F) -> &mut Self
+    where
+        F: Into<Cow<'f, str>>,
+    {
+        self.google_place_type = Some(kind.into());
+        self
+    }
+
     pub fn reply_to<R>(&mut self, to: R) -> &mut Self
     where
         R: ToMessageId,
     {
-        self.reply_to_message_id = Some(to.to_message_id());
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
         self
     }
 
@@ -82,6 +167,23 @@ impl<'t, 'a, 'f> SendVenue<'t, 'a, 'f> {
     }
 }
 
+impl<'b> SendVenue<'b, 'b, 'b> {
+    fn place_ids_from(&mut self, venue: &'b Venue) {
+        if let Some(ref foursquare_id) = venue.foursquare_id {
+            self.foursquare_id(foursquare_id.as_str());
+        }
+        if let Some(ref foursquare_type) = venue.foursquare_type {
+            self.foursquare_type(foursquare_type.as_str());
+        }
+        if let Some(ref google_place_id) = venue.google_place_id {
+            self.google_place_id(google_place_id.as_str());
+        }
+        if let Some(ref google_place_type) = venue.google_place_type {
+            self.google_place_type(google_place_type.as_str());
+        }
+    }
+}
+
 /// Send information about a venue.
 pub trait CanSendVenue<'t, 'a, 'f> {
     fn venue<T, A>(
@@ -165,9 +267,7 @@ impl<'b> ToRequest<'b> for Venue {
             self.title.as_str(),
             self.address.as_str(),
         );
-        if let Some(ref foursquare_id) = self.foursquare_id {
-            rq.foursquare_id(foursquare_id.as_str());
-        }
+        rq.place_ids_from(self);
         rq
     }
 }
@@ -185,9 +285,7 @@ impl<'b> ToReplyRequest<'b> for Venue {
             self.title.as_str(),
             self.address.as_str(),
         );
-        if let Some(ref foursquare_id) = self.foursquare_id {
-            rq.foursquare_id(foursquare_id.as_str());
-        }
+        rq.place_ids_from(self);
         rq
     }
 }
@@ -3,21 +3,27 @@ use std::borrow::Cow;
 use crate::requests::*;
 use crate::types::*;
 
-/// Use this method to send an video
+/// Use this method to send video files, Telegram clients support mp4 videos
+/// (other formats may be sent as Document).
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 #[must_use = "requests do nothing unless sent"]
 pub struct SendVideo<'c> {
+    business_connection_id: Option<String>,
     chat_id: ChatRef,
+    message_thread_id: Option<Integer>,
     video: InputFile,
     caption: Option<Cow<'c, str>>,
     parse_mode: Option<ParseMode>,
+    caption_entities: Option<Vec<MessageEntity>>,
     duration: Option<Integer>,
     width: Option<Integer>,
     height: Option<Integer>,
     supports_streaming: bool,
-    thumb: Option<InputFile>,
-    reply_to_message_id: Option<MessageId>,
+    thumbnail: Option<InputFile>,
+    reply_parameters: Option<ReplyParameters>,
     disable_notification: bool,
+    protect_content: bool,
+    message_effect_id: Option<String>,
     reply_markup: Option<ReplyMarkup>,
 }
 
@@ -25,17 +31,22 @@ impl<'c> ToMultipart for SendVideo<'c> {
     fn to_multipart(&self) -> Result<Multipart, Error> {
         multipart_map! {
             self,
+            (business_connection_id (text), optional);
             (chat_id (text));
+            (message_thread_id (text), optional);
             (video (raw));
             (caption (text), optional);
             (parse_mode (text), optional);
+            (caption_entities (json), optional);
             (duration (text), optional);
             (width (text), optional);
             (height (text), optional);
             (supports_streaming (text), when_true);
-            (thumb (raw), optional);
-            (reply_to_message_id (text), optional);
+            (thumbnail (raw), optional);
+            (reply_parameters (json), optional);
             (disable_notification (text), when_true);
+            (protect_content (text), when_true);
+            (message_effect_id (text), optional);
             (reply_markup (json), optional);
         }
     }
@@ -57,26 +68,34 @@ impl<'c> SendVideo<'c> {
         V: Into<InputFile>,
     {
         Self {
+            business_connection_id: None,
             chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
             video: video.into(),
             caption: None,
             parse_mode: None,
+            caption_entities: None,
             duration: None,
             width: None,
             height: None,
             supports_streaming: false,
-            thumb: None,
-            reply_to_message_id: None,
+            thumbnail: None,
+            reply_parameters: None,
             reply_markup: None,
             disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
         }
     }
 
+    /// Thumbnail of the file sent. The thumbnail should be in JPEG format and less than 200 kB
+    /// in size. A thumbnail's width and height should not exceed 320.
+    /// Thumbnails can't be reused and can be only uploaded as a new file.
     pub fn thumb<V>(&mut self, thumb: V) -> &mut Self
     where
         V: Into<InputFileUpload>,
     {
-        self.thumb = Some(thumb.into().into());
+        self.thumbnail = Some(thumb.into().into());
         self
     }
 
@@ -93,6 +112,12 @@ impl<'c> SendVideo<'c> {
         self
     }
 
+    /// Entities of the caption, which can be specified instead of `parse_mode`.
+    pub fn caption_entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(entities);
+        self
+    }
+
     pub fn duration(&mut self, duration: Integer) -> &mut Self {
         self.duration = Some(duration);
         self
@@ -108,16 +133,41 @@ impl<'c> SendVideo<'c> {
         self
     }
 
+    /// Pass True, if the uploaded video is suitable for streaming.
+    /// Without it, clients have to download the whole file before it can be played or seeked.
     pub fn supports_streaming(&mut self) -> &mut Self {
         self.supports_streaming = true;
         self
     }
 
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
     pub fn reply_to<R>(&mut self, to: R) -> &mut Self
     where
         R: ToMessageId,
     {
-        self.reply_to_message_id = Some(to.to_message_id());
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
         self
     }
 
@@ -133,9 +183,22 @@ impl<'c> SendVideo<'c> {
         self.disable_notification = true;
         self
     }
+
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
 }
 
-/// Can reply with an video
+/// Can reply with a video
 pub trait CanReplySendVideo {
     fn video_reply<'c, T>(&self, video: T) -> SendVideo<'c>
     where
@@ -156,7 +219,7 @@ where
     }
 }
 
-/// Send an video
+/// Send a video
 pub trait CanSendVideo {
     fn video<'c, T>(&self, video: T) -> SendVideo<'c>
     where
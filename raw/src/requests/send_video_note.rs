@@ -0,0 +1,189 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to send rounded square mp4 videos of up to 1 minute long.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendVideoNote {
+    business_connection_id: Option<String>,
+    chat_id: ChatRef,
+    message_thread_id: Option<Integer>,
+    video_note: InputFile,
+    duration: Option<Integer>,
+    length: Option<Integer>,
+    thumbnail: Option<InputFile>,
+    reply_parameters: Option<ReplyParameters>,
+    disable_notification: bool,
+    protect_content: bool,
+    message_effect_id: Option<String>,
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl ToMultipart for SendVideoNote {
+    fn to_multipart(&self) -> Result<Multipart, Error> {
+        multipart_map! {
+            self,
+            (business_connection_id (text), optional);
+            (chat_id (text));
+            (message_thread_id (text), optional);
+            (video_note (raw));
+            (duration (text), optional);
+            (length (text), optional);
+            (thumbnail (raw), optional);
+            (reply_parameters (json), optional);
+            (disable_notification (text), when_true);
+            (protect_content (text), when_true);
+            (message_effect_id (text), optional);
+            (reply_markup (json), optional);
+        }
+    }
+}
+
+impl Request for SendVideoNote {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendVideoNote"), self)
+    }
+}
+
+impl SendVideoNote {
+    /// Sending video notes by a URL is currently unsupported.
+    pub fn new<C, V>(chat: C, video_note: V) -> Self
+    where
+        C: ToChatRef,
+        V: Into<InputFile>,
+    {
+        Self {
+            business_connection_id: None,
+            chat_id: chat.to_chat_ref(),
+            message_thread_id: None,
+            video_note: video_note.into(),
+            duration: None,
+            length: None,
+            thumbnail: None,
+            reply_parameters: None,
+            reply_markup: None,
+            disable_notification: false,
+            protect_content: false,
+            message_effect_id: None,
+        }
+    }
+
+    pub fn thumb<V>(&mut self, thumb: V) -> &mut Self
+    where
+        V: Into<InputFileUpload>,
+    {
+        self.thumbnail = Some(thumb.into().into());
+        self
+    }
+
+    pub fn duration(&mut self, duration: Integer) -> &mut Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Video width and height, i.e. diameter of the video message.
+    pub fn length(&mut self, length: Integer) -> &mut Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Unique identifier of the business connection on behalf of which the message
+    /// will be sent.
+    pub fn business_connection_id<B: Into<String>>(&mut self, id: B) -> &mut Self {
+        self.business_connection_id = Some(id.into());
+        self
+    }
+
+    /// Unique identifier for the target message thread (topic) of the forum; for forum
+    /// supergroups only.
+    pub fn message_thread_id(&mut self, thread_id: Integer) -> &mut Self {
+        self.message_thread_id = Some(thread_id);
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_parameters = Some(ReplyParameters::new(to));
+        self
+    }
+
+    /// Reply to a message, possibly in another chat or quoting a part of it.
+    pub fn reply_parameters<R>(&mut self, parameters: R) -> &mut Self
+    where
+        R: Into<ReplyParameters>,
+    {
+        self.reply_parameters = Some(parameters.into());
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    /// Protects the contents of the sent message from forwarding and saving.
+    pub fn protect_content(&mut self) -> &mut Self {
+        self.protect_content = true;
+        self
+    }
+
+    /// Unique identifier of the message effect to be added to the message; for private
+    /// chats only.
+    pub fn message_effect_id<E: Into<String>>(&mut self, effect_id: E) -> &mut Self {
+        self.message_effect_id = Some(effect_id.into());
+        self
+    }
+}
+
+/// Can reply with a video note
+pub trait CanReplySendVideoNote {
+    fn video_note_reply<T>(&self, video_note: T) -> SendVideoNote
+    where
+        T: Into<InputFile>;
+}
+
+impl<M> CanReplySendVideoNote for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn video_note_reply<T>(&self, video_note: T) -> SendVideoNote
+    where
+        T: Into<InputFile>,
+    {
+        let mut req = SendVideoNote::new(self.to_source_chat(), video_note);
+        req.reply_to(self);
+        req
+    }
+}
+
+/// Send a video note
+pub trait CanSendVideoNote {
+    fn video_note<T>(&self, video_note: T) -> SendVideoNote
+    where
+        T: Into<InputFile>;
+}
+
+impl<M> CanSendVideoNote for M
+where
+    M: ToChatRef,
+{
+    fn video_note<T>(&self, video_note: T) -> SendVideoNote
+    where
+        T: Into<InputFile>,
+    {
+        SendVideoNote::new(self.to_chat_ref(), video_note)
+    }
+}
@@ -0,0 +1,39 @@
+use std::borrow::Cow;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to set a custom title for an administrator in a supergroup promoted
+/// by the bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetChatAdministratorCustomTitle<'t> {
+    chat_id: ChatRef,
+    user_id: UserId,
+    custom_title: Cow<'t, str>,
+}
+
+impl<'t> Request for SetChatAdministratorCustomTitle<'t> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setChatAdministratorCustomTitle"), self)
+    }
+}
+
+impl<'t> SetChatAdministratorCustomTitle<'t> {
+    /// Set the custom title of the administrator, 0-16 characters. Emoji are not allowed.
+    pub fn new<C, U, T>(chat: C, user: U, custom_title: T) -> Self
+    where
+        C: ToChatRef,
+        U: ToUserId,
+        T: Into<Cow<'t, str>>,
+    {
+        SetChatAdministratorCustomTitle {
+            chat_id: chat.to_chat_ref(),
+            user_id: user.to_user_id(),
+            custom_title: custom_title.into(),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::borrow::Cow;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to change the description of a group, a supergroup or a channel.
+/// The bot must be an administrator in the chat for this to work and must have
+/// the appropriate admin rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetChatDescription<'d> {
+    chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Cow<'d, str>>,
+}
+
+impl<'d> Request for SetChatDescription<'d> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setChatDescription"), self)
+    }
+}
+
+impl<'d> SetChatDescription<'d> {
+    /// Pass `None` to remove the description.
+    pub fn new<C, D>(chat: C, description: Option<D>) -> Self
+    where
+        C: ToChatRef,
+        D: Into<Cow<'d, str>>,
+    {
+        SetChatDescription {
+            chat_id: chat.to_chat_ref(),
+            description: description.map(Into::into),
+        }
+    }
+}
+
+/// Change the description of a chat.
+pub trait CanSetChatDescription {
+    fn set_description<'d, D>(&self, description: Option<D>) -> SetChatDescription<'d>
+    where
+        D: Into<Cow<'d, str>>;
+}
+
+impl<C> CanSetChatDescription for C
+where
+    C: ToChatRef,
+{
+    fn set_description<'d, D>(&self, description: Option<D>) -> SetChatDescription<'d>
+    where
+        D: Into<Cow<'d, str>>,
+    {
+        SetChatDescription::new(self, description)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to change the bot's menu button in a private chat, or the default menu button.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetChatMenuButton {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chat_id: Option<ChatRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    menu_button: Option<MenuButton>,
+}
+
+impl Request for SetChatMenuButton {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setChatMenuButton"), self)
+    }
+}
+
+impl SetChatMenuButton {
+    /// Changes the default menu button, unless a chat is specified.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Private chat for which the menu button will be changed.
+    pub fn chat<C: ToChatRef>(&mut self, chat: C) -> &mut Self {
+        self.chat_id = Some(chat.to_chat_ref());
+        self
+    }
+
+    /// The new menu button. Defaults to `MenuButton::Default`.
+    pub fn menu_button(&mut self, menu_button: MenuButton) -> &mut Self {
+        self.menu_button = Some(menu_button);
+        self
+    }
+}
+
+/// Change the bot's menu button in a private chat.
+pub trait CanSetChatMenuButton {
+    fn set_menu_button(&self, menu_button: MenuButton) -> SetChatMenuButton;
+}
+
+impl<C> CanSetChatMenuButton for C
+where
+    C: ToChatRef,
+{
+    fn set_menu_button(&self, menu_button: MenuButton) -> SetChatMenuButton {
+        let mut rq = SetChatMenuButton::new();
+        rq.chat(self).menu_button(menu_button);
+        rq
+    }
+}
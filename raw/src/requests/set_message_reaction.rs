@@ -0,0 +1,77 @@
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to change the chosen reactions on a message. Service messages can't be
+/// reacted to. Automatically forwarded messages from a channel to its discussion group
+/// have the same available reactions as messages in the channel.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetMessageReaction {
+    chat_id: ChatRef,
+    message_id: MessageId,
+    reaction: Vec<ReactionType>,
+    #[serde(skip_serializing_if = "Not::not")]
+    is_big: bool,
+}
+
+impl Request for SetMessageReaction {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setMessageReaction"), self)
+    }
+}
+
+impl SetMessageReaction {
+    /// Creates a request without any reactions, which removes the bot's reactions
+    /// from the message.
+    pub fn new<C, M>(chat: C, message: M) -> Self
+    where
+        C: ToChatRef,
+        M: ToMessageId,
+    {
+        SetMessageReaction {
+            chat_id: chat.to_chat_ref(),
+            message_id: message.to_message_id(),
+            reaction: Vec::new(),
+            is_big: false,
+        }
+    }
+
+    /// Add a reaction to set on the message. Bots can set up to one reaction per message.
+    pub fn reaction<R: Into<ReactionType>>(&mut self, reaction: R) -> &mut Self {
+        self.reaction.push(reaction.into());
+        self
+    }
+
+    /// Set the reaction with a big animation.
+    pub fn big(&mut self) -> &mut Self {
+        self.is_big = true;
+        self
+    }
+}
+
+/// React to a message.
+pub trait CanSetMessageReaction {
+    fn react<R: Into<ReactionType>>(&self, reaction: R) -> SetMessageReaction;
+
+    fn remove_reactions(&self) -> SetMessageReaction;
+}
+
+impl<M> CanSetMessageReaction for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn react<R: Into<ReactionType>>(&self, reaction: R) -> SetMessageReaction {
+        let mut rq = self.remove_reactions();
+        rq.reaction(reaction);
+        rq
+    }
+
+    fn remove_reactions(&self) -> SetMessageReaction {
+        SetMessageReaction::new(self.to_source_chat(), self)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to change the list of the bot's commands.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetMyCommands {
+    commands: Vec<BotCommand>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<BotCommandScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<String>,
+}
+
+impl Request for SetMyCommands {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setMyCommands"), self)
+    }
+}
+
+impl SetMyCommands {
+    /// At most 100 commands can be specified.
+    pub fn new(commands: Vec<BotCommand>) -> Self {
+        Self {
+            commands,
+            scope: None,
+            language_code: None,
+        }
+    }
+
+    pub fn add_command(&mut self, command: BotCommand) -> &mut Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Scope of users for which the commands are relevant. Defaults to `BotCommandScope::Default`.
+    pub fn scope(&mut self, scope: BotCommandScope) -> &mut Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// A two-letter ISO 639-1 language code. If empty, commands will be applied to all users
+    /// from the given scope, for whose language there are no dedicated commands.
+    pub fn language_code<L: Into<String>>(&mut self, language_code: L) -> &mut Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
@@ -0,0 +1,46 @@
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to change the default administrator rights requested by the bot
+/// when it's added as an administrator to groups or channels.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetMyDefaultAdministratorRights {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rights: Option<ChatAdministratorRights>,
+    #[serde(skip_serializing_if = "Not::not")]
+    for_channels: bool,
+}
+
+impl Request for SetMyDefaultAdministratorRights {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setMyDefaultAdministratorRights"), self)
+    }
+}
+
+impl SetMyDefaultAdministratorRights {
+    /// Without rights, the default administrator rights will be cleared.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn rights<R>(&mut self, rights: R) -> &mut Self
+    where
+        R: Into<ChatAdministratorRights>,
+    {
+        self.rights = Some(rights.into());
+        self
+    }
+
+    /// Change the default administrator rights of the bot in channels,
+    /// instead of groups and supergroups.
+    pub fn for_channels(&mut self) -> &mut Self {
+        self.for_channels = true;
+        self
+    }
+}
@@ -0,0 +1,38 @@
+use crate::requests::*;
+
+/// Use this method to change the bot's description, which is shown in the chat
+/// with the bot if the chat is empty.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetMyDescription {
+    description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<String>,
+}
+
+impl Request for SetMyDescription {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setMyDescription"), self)
+    }
+}
+
+impl SetMyDescription {
+    /// New description, 0-512 characters. Pass an empty string to remove
+    /// the dedicated description for the given language.
+    pub fn new<T: Into<String>>(description: T) -> Self {
+        Self {
+            description: description.into(),
+            language_code: None,
+        }
+    }
+
+    /// A two-letter ISO 639-1 language code. If empty, the description will be
+    /// shown to all users for whose language there is no dedicated description.
+    pub fn language_code<L: Into<String>>(&mut self, language_code: L) -> &mut Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
@@ -0,0 +1,37 @@
+use crate::requests::*;
+
+/// Use this method to change the bot's name.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetMyName {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<String>,
+}
+
+impl Request for SetMyName {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setMyName"), self)
+    }
+}
+
+impl SetMyName {
+    /// New name, 0-64 characters. Pass an empty string to remove
+    /// the dedicated name for the given language.
+    pub fn new<T: Into<String>>(name: T) -> Self {
+        Self {
+            name: name.into(),
+            language_code: None,
+        }
+    }
+
+    /// A two-letter ISO 639-1 language code. If empty, the name will be
+    /// shown to all users for whose language there is no dedicated name.
+    pub fn language_code<L: Into<String>>(&mut self, language_code: L) -> &mut Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
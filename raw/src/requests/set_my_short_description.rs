@@ -0,0 +1,38 @@
+use crate::requests::*;
+
+/// Use this method to change the bot's short description, which is shown on the bot's
+/// profile page and is sent together with the link when users share the bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetMyShortDescription {
+    short_description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<String>,
+}
+
+impl Request for SetMyShortDescription {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setMyShortDescription"), self)
+    }
+}
+
+impl SetMyShortDescription {
+    /// New short description, 0-120 characters. Pass an empty string to remove
+    /// the dedicated short description for the given language.
+    pub fn new<T: Into<String>>(short_description: T) -> Self {
+        Self {
+            short_description: short_description.into(),
+            language_code: None,
+        }
+    }
+
+    /// A two-letter ISO 639-1 language code. If empty, the short description will be
+    /// shown to all users for whose language there is no dedicated short description.
+    pub fn language_code<L: Into<String>>(&mut self, language_code: L) -> &mut Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+}
@@ -0,0 +1,43 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to move a sticker in a set created by the bot to a specific position.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetStickerPositionInSet {
+    sticker: FileRef,
+    position: Integer,
+}
+
+impl Request for SetStickerPositionInSet {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setStickerPositionInSet"), self)
+    }
+}
+
+impl SetStickerPositionInSet {
+    /// Positions are zero-based.
+    pub fn new<F>(sticker: F, position: Integer) -> Self
+    where
+        F: ToFileRef,
+    {
+        Self {
+            sticker: sticker.to_file_ref(),
+            position,
+        }
+    }
+}
+
+/// Move a sticker in a set created by the bot.
+pub trait CanSetStickerPositionInSet {
+    fn set_position_in_set(&self, position: Integer) -> SetStickerPositionInSet;
+}
+
+impl CanSetStickerPositionInSet for Sticker {
+    fn set_position_in_set(&self, position: Integer) -> SetStickerPositionInSet {
+        SetStickerPositionInSet::new(self, position)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to specify a URL and receive incoming updates via an outgoing webhook.
+/// Whenever there is an update for the bot, Telegram will send an HTTPS POST request
+/// to the specified URL, containing a JSON-serialized `Update`.
+///
+/// `GetUpdates` can't be used while a webhook is set up.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetWebhook {
+    url: String,
+    certificate: Option<InputFile>,
+    ip_address: Option<String>,
+    max_connections: Option<Integer>,
+    allowed_updates: Option<Vec<AllowedUpdate>>,
+    drop_pending_updates: bool,
+    secret_token: Option<String>,
+}
+
+impl ToMultipart for SetWebhook {
+    fn to_multipart(&self) -> Result<Multipart, Error> {
+        multipart_map! {
+            self,
+            (url (text));
+            (certificate (raw), optional);
+            (ip_address (text), optional);
+            (max_connections (text), optional);
+            (allowed_updates (json), optional);
+            (drop_pending_updates (text), when_true);
+            (secret_token (text), optional);
+        }
+    }
+}
+
+impl Request for SetWebhook {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setWebhook"), self)
+    }
+}
+
+impl SetWebhook {
+    /// Set up a webhook at the given HTTPS URL, an empty URL removes the webhook.
+    pub fn new<U>(url: U) -> Self
+    where
+        U: Into<String>,
+    {
+        SetWebhook {
+            url: url.into(),
+            certificate: None,
+            ip_address: None,
+            max_connections: None,
+            allowed_updates: None,
+            drop_pending_updates: false,
+            secret_token: None,
+        }
+    }
+
+    /// Upload your public key certificate so that the root certificate in use can be checked.
+    pub fn certificate<V>(&mut self, certificate: V) -> &mut Self
+    where
+        V: Into<InputFileUpload>,
+    {
+        self.certificate = Some(certificate.into().into());
+        self
+    }
+
+    /// The fixed IP address which will be used to send webhook requests instead of
+    /// the IP address resolved through DNS.
+    pub fn ip_address<T: Into<String>>(&mut self, ip_address: T) -> &mut Self {
+        self.ip_address = Some(ip_address.into());
+        self
+    }
+
+    /// The maximum allowed number of simultaneous HTTPS connections to the webhook
+    /// for update delivery, 1-100. Defaults to 40.
+    pub fn max_connections(&mut self, max_connections: Integer) -> &mut Self {
+        self.max_connections = Some(max_connections);
+        self
+    }
+
+    /// The kinds of updates the bot wants to receive. If not specified, the previous
+    /// setting is used.
+    pub fn allowed_updates(&mut self, updates: &[AllowedUpdate]) -> &mut Self {
+        self.allowed_updates = Some(updates.to_vec());
+        self
+    }
+
+    /// Drop all pending updates.
+    pub fn drop_pending_updates(&mut self) -> &mut Self {
+        self.drop_pending_updates = true;
+        self
+    }
+
+    /// A secret token, 1-256 characters `A-Z`, `a-z`, `0-9`, `_` and `-`, to be sent
+    /// in the `X-Telegram-Bot-Api-Secret-Token` header of every webhook request.
+    pub fn secret_token<T: Into<String>>(&mut self, token: T) -> &mut Self {
+        self.secret_token = Some(token.into());
+        self
+    }
+}
@@ -56,3 +56,42 @@ where
         StopMessageLiveLocation::new(self.to_source_chat(), self.to_message_id())
     }
 }
+
+/// Use this method to stop updating a live location message sent via the bot
+/// (for inline bots) before live_period expires.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct StopInlineMessageLiveLocation {
+    inline_message_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl Request for StopInlineMessageLiveLocation {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("stopMessageLiveLocation"), self)
+    }
+}
+
+impl StopInlineMessageLiveLocation {
+    pub fn new<I>(inline_message_id: I) -> Self
+    where
+        I: Into<String>,
+    {
+        StopInlineMessageLiveLocation {
+            inline_message_id: inline_message_id.into(),
+            reply_markup: None,
+        }
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
@@ -0,0 +1,34 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to unban a previously banned channel chat in a supergroup or channel.
+/// The bot must be an administrator for this to work and must have the appropriate
+/// administrator rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct UnbanChatSenderChat {
+    chat_id: ChatRef,
+    sender_chat_id: ChatId,
+}
+
+impl Request for UnbanChatSenderChat {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("unbanChatSenderChat"), self)
+    }
+}
+
+impl UnbanChatSenderChat {
+    pub fn new<C, S>(chat: C, sender_chat: S) -> Self
+    where
+        C: ToChatRef,
+        S: Into<ChatId>,
+    {
+        UnbanChatSenderChat {
+            chat_id: chat.to_chat_ref(),
+            sender_chat_id: sender_chat.into(),
+        }
+    }
+}
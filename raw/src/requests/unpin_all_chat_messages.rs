@@ -0,0 +1,46 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to clear the list of pinned messages in a chat.
+/// The bot must be an administrator in the chat for this to work
+/// and must have the ‘can_pin_messages’ admin right in the
+/// supergroup or ‘can_edit_messages’ admin right in the channel.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct UnpinAllChatMessages {
+    chat_id: ChatRef,
+}
+
+impl Request for UnpinAllChatMessages {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("unpinAllChatMessages"), self)
+    }
+}
+
+impl UnpinAllChatMessages {
+    pub fn new<C>(chat: C) -> Self
+    where
+        C: ToChatRef,
+    {
+        Self {
+            chat_id: chat.to_chat_ref(),
+        }
+    }
+}
+
+/// Clear the list of pinned messages in a chat.
+pub trait CanUnpinAllMessages {
+    fn unpin_all_messages(&self) -> UnpinAllChatMessages;
+}
+
+impl<C> CanUnpinAllMessages for C
+where
+    C: ToChatRef,
+{
+    fn unpin_all_messages(&self) -> UnpinAllChatMessages {
+        UnpinAllChatMessages::new(self)
+    }
+}
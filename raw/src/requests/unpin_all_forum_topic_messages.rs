@@ -0,0 +1,53 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to clear the list of pinned messages in a forum topic. The bot must be
+/// an administrator in the chat for this to work and must have the `can_pin_messages`
+/// administrator right in the supergroup.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct UnpinAllForumTopicMessages {
+    chat_id: ChatRef,
+    message_thread_id: Integer,
+}
+
+impl Request for UnpinAllForumTopicMessages {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("unpinAllForumTopicMessages"), self)
+    }
+}
+
+impl UnpinAllForumTopicMessages {
+    pub fn new<C>(chat: C, message_thread_id: Integer) -> Self
+    where
+        C: ToChatRef,
+    {
+        UnpinAllForumTopicMessages {
+            chat_id: chat.to_chat_ref(),
+            message_thread_id,
+        }
+    }
+}
+
+/// Clear the list of pinned messages in a forum topic.
+pub trait CanUnpinAllForumTopicMessages {
+    fn unpin_all_forum_topic_messages(
+        &self,
+        message_thread_id: Integer,
+    ) -> UnpinAllForumTopicMessages;
+}
+
+impl<C> CanUnpinAllForumTopicMessages for C
+where
+    C: ToChatRef,
+{
+    fn unpin_all_forum_topic_messages(
+        &self,
+        message_thread_id: Integer,
+    ) -> UnpinAllForumTopicMessages {
+        UnpinAllForumTopicMessages::new(self, message_thread_id)
+    }
+}
@@ -1,7 +1,8 @@
 use crate::requests::*;
 use crate::types::*;
 
-///Use this method to unpin a message in a supergroup or a channel.
+/// Use this method to unpin a message in a supergroup or a channel.
+/// If no message is specified, the most recent pinned message is unpinned.
 /// The bot must be an administrator in the chat for this to work
 /// and must have the ‘can_pin_messages’ admin right in the
 /// supergroup or ‘can_edit_messages’ admin right in the channel.
@@ -9,6 +10,8 @@ use crate::types::*;
 #[must_use = "requests do nothing unless sent"]
 pub struct UnpinChatMessage {
     chat_id: ChatRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_id: Option<MessageId>,
 }
 
 impl Request for UnpinChatMessage {
@@ -21,16 +24,27 @@ impl Request for UnpinChatMessage {
 }
 
 impl UnpinChatMessage {
-    fn new<C>(chat: C) -> Self
+    pub fn new<C>(chat: C) -> Self
     where
         C: ToChatRef,
     {
         Self {
             chat_id: chat.to_chat_ref(),
+            message_id: None,
         }
     }
+
+    /// Identifier of a message to unpin.
+    pub fn message_id<M>(&mut self, message: M) -> &mut Self
+    where
+        M: ToMessageId,
+    {
+        self.message_id = Some(message.to_message_id());
+        self
+    }
 }
 
+/// Unpin the most recent pinned message in a chat.
 pub trait CanUnpinMessage {
     fn unpin_message(&self) -> UnpinChatMessage;
 }
@@ -43,3 +57,19 @@ where
         UnpinChatMessage::new(self)
     }
 }
+
+/// Unpin message in a supergroup or a channel.
+pub trait CanUnpinMessageForMessage {
+    fn unpin(&self) -> UnpinChatMessage;
+}
+
+impl<M> CanUnpinMessageForMessage for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn unpin(&self) -> UnpinChatMessage {
+        let mut rq = UnpinChatMessage::new(self.to_source_chat());
+        rq.message_id(self.to_message_id());
+        rq
+    }
+}
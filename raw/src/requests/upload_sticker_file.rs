@@ -0,0 +1,47 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to upload a file with a sticker for later use in the
+/// `createNewStickerSet` and `addStickerToSet` methods (the file can be used multiple times).
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[must_use = "requests do nothing unless sent"]
+pub struct UploadStickerFile {
+    user_id: UserId,
+    sticker: InputFile,
+    sticker_format: StickerFormat,
+}
+
+impl ToMultipart for UploadStickerFile {
+    fn to_multipart(&self) -> Result<Multipart, Error> {
+        multipart_map! {
+            self,
+            (user_id (text));
+            (sticker (raw));
+            (sticker_format (text));
+        }
+    }
+}
+
+impl Request for UploadStickerFile {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonIdResponse<File>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("uploadStickerFile"), self)
+    }
+}
+
+impl UploadStickerFile {
+    /// The sticker can only be uploaded as a new file.
+    pub fn new<U, V>(user: U, sticker: V, sticker_format: StickerFormat) -> Self
+    where
+        U: ToUserId,
+        V: Into<InputFileUpload>,
+    {
+        Self {
+            user_id: user.to_user_id(),
+            sticker: sticker.into().into(),
+            sticker_format,
+        }
+    }
+}
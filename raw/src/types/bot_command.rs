@@ -0,0 +1,72 @@
+use crate::types::*;
+
+/// This object represents a bot command.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub struct BotCommand {
+    /// Text of the command, 1-32 characters.
+    /// Can contain only lowercase English letters, digits and underscores.
+    pub command: String,
+    /// Description of the command, 1-256 characters.
+    pub description: String,
+}
+
+impl BotCommand {
+    pub fn new<C, D>(command: C, description: D) -> Self
+    where
+        C: Into<String>,
+        D: Into<String>,
+    {
+        Self {
+            command: command.into(),
+            description: description.into(),
+        }
+    }
+}
+
+/// The scope to which bot commands are applied.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(tag = "type")]
+pub enum BotCommandScope {
+    /// Default commands are used if no commands with a narrower scope are specified for the user.
+    #[serde(rename = "default")]
+    Default,
+    /// Covers all private chats.
+    #[serde(rename = "all_private_chats")]
+    AllPrivateChats,
+    /// Covers all group and supergroup chats.
+    #[serde(rename = "all_group_chats")]
+    AllGroupChats,
+    /// Covers all group and supergroup chat administrators.
+    #[serde(rename = "all_chat_administrators")]
+    AllChatAdministrators,
+    /// Covers a specific chat.
+    #[serde(rename = "chat")]
+    Chat { chat_id: ChatRef },
+    /// Covers all administrators of a specific group or supergroup chat.
+    #[serde(rename = "chat_administrators")]
+    ChatAdministrators { chat_id: ChatRef },
+    /// Covers a specific member of a group or supergroup chat.
+    #[serde(rename = "chat_member")]
+    ChatMember { chat_id: ChatRef, user_id: UserId },
+}
+
+impl BotCommandScope {
+    pub fn chat<C: ToChatRef>(chat: C) -> Self {
+        BotCommandScope::Chat {
+            chat_id: chat.to_chat_ref(),
+        }
+    }
+
+    pub fn chat_administrators<C: ToChatRef>(chat: C) -> Self {
+        BotCommandScope::ChatAdministrators {
+            chat_id: chat.to_chat_ref(),
+        }
+    }
+
+    pub fn chat_member<C: ToChatRef, U: ToUserId>(chat: C, user: U) -> Self {
+        BotCommandScope::ChatMember {
+            chat_id: chat.to_chat_ref(),
+            user_id: user.to_user_id(),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+/// This object represents the bot's name.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct BotName {
+    /// The bot's name.
+    pub name: String,
+}
+
+/// This object represents the bot's description.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct BotDescription {
+    /// The bot's description.
+    pub description: String,
+}
+
+/// This object represents the bot's short description.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct BotShortDescription {
+    /// The bot's short description.
+    pub short_description: String,
+}
@@ -0,0 +1,32 @@
+use crate::types::*;
+
+/// Describes the connection of the bot with a business account.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct BusinessConnection {
+    /// Unique identifier of the business connection.
+    pub id: String,
+    /// Business account user that created the business connection.
+    pub user: User,
+    /// Identifier of a private chat with the user who created the business connection.
+    pub user_chat_id: Integer,
+    /// Date the connection was established in Unix time.
+    pub date: Integer,
+    /// True, if the bot can act on behalf of the business account in chats that were
+    /// active in the last 24 hours.
+    #[serde(default)]
+    pub can_reply: bool,
+    /// True, if the connection is active.
+    pub is_enabled: bool,
+}
+
+/// This object is received when messages are deleted from a connected business account.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct BusinessMessagesDeleted {
+    /// Unique identifier of the business connection.
+    pub business_connection_id: String,
+    /// Information about a chat in the business account. The bot may not have access
+    /// to the chat or the corresponding user.
+    pub chat: Chat,
+    /// The list of identifiers of deleted messages in the chat of the business account.
+    pub message_ids: Vec<MessageId>,
+}
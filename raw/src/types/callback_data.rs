@@ -0,0 +1,76 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::requests::{Error, ErrorKind};
+use crate::types::*;
+
+/// Maximum length of the data of a callback button, in bytes.
+pub const CALLBACK_DATA_MAX_LENGTH: usize = 64;
+
+/// Typed data of callback buttons, encoded to and decoded from the `callback_data` field.
+///
+/// Implement it for a type deriving `Serialize` and `Deserialize`, usually an enum of
+/// the actions of a keyboard. Values are encoded as JSON, so keep them short: unit and
+/// tuple variants are encoded more compactly than struct variants, and long names can
+/// be shortened with `#[serde(rename = "...")]`. Encoding fails if the data is longer
+/// than 64 bytes.
+///
+/// Variants are identified by name, so buttons of messages sent before variants were
+/// added or reordered can still be decoded.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use telegram_bot_raw::{CallbackData, InlineKeyboardButton};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// enum Action {
+///     #[serde(rename = "v")]
+///     Vote(i64, bool),
+///     #[serde(rename = "r")]
+///     Refresh,
+/// }
+///
+/// impl CallbackData for Action {}
+///
+/// let data = Action::Vote(42, true).encode_callback_data().unwrap();
+/// assert_eq!(data, r#"{"v":[42,true]}"#);
+/// assert_eq!(Action::decode_callback_data(&data).unwrap(), Action::Vote(42, true));
+///
+/// let button = InlineKeyboardButton::callback_data("Refresh", &Action::Refresh).unwrap();
+/// ```
+pub trait CallbackData: Serialize + DeserializeOwned {
+    /// Encode the value for the `callback_data` field of a button.
+    fn encode_callback_data(&self) -> Result<String, Error> {
+        let data = serde_json::to_string(self).map_err(ErrorKind::from)?;
+        if data.len() > CALLBACK_DATA_MAX_LENGTH {
+            return Err(ErrorKind::CallbackDataTooLong(data.len()).into());
+        }
+        Ok(data)
+    }
+
+    /// Decode the data of a callback query. Clients can send arbitrary data, so this
+    /// can fail even if the bot only sends valid buttons.
+    fn decode_callback_data(data: &str) -> Result<Self, Error> {
+        Ok(serde_json::from_str(data).map_err(ErrorKind::from)?)
+    }
+}
+
+impl CallbackQuery {
+    /// Decode the data of the pressed button, or `None` if the query has no data, e.g.
+    /// because a game button was pressed.
+    pub fn decode_data<T: CallbackData>(&self) -> Result<Option<T>, Error> {
+        match self.data {
+            Some(ref data) => T::decode_callback_data(data).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl InlineKeyboardButton {
+    /// Button sending a callback query with the encoded `data` when pressed.
+    pub fn callback_data<T: AsRef<str>, D: CallbackData>(text: T, data: &D) -> Result<Self, Error> {
+        Ok(Self::callback(text, data.encode_callback_data()?))
+    }
+}
@@ -3,19 +3,22 @@ use serde::de::{Deserialize, Deserializer, Error};
 use crate::types::*;
 
 /// This object represents a Telegram user or bot.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize, Serialize)]
 pub struct User {
     /// Unique identifier for this user or bot.
     pub id: UserId,
     /// User‘s or bot’s first name.
     pub first_name: String,
     /// User‘s or bot’s last name.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
     /// User‘s or bot’s username.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
     /// True, if this user is a bot.
     pub is_bot: bool,
     /// IETF language tag of the user's language
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 }
 
@@ -43,10 +46,18 @@ pub struct Supergroup {
     pub title: String,
     /// Username for supergroup.
     pub username: Option<String>,
+    /// True, if the supergroup chat is a forum (has topics enabled).
+    #[serde(default)]
+    pub is_forum: bool,
     /// Invite link for this supergroup, specific to this bot.
     /// You can generate a new invite link by using the
     /// export_invite_link method.
     pub invite_link: Option<String>,
+    /// For supergroups, name of the group sticker set. Returned only in `GetChat`.
+    pub sticker_set_name: Option<String>,
+    /// True, if the bot can change the group sticker set. Returned only in `GetChat`.
+    #[serde(default)]
+    pub can_set_sticker_set: bool,
 }
 
 /// This object represents a channel.
@@ -143,7 +154,10 @@ impl<'de> Deserialize<'de> for Chat {
                 id: raw.id.into(),
                 title: required_field!(title),
                 username: raw.username,
+                is_forum: raw.is_forum.unwrap_or(false),
                 invite_link: raw.invite_link,
+                sticker_set_name: raw.sticker_set_name,
+                can_set_sticker_set: raw.can_set_sticker_set.unwrap_or(false),
             }),
             "channel" => Chat::Channel(Channel {
                 id: raw.id.into(),
@@ -179,4 +193,10 @@ pub struct RawChat {
     pub language_code: Option<String>,
     /// True if a group has ‘All Members Are Admins’ enabled.
     pub all_members_are_administrators: Option<bool>,
+    /// True, if the supergroup chat is a forum (has topics enabled)
+    pub is_forum: Option<bool>,
+    /// For supergroups, name of the group sticker set. Returned only in getChat.
+    pub sticker_set_name: Option<String>,
+    /// True, if the bot can change the group sticker set. Returned only in getChat.
+    pub can_set_sticker_set: Option<bool>,
 }
@@ -0,0 +1,117 @@
+/// Represents the rights of an administrator in a chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct ChatAdministratorRights {
+    /// True, if the user's presence in the chat is hidden.
+    #[serde(default)]
+    pub is_anonymous: bool,
+    /// True, if the administrator can access the chat event log, get boost list, see hidden
+    /// supergroup and channel members, report spam messages and ignore slow mode.
+    #[serde(default)]
+    pub can_manage_chat: bool,
+    /// True, if the administrator can delete messages of other users.
+    #[serde(default)]
+    pub can_delete_messages: bool,
+    /// True, if the administrator can manage video chats.
+    #[serde(default)]
+    pub can_manage_video_chats: bool,
+    /// True, if the administrator can restrict, ban or unban chat members.
+    #[serde(default)]
+    pub can_restrict_members: bool,
+    /// True, if the administrator can add new administrators with a subset of their own
+    /// privileges or demote administrators that they have promoted, directly or indirectly.
+    #[serde(default)]
+    pub can_promote_members: bool,
+    /// True, if the user is allowed to change the chat title, photo and other settings.
+    #[serde(default)]
+    pub can_change_info: bool,
+    /// True, if the user is allowed to invite new users to the chat.
+    #[serde(default)]
+    pub can_invite_users: bool,
+    /// True, if the administrator can post messages in the channel; channels only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_messages: Option<bool>,
+    /// True, if the administrator can edit messages of other users and can pin messages;
+    /// channels only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_messages: Option<bool>,
+    /// True, if the user is allowed to pin messages; groups and supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+    /// True, if the user is allowed to create, rename, close, and reopen forum topics;
+    /// supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_topics: Option<bool>,
+}
+
+impl ChatAdministratorRights {
+    /// Rights with nothing granted.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn is_anonymous(&mut self, value: bool) -> &mut Self {
+        self.is_anonymous = value;
+        self
+    }
+
+    pub fn can_manage_chat(&mut self, value: bool) -> &mut Self {
+        self.can_manage_chat = value;
+        self
+    }
+
+    pub fn can_delete_messages(&mut self, value: bool) -> &mut Self {
+        self.can_delete_messages = value;
+        self
+    }
+
+    pub fn can_manage_video_chats(&mut self, value: bool) -> &mut Self {
+        self.can_manage_video_chats = value;
+        self
+    }
+
+    pub fn can_restrict_members(&mut self, value: bool) -> &mut Self {
+        self.can_restrict_members = value;
+        self
+    }
+
+    pub fn can_promote_members(&mut self, value: bool) -> &mut Self {
+        self.can_promote_members = value;
+        self
+    }
+
+    pub fn can_change_info(&mut self, value: bool) -> &mut Self {
+        self.can_change_info = value;
+        self
+    }
+
+    pub fn can_invite_users(&mut self, value: bool) -> &mut Self {
+        self.can_invite_users = value;
+        self
+    }
+
+    pub fn can_post_messages(&mut self, value: bool) -> &mut Self {
+        self.can_post_messages = Some(value);
+        self
+    }
+
+    pub fn can_edit_messages(&mut self, value: bool) -> &mut Self {
+        self.can_edit_messages = Some(value);
+        self
+    }
+
+    pub fn can_pin_messages(&mut self, value: bool) -> &mut Self {
+        self.can_pin_messages = Some(value);
+        self
+    }
+
+    pub fn can_manage_topics(&mut self, value: bool) -> &mut Self {
+        self.can_manage_topics = Some(value);
+        self
+    }
+}
+
+impl<'a> From<&'a mut ChatAdministratorRights> for ChatAdministratorRights {
+    fn from(value: &'a mut ChatAdministratorRights) -> ChatAdministratorRights {
+        value.clone()
+    }
+}
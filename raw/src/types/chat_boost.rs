@@ -0,0 +1,77 @@
+use crate::types::*;
+
+/// This object contains information about a chat boost.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct ChatBoost {
+    /// Unique identifier of the boost.
+    pub boost_id: String,
+    /// Point in time (Unix timestamp) when the chat was boosted.
+    pub add_date: Integer,
+    /// Point in time (Unix timestamp) when the boost will automatically expire,
+    /// unless the booster's Telegram Premium subscription is prolonged.
+    pub expiration_date: Integer,
+    /// Source of the added boost.
+    pub source: ChatBoostSource,
+}
+
+/// Source of a chat boost.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+#[serde(tag = "source")]
+pub enum ChatBoostSource {
+    /// The boost was obtained by subscribing to Telegram Premium or by gifting
+    /// a Telegram Premium subscription to another user.
+    #[serde(rename = "premium")]
+    Premium {
+        /// User that boosted the chat.
+        user: User,
+    },
+    /// The boost was obtained by the creation of Telegram Premium gift codes to boost a chat.
+    #[serde(rename = "gift_code")]
+    GiftCode {
+        /// User for which the gift code was created.
+        user: User,
+    },
+    /// The boost was obtained by the creation of a Telegram Premium giveaway.
+    #[serde(rename = "giveaway")]
+    Giveaway {
+        /// Identifier of a message in the chat with the giveaway.
+        giveaway_message_id: MessageId,
+        /// User that won the prize in the giveaway if any.
+        user: Option<User>,
+        /// True, if the giveaway was completed, but there was no user to win the prize.
+        #[serde(default)]
+        is_unclaimed: bool,
+    },
+    #[doc(hidden)]
+    #[serde(other)]
+    Unknown,
+}
+
+/// This object represents a boost added to a chat or changed.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct ChatBoostUpdated {
+    /// Chat which was boosted.
+    pub chat: Chat,
+    /// Information about the chat boost.
+    pub boost: ChatBoost,
+}
+
+/// This object represents a boost removed from a chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct ChatBoostRemoved {
+    /// Chat which was boosted.
+    pub chat: Chat,
+    /// Unique identifier of the boost.
+    pub boost_id: String,
+    /// Point in time (Unix timestamp) when the boost was removed.
+    pub remove_date: Integer,
+    /// Source of the removed boost.
+    pub source: ChatBoostSource,
+}
+
+/// This object represents a list of boosts added to a chat by a user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct UserChatBoosts {
+    /// The list of boosts added to the chat by the user.
+    pub boosts: Vec<ChatBoost>,
+}
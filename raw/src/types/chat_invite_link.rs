@@ -7,12 +7,19 @@ pub struct ChatInviteLink {
     pub invite_link: String,
     /// Creator of the link
     pub creator: User,
+    /// True, if users joining the chat via the link need to be approved by chat administrators
+    #[serde(default)]
+    pub creates_join_request: bool,
     /// True, if the link is primary
     pub is_primary: bool,
     /// True, if the link is revoked
     pub is_revoked: bool,
+    /// Invite link name
+    pub name: Option<String>,
     /// Point in time (Unix timestamp) when the link will expire or has been expired
     pub expire_date: Option<Integer>,
     /// Maximum number of users that can be members of the chat simultaneously after joining the chat via this invite link; 1-99999
     pub member_limit: Option<Integer>,
+    /// Number of pending join requests created using this link
+    pub pending_join_request_count: Option<Integer>,
 }
@@ -1,89 +1,148 @@
-use std::fmt;
-
-use serde::de;
-use serde::de::{Deserialize, Deserializer, Visitor};
-
 use crate::types::*;
 
-/// The member's status in the chat
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
-pub enum ChatMemberStatus {
-    Creator,
-    Administrator,
-    Member,
-    Left,
-    Kicked,
-    #[doc(hidden)]
-    Unknown(String),
+/// This object contains information about one member of the chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct ChatMember {
+    /// Information about the user.
+    pub user: User,
+    /// The member's status in the chat together with the information specific to it.
+    #[serde(flatten)]
+    pub kind: ChatMemberKind,
 }
 
-impl<'de> Deserialize<'de> for ChatMemberStatus {
-    fn deserialize<D>(deserializer: D) -> Result<ChatMemberStatus, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        struct ChatMemberStatusVisitor;
-        use self::ChatMemberStatus::*;
-
-        impl<'de> Visitor<'de> for ChatMemberStatusVisitor {
-            type Value = ChatMemberStatus;
-
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("creator | administrator | member | left | kicked")
-            }
+impl ChatMember {
+    /// True, if the member is the creator or an administrator of the chat.
+    pub fn is_admin(&self) -> bool {
+        matches!(
+            self.kind,
+            ChatMemberKind::Creator { .. } | ChatMemberKind::Administrator(_)
+        )
+    }
 
-            fn visit_str<E>(self, value: &str) -> Result<ChatMemberStatus, E>
-            where
-                E: de::Error,
-            {
-                Ok(match value {
-                    "creator" => Creator,
-                    "administrator" => Administrator,
-                    "member" => Member,
-                    "left" => Left,
-                    "kicked" => Kicked,
-                    _unknown => Unknown(value.to_string()),
-                })
-            }
+    /// True, if the user is currently a member of the chat.
+    pub fn is_member(&self) -> bool {
+        match self.kind {
+            ChatMemberKind::Creator { .. }
+            | ChatMemberKind::Administrator(_)
+            | ChatMemberKind::Member => true,
+            ChatMemberKind::Restricted(ref restricted) => restricted.is_member,
+            _ => false,
         }
-
-        deserializer.deserialize_str(ChatMemberStatusVisitor)
     }
 }
 
-/// This object contains information about one member of the chat.
+/// The member's status in the chat.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
-pub struct ChatMember {
-    /// Information about the user.
-    pub user: User,
-    /// The member's status in the chat.
-    pub status: ChatMemberStatus,
-    ///Optional. Restricted and kicked only. Date when restrictions will be lifted for this user, unix time
-    pub until_date: Option<Integer>,
-    ///Optional. Administrators only. True, if the bot is allowed to edit administrator privileges of that user
-    pub can_be_edited: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can change the chat title, photo and other settings
-    pub can_change_info: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can post in the channel, channels only
+#[serde(tag = "status")]
+pub enum ChatMemberKind {
+    /// The member is the owner of the chat.
+    #[serde(rename = "creator")]
+    Creator {
+        /// Custom title for this user.
+        custom_title: Option<String>,
+        /// True, if the user's presence in the chat is hidden.
+        #[serde(default)]
+        is_anonymous: bool,
+    },
+    /// The member has some additional privileges.
+    #[serde(rename = "administrator")]
+    Administrator(ChatMemberAdministrator),
+    /// The member has no additional privileges or restrictions.
+    #[serde(rename = "member")]
+    Member,
+    /// The member is under certain restrictions in the chat. Supergroups only.
+    #[serde(rename = "restricted")]
+    Restricted(ChatMemberRestricted),
+    /// The user isn't currently a member of the chat, but may join it themselves.
+    #[serde(rename = "left")]
+    Left,
+    /// The user was banned in the chat and can't return to the chat or view chat messages.
+    #[serde(rename = "kicked")]
+    Kicked {
+        /// Date when restrictions will be lifted for this user, unix time.
+        /// If 0, then the user is banned forever.
+        #[serde(default)]
+        until_date: Integer,
+    },
+    #[doc(hidden)]
+    #[serde(other)]
+    Unknown,
+}
+
+/// Privileges of a chat member that is an administrator.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct ChatMemberAdministrator {
+    /// True, if the bot is allowed to edit administrator privileges of that user.
+    #[serde(default)]
+    pub can_be_edited: bool,
+    /// True, if the user's presence in the chat is hidden.
+    #[serde(default)]
+    pub is_anonymous: bool,
+    /// True, if the administrator can access the chat event log, chat statistics,
+    /// message statistics in channels, see channel members, see anonymous administrators
+    /// in supergroups and ignore slow mode.
+    #[serde(default)]
+    pub can_manage_chat: bool,
+    /// True, if the administrator can delete messages of other users.
+    #[serde(default)]
+    pub can_delete_messages: bool,
+    /// True, if the administrator can restrict, ban or unban chat members.
+    #[serde(default)]
+    pub can_restrict_members: bool,
+    /// True, if the administrator can add new administrators with a subset of their own
+    /// privileges or demote administrators that they have promoted, directly or indirectly.
+    #[serde(default)]
+    pub can_promote_members: bool,
+    /// True, if the user is allowed to change the chat title, photo and other settings.
+    #[serde(default)]
+    pub can_change_info: bool,
+    /// True, if the user is allowed to invite new users to the chat.
+    #[serde(default)]
+    pub can_invite_users: bool,
+    /// True, if the administrator can post in the channel; channels only.
     pub can_post_messages: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can edit messages of other users and can pin messages, channels only
+    /// True, if the administrator can edit messages of other users and can pin messages;
+    /// channels only.
     pub can_edit_messages: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can delete messages of other users
-    pub can_delete_messages: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can invite new users to the chat
-    pub can_invite_users: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can restrict, ban or unban chat members
-    pub can_restrict_members: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can pin messages, supergroups only
+    /// True, if the user is allowed to pin messages; groups and supergroups only.
     pub can_pin_messages: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can add new administrators with a subset of his own privileges or demote administrators that he has promoted, directly or indirectly (promoted by administrators that were appointed by the user)
-    pub can_promote_members: Option<bool>,
-    ///Optional. Restricted only. True, if the user can send text messages, contacts, locations and venues
-    pub can_send_messages: Option<bool>,
-    ///Optional. Restricted only. True, if the user can send audios, documents, photos, videos, video notes and voice notes, implies can_send_messages
-    pub can_send_media_messages: Option<bool>,
-    ///Optional. Restricted only. True, if the user can send animations, games, stickers and use inline bots, implies can_send_media_messages
-    pub can_send_other_messages: Option<bool>,
-    ///Optional. Restricted only. True, if user may add web page previews to his messages, implies can_send_media_messages
-    pub can_add_web_page_previews: Option<bool>,
+    /// Custom title for this user.
+    pub custom_title: Option<String>,
+}
+
+/// Restrictions of a chat member that is restricted.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct ChatMemberRestricted {
+    /// True, if the user is a member of the chat at the moment of the request.
+    #[serde(default)]
+    pub is_member: bool,
+    /// True, if the user is allowed to send text messages, contacts, locations and venues.
+    #[serde(default)]
+    pub can_send_messages: bool,
+    /// True, if the user is allowed to send audios, documents, photos, videos, video notes
+    /// and voice notes.
+    #[serde(default)]
+    pub can_send_media_messages: bool,
+    /// True, if the user is allowed to send polls.
+    #[serde(default)]
+    pub can_send_polls: bool,
+    /// True, if the user is allowed to send animations, games, stickers and use inline bots.
+    #[serde(default)]
+    pub can_send_other_messages: bool,
+    /// True, if the user is allowed to add web page previews to their messages.
+    #[serde(default)]
+    pub can_add_web_page_previews: bool,
+    /// True, if the user is allowed to change the chat title, photo and other settings.
+    #[serde(default)]
+    pub can_change_info: bool,
+    /// True, if the user is allowed to invite new users to the chat.
+    #[serde(default)]
+    pub can_invite_users: bool,
+    /// True, if the user is allowed to pin messages.
+    #[serde(default)]
+    pub can_pin_messages: bool,
+    /// Date when restrictions will be lifted for this user, unix time.
+    /// If 0, then the user is restricted forever.
+    #[serde(default)]
+    pub until_date: Integer,
 }
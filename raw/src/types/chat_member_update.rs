@@ -16,3 +16,45 @@ pub struct ChatMemberUpdate {
     /// Chat invite link, which was used by the user to join the chat; for joining by invite link events only.
     pub invite_link: Option<ChatInviteLink>,
 }
+
+impl ChatMemberUpdate {
+    /// Previous status of the chat member.
+    pub fn old_status(&self) -> &ChatMemberKind {
+        &self.old_chat_member.kind
+    }
+
+    /// New status of the chat member.
+    pub fn new_status(&self) -> &ChatMemberKind {
+        &self.new_chat_member.kind
+    }
+
+    /// Summarizes the transition from the old to the new status, e.g. to detect
+    /// that the bot was added to or removed from a chat.
+    pub fn status_change(&self) -> ChatMemberStatusChange {
+        let (old, new) = (&self.old_chat_member, &self.new_chat_member);
+        match (old.is_member(), new.is_member()) {
+            (false, true) => ChatMemberStatusChange::Joined,
+            (true, false) => ChatMemberStatusChange::Left,
+            _ => match (old.is_admin(), new.is_admin()) {
+                (false, true) => ChatMemberStatusChange::Promoted,
+                (true, false) => ChatMemberStatusChange::Demoted,
+                _ => ChatMemberStatusChange::Other,
+            },
+        }
+    }
+}
+
+/// Transition between two statuses of a chat member.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub enum ChatMemberStatusChange {
+    /// The user became a member of the chat.
+    Joined,
+    /// The user left the chat or was banned from it.
+    Left,
+    /// The member became an administrator or the creator of the chat.
+    Promoted,
+    /// The member lost its administrator privileges but stayed in the chat.
+    Demoted,
+    /// Any other change, e.g. of administrator rights or restrictions.
+    Other,
+}
@@ -0,0 +1,14 @@
+use crate::types::*;
+
+/// This object represents a forum topic.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct ForumTopic {
+    /// Unique identifier of the forum topic.
+    pub message_thread_id: Integer,
+    /// Name of the topic.
+    pub name: String,
+    /// Color of the topic icon in RGB format.
+    pub icon_color: Integer,
+    /// Unique identifier of the custom emoji shown as the topic icon.
+    pub icon_custom_emoji_id: Option<String>,
+}
@@ -0,0 +1,87 @@
+use crate::types::*;
+
+/// This object represents a message about a scheduled giveaway.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct Giveaway {
+    /// The list of chats which the user must join to participate in the giveaway.
+    pub chats: Vec<Chat>,
+    /// Point in time (Unix timestamp) when winners of the giveaway will be selected.
+    pub winners_selection_date: Integer,
+    /// The number of users which are supposed to be selected as winners of the giveaway.
+    pub winner_count: Integer,
+    /// True, if only users who join the chats after the giveaway started
+    /// should be eligible to win.
+    #[serde(default)]
+    pub only_new_members: bool,
+    /// True, if the list of giveaway winners will be visible to everyone.
+    #[serde(default)]
+    pub has_public_winners: bool,
+    /// Description of additional giveaway prize.
+    pub prize_description: Option<String>,
+    /// A list of two-letter ISO 3166-1 alpha-2 country codes indicating the countries
+    /// from which eligible users for the giveaway must come.
+    pub country_codes: Option<Vec<String>>,
+    /// The number of Telegram Stars to be split between giveaway winners;
+    /// for Telegram Star giveaways only.
+    pub prize_star_count: Option<Integer>,
+    /// The number of months the Telegram Premium subscription won from the giveaway
+    /// will be active for; for Telegram Premium giveaways only.
+    pub premium_subscription_month_count: Option<Integer>,
+}
+
+/// This object represents a service message about the creation of a scheduled giveaway.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct GiveawayCreated {
+    /// The number of Telegram Stars to be split between giveaway winners;
+    /// for Telegram Star giveaways only.
+    pub prize_star_count: Option<Integer>,
+}
+
+/// This object represents a message about the completion of a giveaway with public winners.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct GiveawayWinners {
+    /// The chat that created the giveaway.
+    pub chat: Chat,
+    /// Identifier of the message with the giveaway in the chat.
+    pub giveaway_message_id: MessageId,
+    /// Point in time (Unix timestamp) when winners of the giveaway were selected.
+    pub winners_selection_date: Integer,
+    /// Total number of winners in the giveaway.
+    pub winner_count: Integer,
+    /// List of up to 100 winners of the giveaway.
+    pub winners: Vec<User>,
+    /// The number of other chats the user had to join in order to be eligible for the giveaway.
+    pub additional_chat_count: Option<Integer>,
+    /// The number of Telegram Stars that were split between giveaway winners;
+    /// for Telegram Star giveaways only.
+    pub prize_star_count: Option<Integer>,
+    /// The number of months the Telegram Premium subscription won from the giveaway
+    /// will be active for; for Telegram Premium giveaways only.
+    pub premium_subscription_month_count: Option<Integer>,
+    /// Number of undistributed prizes.
+    pub unclaimed_prize_count: Option<Integer>,
+    /// True, if only users who had joined the chats after the giveaway started
+    /// were eligible to win.
+    #[serde(default)]
+    pub only_new_members: bool,
+    /// True, if the giveaway was canceled because the payment for it was refunded.
+    #[serde(default)]
+    pub was_refunded: bool,
+    /// Description of additional giveaway prize.
+    pub prize_description: Option<String>,
+}
+
+/// This object represents a service message about the completion of a giveaway
+/// without public winners.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct GiveawayCompleted {
+    /// Number of winners in the giveaway.
+    pub winner_count: Integer,
+    /// Number of undistributed prizes.
+    pub unclaimed_prize_count: Option<Integer>,
+    /// Message with the giveaway that was completed, if it wasn't deleted.
+    pub giveaway_message: Option<Box<MessageOrChannelPost>>,
+    /// True, if the giveaway is a Telegram Star giveaway.
+    #[serde(default)]
+    pub is_star_giveaway: bool,
+}
@@ -721,7 +721,150 @@ impl InlineQueryResultArticle {
     }
 }
 
-// TODO: builders for InlineQueryResult types
+impl InlineQueryResultPhoto {
+    pub fn new<T: Into<String>, U: Into<String>, V: Into<String>>(
+        id: T,
+        photo_url: U,
+        thumb_url: V,
+    ) -> InlineQueryResultPhoto {
+        InlineQueryResultPhoto {
+            id: id.into(),
+            photo_url: photo_url.into(),
+            thumb_url: thumb_url.into(),
+            photo_width: None,
+            photo_height: None,
+            title: None,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn photo_width<T: Into<Integer>>(&mut self, photo_width: T) -> &mut Self {
+        self.photo_width = Some(photo_width.into());
+        self
+    }
+
+    pub fn photo_height<T: Into<Integer>>(&mut self, photo_height: T) -> &mut Self {
+        self.photo_height = Some(photo_height.into());
+        self
+    }
+
+    pub fn title<T: Into<String>>(&mut self, title: T) -> &mut Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description<T: Into<String>>(&mut self, description: T) -> &mut Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedPhoto {
+    pub fn new<T: Into<String>, U: Into<String>>(
+        id: T,
+        photo_file_id: U,
+    ) -> InlineQueryResultCachedPhoto {
+        InlineQueryResultCachedPhoto {
+            id: id.into(),
+            photo_file_id: photo_file_id.into(),
+            title: None,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn title<T: Into<String>>(&mut self, title: T) -> &mut Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description<T: Into<String>>(&mut self, description: T) -> &mut Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+impl InlineQueryResultCachedSticker {
+    pub fn new<T: Into<String>, U: Into<String>>(
+        id: T,
+        sticker_file_id: U,
+    ) -> InlineQueryResultCachedSticker {
+        InlineQueryResultCachedSticker {
+            id: id.into(),
+            sticker_file_id: sticker_file_id.into(),
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    pub fn reply_markup<T: Into<InlineKeyboardMarkup>>(&mut self, reply_markup: T) -> &mut Self {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    pub fn input_message_content<T: Into<InputMessageContent>>(
+        &mut self,
+        input_message_content: T,
+    ) -> &mut Self {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+// TODO: builders for the remaining InlineQueryResult types
 
 impl From<InlineQueryResultCachedAudio> for InlineQueryResult {
     fn from(audio: InlineQueryResultCachedAudio) -> Self {
@@ -846,9 +989,9 @@ pub struct InputTextMessageContent {
     /// Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
-    /// Disables link previews for links in the sent message
-    #[serde(skip_serializing_if = "Not::not")]
-    pub disable_web_page_preview: bool,
+    /// Link preview generation options for the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_preview_options: Option<LinkPreviewOptions>,
 }
 
 #[derive(Serialize, Debug)]
@@ -895,6 +1038,32 @@ pub struct InputContactMessageContent {
     pub vcard: Option<String>,
 }
 
+impl InputTextMessageContent {
+    pub fn new<T: Into<String>>(message_text: T) -> InputTextMessageContent {
+        InputTextMessageContent {
+            message_text: message_text.into(),
+            parse_mode: None,
+            link_preview_options: None,
+        }
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Disable the link preview, a shorthand for `LinkPreviewOptions::disabled`.
+    pub fn disable_preview(&mut self) -> &mut Self {
+        self.link_preview_options = Some(LinkPreviewOptions::disabled());
+        self
+    }
+
+    pub fn link_preview_options(&mut self, options: LinkPreviewOptions) -> &mut Self {
+        self.link_preview_options = Some(options);
+        self
+    }
+}
+
 impl From<InputTextMessageContent> for InputMessageContent {
     fn from(value: InputTextMessageContent) -> Self {
         InputMessageContent::InputTextMessageContent(value)
@@ -3,12 +3,16 @@ use bytes::Bytes;
 use crate::requests::*;
 use crate::types::*;
 
+/// File to send: either a reference to a file known to Telegram or a new upload.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct InputFile(InputFileImpl);
 
+/// Reference to a file that is already stored on the Telegram servers (`file_id`),
+/// or an HTTP URL for Telegram to get the file from the Internet.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct InputFileRef(Text);
 
+/// New file to upload using multipart/form-data, read from disk or from memory.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct InputFileUpload(InputFileUploadImpl);
 
@@ -25,6 +29,28 @@ enum InputFileUploadImpl {
     Data { file_name: Text, data: Bytes },
 }
 
+impl InputFile {
+    /// Resend a file which is already stored on the Telegram servers.
+    pub fn file_id(file_id: impl Into<Text>) -> Self {
+        InputFileRef::new(file_id).into()
+    }
+
+    /// Let Telegram download the file from the given HTTP URL.
+    pub fn url(url: impl Into<Text>) -> Self {
+        InputFileRef::new(url).into()
+    }
+
+    /// Upload a file from disk, the file name is taken from the path.
+    pub fn path(path: impl Into<Text>) -> Self {
+        InputFileUpload::with_path(path).into()
+    }
+
+    /// Upload a file from memory.
+    pub fn data(data: impl Into<Bytes>, file_name: impl Into<Text>) -> Self {
+        InputFileUpload::with_data(data, file_name).into()
+    }
+}
+
 impl InputFileRef {
     pub fn new(r: impl Into<Text>) -> Self {
         InputFileRef(r.into())
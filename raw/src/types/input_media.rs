@@ -0,0 +1,254 @@
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Names of the multipart fields used for files attached with `attach://<name>`.
+const ATTACH_NAMES: [&str; 20] = [
+    "file0", "file1", "file2", "file3", "file4", "file5", "file6", "file7", "file8", "file9",
+    "file10", "file11", "file12", "file13", "file14", "file15", "file16", "file17", "file18",
+    "file19",
+];
+
+/// This object represents the content of a media message to be sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum InputMedia {
+    /// Represents a photo to be sent.
+    Photo(InputMediaPhoto),
+    /// Represents a video to be sent.
+    Video(InputMediaVideo),
+}
+
+/// Represents a photo to be sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct InputMediaPhoto {
+    media: InputFile,
+    caption: Option<String>,
+    parse_mode: Option<ParseMode>,
+    caption_entities: Option<Vec<MessageEntity>>,
+}
+
+impl InputMediaPhoto {
+    pub fn new<V>(media: V) -> Self
+    where
+        V: Into<InputFile>,
+    {
+        Self {
+            media: media.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+        }
+    }
+
+    /// Caption of the photo to be sent, 0-1024 characters.
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Entities of the caption, which can be specified instead of `parse_mode`.
+    pub fn caption_entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(entities);
+        self
+    }
+}
+
+/// Represents a video to be sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct InputMediaVideo {
+    media: InputFile,
+    thumb: Option<InputFile>,
+    caption: Option<String>,
+    parse_mode: Option<ParseMode>,
+    caption_entities: Option<Vec<MessageEntity>>,
+    width: Option<Integer>,
+    height: Option<Integer>,
+    duration: Option<Integer>,
+    supports_streaming: bool,
+}
+
+impl InputMediaVideo {
+    pub fn new<V>(media: V) -> Self
+    where
+        V: Into<InputFile>,
+    {
+        Self {
+            media: media.into(),
+            thumb: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            width: None,
+            height: None,
+            duration: None,
+            supports_streaming: false,
+        }
+    }
+
+    /// Thumbnail of the video. Thumbnails can't be reused and can be only uploaded as a new file.
+    pub fn thumb<V>(&mut self, thumb: V) -> &mut Self
+    where
+        V: Into<InputFileUpload>,
+    {
+        self.thumb = Some(thumb.into().into());
+        self
+    }
+
+    /// Caption of the video to be sent, 0-1024 characters.
+    pub fn caption<T: Into<String>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Entities of the caption, which can be specified instead of `parse_mode`.
+    pub fn caption_entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.caption_entities = Some(entities);
+        self
+    }
+
+    pub fn width(&mut self, width: Integer) -> &mut Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(&mut self, height: Integer) -> &mut Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn duration(&mut self, duration: Integer) -> &mut Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Pass True, if the uploaded video is suitable for streaming.
+    pub fn supports_streaming(&mut self) -> &mut Self {
+        self.supports_streaming = true;
+        self
+    }
+}
+
+impl From<InputMediaPhoto> for InputMedia {
+    fn from(value: InputMediaPhoto) -> Self {
+        InputMedia::Photo(value)
+    }
+}
+
+impl<'a> From<&'a mut InputMediaPhoto> for InputMedia {
+    fn from(value: &'a mut InputMediaPhoto) -> Self {
+        InputMedia::Photo(value.clone())
+    }
+}
+
+impl From<InputMediaVideo> for InputMedia {
+    fn from(value: InputMediaVideo) -> Self {
+        InputMedia::Video(value)
+    }
+}
+
+impl<'a> From<&'a mut InputMediaVideo> for InputMedia {
+    fn from(value: &'a mut InputMediaVideo) -> Self {
+        InputMedia::Video(value.clone())
+    }
+}
+
+/// `InputMedia` as it is sent to Telegram, with uploads replaced by `attach://<name>`.
+#[derive(Serialize)]
+struct RawInputMedia<'a> {
+    #[serde(rename = "type")]
+    type_: &'static str,
+    media: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumbnail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption_entities: Option<&'a [MessageEntity]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<Integer>,
+    #[serde(skip_serializing_if = "Not::not")]
+    supports_streaming: bool,
+}
+
+/// Returns the value for a media field, adding the file to `multipart` if it has to be uploaded.
+pub(crate) fn attach(file: &InputFile, multipart: &mut Multipart) -> Result<String, Error> {
+    match file.to_multipart_value() {
+        MultipartValue::Text(file_ref) => Ok(file_ref.as_str().to_string()),
+        upload => {
+            let name = ATTACH_NAMES
+                .get(multipart.len())
+                .ok_or(ErrorKind::TooManyAttachments)?;
+            multipart.push((name, upload));
+            Ok(format!("attach://{}", name))
+        }
+    }
+}
+
+impl InputMedia {
+    /// Serializes a list of media to JSON, collecting files to upload in `attachments`.
+    pub(crate) fn list_to_json(
+        media: &[InputMedia],
+        attachments: &mut Multipart,
+    ) -> Result<String, Error> {
+        let raw = media
+            .iter()
+            .map(|media| media.to_raw(attachments))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(serde_json::to_string(&raw).map_err(ErrorKind::from)?)
+    }
+
+    /// Serializes a single media to JSON, collecting files to upload in `attachments`.
+    pub(crate) fn to_json(&self, attachments: &mut Multipart) -> Result<String, Error> {
+        let raw = self.to_raw(attachments)?;
+        Ok(serde_json::to_string(&raw).map_err(ErrorKind::from)?)
+    }
+
+    fn to_raw(&self, attachments: &mut Multipart) -> Result<RawInputMedia<'_>, Error> {
+        Ok(match self {
+            InputMedia::Photo(photo) => RawInputMedia {
+                type_: "photo",
+                media: attach(&photo.media, attachments)?,
+                thumbnail: None,
+                caption: photo.caption.as_deref(),
+                parse_mode: photo.parse_mode,
+                caption_entities: photo.caption_entities.as_deref(),
+                width: None,
+                height: None,
+                duration: None,
+                supports_streaming: false,
+            },
+            InputMedia::Video(video) => RawInputMedia {
+                type_: "video",
+                media: attach(&video.media, attachments)?,
+                thumbnail: match &video.thumb {
+                    Some(thumb) => Some(attach(thumb, attachments)?),
+                    None => None,
+                },
+                caption: video.caption.as_deref(),
+                parse_mode: video.parse_mode,
+                caption_entities: video.caption_entities.as_deref(),
+                width: video.width,
+                height: video.height,
+                duration: video.duration,
+                supports_streaming: video.supports_streaming,
+            },
+        })
+    }
+}
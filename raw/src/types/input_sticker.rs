@@ -0,0 +1,96 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// This object describes a sticker to be added to a sticker set.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct InputSticker {
+    sticker: InputFile,
+    format: StickerFormat,
+    emoji_list: Vec<String>,
+    mask_position: Option<MaskPosition>,
+    keywords: Vec<String>,
+}
+
+impl InputSticker {
+    /// Animated and video stickers can't be uploaded via HTTP URL.
+    pub fn new<V, E>(sticker: V, format: StickerFormat, emoji: E) -> Self
+    where
+        V: Into<InputFile>,
+        E: Into<String>,
+    {
+        Self {
+            sticker: sticker.into(),
+            format,
+            emoji_list: vec![emoji.into()],
+            mask_position: None,
+            keywords: Vec::new(),
+        }
+    }
+
+    /// Add an emoji associated with the sticker, 1-20 emoji in total.
+    pub fn add_emoji<E: Into<String>>(&mut self, emoji: E) -> &mut Self {
+        self.emoji_list.push(emoji.into());
+        self
+    }
+
+    /// Position where the mask should be placed on faces. For “mask” stickers only.
+    pub fn mask_position(&mut self, mask_position: MaskPosition) -> &mut Self {
+        self.mask_position = Some(mask_position);
+        self
+    }
+
+    /// Add a search keyword for the sticker, 0-20 keywords in total.
+    /// For “regular” and “custom_emoji” stickers only.
+    pub fn add_keyword<K: Into<String>>(&mut self, keyword: K) -> &mut Self {
+        self.keywords.push(keyword.into());
+        self
+    }
+}
+
+impl<'a> From<&'a mut InputSticker> for InputSticker {
+    fn from(value: &'a mut InputSticker) -> Self {
+        value.clone()
+    }
+}
+
+/// `InputSticker` as it is sent to Telegram, with uploads replaced by `attach://<name>`.
+#[derive(Serialize)]
+struct RawInputSticker<'a> {
+    sticker: String,
+    format: StickerFormat,
+    emoji_list: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mask_position: Option<&'a MaskPosition>,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    keywords: &'a [String],
+}
+
+impl InputSticker {
+    /// Serializes a list of stickers to JSON, collecting files to upload in `attachments`.
+    pub(crate) fn list_to_json(
+        stickers: &[InputSticker],
+        attachments: &mut Multipart,
+    ) -> Result<String, Error> {
+        let raw = stickers
+            .iter()
+            .map(|sticker| sticker.to_raw(attachments))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(serde_json::to_string(&raw).map_err(ErrorKind::from)?)
+    }
+
+    /// Serializes a single sticker to JSON, collecting files to upload in `attachments`.
+    pub(crate) fn to_json(&self, attachments: &mut Multipart) -> Result<String, Error> {
+        let raw = self.to_raw(attachments)?;
+        Ok(serde_json::to_string(&raw).map_err(ErrorKind::from)?)
+    }
+
+    fn to_raw(&self, attachments: &mut Multipart) -> Result<RawInputSticker<'_>, Error> {
+        Ok(RawInputSticker {
+            sticker: attach(&self.sticker, attachments)?,
+            format: self.format,
+            emoji_list: &self.emoji_list,
+            mask_position: self.mask_position.as_ref(),
+            keywords: &self.keywords,
+        })
+    }
+}
@@ -0,0 +1,57 @@
+use std::ops::Not;
+
+/// Options used for link preview generation.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Eq, Hash, Serialize, Deserialize)]
+pub struct LinkPreviewOptions {
+    /// True, if the link preview is disabled.
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub is_disabled: bool,
+    /// URL to use for the link preview. If empty, then the first URL found in the message
+    /// text will be used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// True, if the media in the link preview is supposed to be shrunk.
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub prefer_small_media: bool,
+    /// True, if the media in the link preview is supposed to be enlarged.
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub prefer_large_media: bool,
+    /// True, if the link preview must be shown above the message text.
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub show_above_text: bool,
+}
+
+impl LinkPreviewOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Options that disable the link preview.
+    pub fn disabled() -> Self {
+        LinkPreviewOptions {
+            is_disabled: true,
+            ..Self::default()
+        }
+    }
+
+    /// Show the preview of the given URL instead of the first one in the text.
+    pub fn url<U: Into<String>>(&mut self, url: U) -> &mut Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn prefer_small_media(&mut self) -> &mut Self {
+        self.prefer_small_media = true;
+        self
+    }
+
+    pub fn prefer_large_media(&mut self) -> &mut Self {
+        self.prefer_large_media = true;
+        self
+    }
+
+    pub fn show_above_text(&mut self) -> &mut Self {
+        self.show_above_text = true;
+        self
+    }
+}
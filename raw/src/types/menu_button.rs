@@ -0,0 +1,37 @@
+use crate::types::*;
+
+/// This object describes the bot's menu button in a private chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum MenuButton {
+    /// Opens the bot's list of commands.
+    #[serde(rename = "commands")]
+    Commands,
+    /// Launches a Web App.
+    #[serde(rename = "web_app")]
+    WebApp {
+        /// Text on the button.
+        text: String,
+        /// Description of the Web App that will be launched when the user presses the button.
+        web_app: WebAppInfo,
+    },
+    /// No specific value for the menu button was set.
+    #[serde(rename = "default")]
+    Default,
+    #[doc(hidden)]
+    #[serde(other)]
+    Unknown,
+}
+
+impl MenuButton {
+    pub fn web_app<T, W>(text: T, web_app: W) -> Self
+    where
+        T: Into<String>,
+        W: Into<WebAppInfo>,
+    {
+        MenuButton::WebApp {
+            text: text.into(),
+            web_app: web_app.into(),
+        }
+    }
+}
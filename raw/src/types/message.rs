@@ -1,7 +1,8 @@
 use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
 
+use crate::requests::RequestUrl;
 use crate::types::*;
-use crate::url::*;
 
 /// This object represents a chat message or a channel post.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -10,13 +11,88 @@ pub enum MessageOrChannelPost {
     ChannelPost(ChannelPost),
 }
 
+impl MessageOrChannelPost {
+    /// Unique message identifier inside the chat.
+    pub fn id(&self) -> MessageId {
+        match self {
+            MessageOrChannelPost::Message(message) => message.id,
+            MessageOrChannelPost::ChannelPost(post) => post.id,
+        }
+    }
+
+    /// Date the message was sent in Unix time.
+    pub fn date(&self) -> Integer {
+        match self {
+            MessageOrChannelPost::Message(message) => message.date,
+            MessageOrChannelPost::ChannelPost(post) => post.date,
+        }
+    }
+
+    /// Date the message was last edited in Unix time.
+    pub fn edit_date(&self) -> Option<Integer> {
+        match self {
+            MessageOrChannelPost::Message(message) => message.edit_date,
+            MessageOrChannelPost::ChannelPost(post) => post.edit_date,
+        }
+    }
+
+    /// Sender of the message, channel posts have none.
+    pub fn from(&self) -> Option<&User> {
+        match self {
+            MessageOrChannelPost::Message(message) => Some(&message.from),
+            MessageOrChannelPost::ChannelPost(_) => None,
+        }
+    }
+
+    /// Information about the original message.
+    pub fn forward(&self) -> Option<&Forward> {
+        match self {
+            MessageOrChannelPost::Message(message) => message.forward.as_ref(),
+            MessageOrChannelPost::ChannelPost(post) => post.forward.as_ref(),
+        }
+    }
+
+    /// Kind of the message.
+    pub fn kind(&self) -> &MessageKind {
+        match self {
+            MessageOrChannelPost::Message(message) => &message.kind,
+            MessageOrChannelPost::ChannelPost(post) => &post.kind,
+        }
+    }
+}
+
+impl From<Message> for MessageOrChannelPost {
+    fn from(message: Message) -> Self {
+        MessageOrChannelPost::Message(message)
+    }
+}
+
+impl From<ChannelPost> for MessageOrChannelPost {
+    fn from(post: ChannelPost) -> Self {
+        MessageOrChannelPost::ChannelPost(post)
+    }
+}
+
 /// This object represents a chat message.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct Message {
     /// Unique message identifier inside this chat.
     pub id: MessageId,
+    /// Unique identifier of a message thread (forum topic) to which the message belongs;
+    /// for supergroups only.
+    pub thread_id: Option<Integer>,
+    /// True, if the message is sent to a forum topic.
+    pub is_topic_message: bool,
+    /// Unique identifier of the business connection from which the message was received.
+    /// If set, the message is a business message and replies must be sent on behalf of
+    /// the same connection.
+    pub business_connection_id: Option<String>,
     /// Sender, can be empty for messages sent to channels.
     pub from: User,
+    /// Sender of the message when it is sent on behalf of a chat, e.g. a channel posting
+    /// in its discussion group, or a group for messages of anonymous administrators.
+    /// `from` contains a placeholder user for such messages.
+    pub sender_chat: Option<Chat>,
     /// Date the message was sent in Unix time.
     pub date: Integer,
     /// Conversation the message belongs to.
@@ -103,6 +179,13 @@ pub enum MessageKind {
         /// Information about the file.
         data: Audio,
     },
+    /// Message is an animation (GIF or H.264/MPEG-4 AVC video without sound).
+    Animation {
+        /// Information about the animation.
+        data: Animation,
+        /// Caption for the animation, 0-1024 characters.
+        caption: Option<String>,
+    },
     /// Message is a general file.
     Document {
         /// Information about the file.
@@ -163,6 +246,88 @@ pub enum MessageKind {
         /// Information about the venue.
         data: Venue,
     },
+    /// Message is a dice with random value.
+    Dice {
+        /// Information about the dice.
+        data: Dice,
+    },
+    /// Message is a game.
+    Game {
+        /// Information about the game.
+        data: Game,
+    },
+    /// Message is an invoice for a payment.
+    Invoice {
+        /// Information about the invoice.
+        data: Invoice,
+    },
+    /// Message is a service message about a successful payment.
+    SuccessfulPayment {
+        /// Information about the payment.
+        data: SuccessfulPayment,
+    },
+    /// Message is a service message about a refunded payment.
+    RefundedPayment {
+        /// Information about the payment.
+        data: RefundedPayment,
+    },
+    /// Service message: data sent by a Web App.
+    WebAppData {
+        /// Information about the data.
+        data: WebAppData,
+    },
+    /// Service message: video chat scheduled.
+    VideoChatScheduled {
+        /// Information about the scheduled video chat.
+        data: VideoChatScheduled,
+    },
+    /// Service message: video chat started.
+    VideoChatStarted {
+        /// Information about the video chat.
+        data: VideoChatStarted,
+    },
+    /// Service message: video chat ended.
+    VideoChatEnded {
+        /// Information about the ended video chat.
+        data: VideoChatEnded,
+    },
+    /// Service message: new participants invited to a video chat.
+    VideoChatParticipantsInvited {
+        /// Information about the invited participants.
+        data: VideoChatParticipantsInvited,
+    },
+    /// Message contains paid media.
+    PaidMedia {
+        /// Information about the paid media.
+        data: PaidMediaInfo,
+        /// Caption for the paid media, 0-1024 characters.
+        caption: Option<String>,
+    },
+    /// Message is a scheduled giveaway.
+    Giveaway {
+        /// Information about the giveaway.
+        data: Giveaway,
+    },
+    /// Service message: a scheduled giveaway was created.
+    GiveawayCreated {
+        /// Information about the created giveaway.
+        data: GiveawayCreated,
+    },
+    /// A giveaway with public winners was completed.
+    GiveawayWinners {
+        /// Information about the giveaway winners.
+        data: GiveawayWinners,
+    },
+    /// Service message: a giveaway without public winners was completed.
+    GiveawayCompleted {
+        /// Information about the completed giveaway.
+        data: GiveawayCompleted,
+    },
+    /// Telegram Passport data.
+    PassportData {
+        /// Encrypted data shared with the bot.
+        data: PassportData,
+    },
     /// New members that were added to the group or supergroup and
     /// information about them (the bot itself may be one of these members)
     NewChatMembers {
@@ -221,6 +386,10 @@ pub enum MessageKind {
 impl Message {
     fn from_raw_message(raw: RawMessage) -> Result<Self, String> {
         let id = raw.message_id;
+        let thread_id = raw.message_thread_id;
+        let is_topic_message = raw.is_topic_message.unwrap_or(false);
+        let business_connection_id = raw.business_connection_id.clone();
+        let sender_chat = raw.sender_chat.clone();
         let from = match raw.from.clone() {
             Some(from) => from,
             None => return Err(format!("Missing `from` field for Message")),
@@ -285,7 +454,11 @@ impl Message {
         let make_message = |kind| {
             Ok(Message {
                 id: id.into(),
+                thread_id,
+                is_topic_message,
+                business_connection_id,
                 from: from,
+                sender_chat,
                 date: date,
                 chat: chat,
                 forward: forward,
@@ -343,6 +516,7 @@ impl Message {
         }
 
         maybe_field!(audio, Audio);
+        maybe_field_with_caption!(animation, Animation);
         maybe_field_with_caption!(document, Document);
         maybe_field_with_caption_and_group!(photo, Photo);
         maybe_field!(sticker, Sticker);
@@ -353,6 +527,25 @@ impl Message {
         maybe_field!(location, Location);
         maybe_field!(poll, Poll);
         maybe_field!(venue, Venue);
+        maybe_field!(dice, Dice);
+        maybe_field!(game, Game);
+        maybe_field!(invoice, Invoice);
+        maybe_field!(successful_payment, SuccessfulPayment);
+        maybe_field!(refunded_payment, RefundedPayment);
+        maybe_field!(web_app_data, WebAppData);
+        maybe_field!(video_chat_scheduled, VideoChatScheduled);
+        maybe_field!(video_chat_started, VideoChatStarted);
+        maybe_field!(video_chat_ended, VideoChatEnded);
+        maybe_field!(
+            video_chat_participants_invited,
+            VideoChatParticipantsInvited
+        );
+        maybe_field_with_caption!(paid_media, PaidMedia);
+        maybe_field!(giveaway, Giveaway);
+        maybe_field!(giveaway_created, GiveawayCreated);
+        maybe_field!(giveaway_winners, GiveawayWinners);
+        maybe_field!(giveaway_completed, GiveawayCompleted);
+        maybe_field!(passport_data, PassportData);
         maybe_field!(new_chat_members, NewChatMembers);
         maybe_field!(left_chat_member, LeftChatMember);
         maybe_field!(new_chat_title, NewChatTitle);
@@ -497,6 +690,7 @@ impl ChannelPost {
         }
 
         maybe_field!(audio, Audio);
+        maybe_field_with_caption!(animation, Animation);
         maybe_field_with_caption!(document, Document);
         maybe_field_with_caption_and_group!(photo, Photo);
         maybe_field!(sticker, Sticker);
@@ -507,6 +701,25 @@ impl ChannelPost {
         maybe_field!(location, Location);
         maybe_field!(poll, Poll);
         maybe_field!(venue, Venue);
+        maybe_field!(dice, Dice);
+        maybe_field!(game, Game);
+        maybe_field!(invoice, Invoice);
+        maybe_field!(successful_payment, SuccessfulPayment);
+        maybe_field!(refunded_payment, RefundedPayment);
+        maybe_field!(web_app_data, WebAppData);
+        maybe_field!(video_chat_scheduled, VideoChatScheduled);
+        maybe_field!(video_chat_started, VideoChatStarted);
+        maybe_field!(video_chat_ended, VideoChatEnded);
+        maybe_field!(
+            video_chat_participants_invited,
+            VideoChatParticipantsInvited
+        );
+        maybe_field_with_caption!(paid_media, PaidMedia);
+        maybe_field!(giveaway, Giveaway);
+        maybe_field!(giveaway_created, GiveawayCreated);
+        maybe_field!(giveaway_winners, GiveawayWinners);
+        maybe_field!(giveaway_completed, GiveawayCompleted);
+        maybe_field!(passport_data, PassportData);
         maybe_field!(new_chat_members, NewChatMembers);
         maybe_field!(left_chat_member, LeftChatMember);
         maybe_field!(new_chat_title, NewChatTitle);
@@ -563,8 +776,16 @@ impl<'de> Deserialize<'de> for MessageOrChannelPost {
 pub struct RawMessage {
     /// Unique message identifier inside this chat.
     pub message_id: Integer,
+    /// Unique identifier of a message thread to which the message belongs; for supergroups only.
+    pub message_thread_id: Option<Integer>,
+    /// True, if the message is sent to a forum topic.
+    pub is_topic_message: Option<bool>,
+    /// Unique identifier of the business connection from which the message was received.
+    pub business_connection_id: Option<String>,
     /// Sender, can be empty for messages sent to channels.
     pub from: Option<User>,
+    /// Sender of the message when it is sent on behalf of a chat.
+    pub sender_chat: Option<Chat>,
     /// Date the message was sent in Unix time.
     pub date: Integer,
     /// Conversation the message belongs to.
@@ -593,7 +814,11 @@ pub struct RawMessage {
     pub audio: Option<Audio>,
     /// Message is a general file, information about the file.
     pub document: Option<Document>,
-    // pub game: Option<Game>,
+    /// Message is an animation, information about the animation.
+    /// For backward compatibility, when this field is set, the document field will also be set.
+    pub animation: Option<Animation>,
+    /// Message is a game, information about the game.
+    pub game: Option<Game>,
     /// Message is a photo, available sizes of the photo.
     pub photo: Option<Vec<PhotoSize>>,
     /// Message is a sticker, information about the sticker.
@@ -614,6 +839,36 @@ pub struct RawMessage {
     pub poll: Option<Poll>,
     /// Message is a venue, information about the venue.
     pub venue: Option<Venue>,
+    /// Message is a dice with random value.
+    pub dice: Option<Dice>,
+    /// Message is an invoice for a payment, information about the invoice.
+    pub invoice: Option<Invoice>,
+    /// Message is a service message about a successful payment, information about the payment.
+    pub successful_payment: Option<SuccessfulPayment>,
+    /// Message is a service message about a refunded payment, information about the payment.
+    pub refunded_payment: Option<RefundedPayment>,
+    /// Service message: data sent by a Web App.
+    pub web_app_data: Option<WebAppData>,
+    /// Service message: video chat scheduled.
+    pub video_chat_scheduled: Option<VideoChatScheduled>,
+    /// Service message: video chat started.
+    pub video_chat_started: Option<VideoChatStarted>,
+    /// Service message: video chat ended.
+    pub video_chat_ended: Option<VideoChatEnded>,
+    /// Service message: new participants invited to a video chat.
+    pub video_chat_participants_invited: Option<VideoChatParticipantsInvited>,
+    /// Message contains paid media; information about the paid media.
+    pub paid_media: Option<PaidMediaInfo>,
+    /// The message is a scheduled giveaway message.
+    pub giveaway: Option<Giveaway>,
+    /// Service message: a scheduled giveaway was created.
+    pub giveaway_created: Option<GiveawayCreated>,
+    /// A giveaway with public winners was completed.
+    pub giveaway_winners: Option<GiveawayWinners>,
+    /// Service message: a giveaway without public winners was completed.
+    pub giveaway_completed: Option<GiveawayCompleted>,
+    /// Telegram Passport data.
+    pub passport_data: Option<PassportData>,
     /// New members that were added to the group or supergroup and information
     /// about them (the bot itself may be one of these members)
     pub new_chat_members: Option<Vec<User>>,
@@ -661,6 +916,42 @@ pub struct MessageEntity {
     pub kind: MessageEntityKind,
 }
 
+impl MessageEntity {
+    pub fn new(offset: Integer, length: Integer, kind: MessageEntityKind) -> Self {
+        MessageEntity {
+            offset,
+            length,
+            kind,
+        }
+    }
+
+    /// The part of `text` the entity refers to, or `None` if the entity doesn't fit the
+    /// text. Offsets and lengths of entities are counted in UTF-16 code units, so they
+    /// can't be used to index a `str` directly.
+    pub fn text_in<'a>(&self, text: &'a str) -> Option<&'a str> {
+        if self.offset < 0 || self.length < 0 {
+            return None;
+        }
+        let start = self.offset as usize;
+        let end = start + self.length as usize;
+
+        let mut units = 0;
+        let mut start_byte = None;
+        let mut end_byte = None;
+        for (index, c) in text.char_indices().chain(Some((text.len(), '\0'))) {
+            if units == start {
+                start_byte = Some(index);
+            }
+            if units == end {
+                end_byte = Some(index);
+                break;
+            }
+            units += c.len_utf16();
+        }
+        text.get(start_byte?..end_byte?)
+    }
+}
+
 /// Kind of the entity.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum MessageEntityKind {
@@ -671,10 +962,17 @@ pub enum MessageEntityKind {
     Email,
     Bold,
     Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
     Code,
     Pre,
+    Blockquote,
     TextLink(String), // TODO(knsd) URL?
     TextMention(User),
+    /// Inline custom emoji sticker, with the unique identifier of the custom emoji.
+    /// Use `GetCustomEmojiStickers` to get full information about the sticker.
+    CustomEmoji(String),
     #[doc(hidden)]
     Unknown(RawMessageEntity),
 }
@@ -708,10 +1006,15 @@ impl<'de> Deserialize<'de> for MessageEntity {
             "email" => Email,
             "bold" => Bold,
             "italic" => Italic,
+            "underline" => Underline,
+            "strikethrough" => Strikethrough,
+            "spoiler" => Spoiler,
             "code" => Code,
             "pre" => Pre,
+            "blockquote" => Blockquote,
             "text_link" => TextLink(required_field!(url)),
             "text_mention" => TextMention(required_field!(user)),
+            "custom_emoji" => CustomEmoji(required_field!(custom_emoji_id)),
             _ => Unknown(raw),
         };
 
@@ -723,13 +1026,53 @@ impl<'de> Deserialize<'de> for MessageEntity {
     }
 }
 
+impl Serialize for MessageEntity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use self::MessageEntityKind::*;
+
+        let (type_, url, user, custom_emoji_id) = match self.kind {
+            Mention => ("mention", None, None, None),
+            Hashtag => ("hashtag", None, None, None),
+            BotCommand => ("bot_command", None, None, None),
+            Url => ("url", None, None, None),
+            Email => ("email", None, None, None),
+            Bold => ("bold", None, None, None),
+            Italic => ("italic", None, None, None),
+            Underline => ("underline", None, None, None),
+            Strikethrough => ("strikethrough", None, None, None),
+            Spoiler => ("spoiler", None, None, None),
+            Code => ("code", None, None, None),
+            Pre => ("pre", None, None, None),
+            Blockquote => ("blockquote", None, None, None),
+            TextLink(ref url) => ("text_link", Some(url.clone()), None, None),
+            TextMention(ref user) => ("text_mention", None, Some(user.clone()), None),
+            CustomEmoji(ref id) => ("custom_emoji", None, None, Some(id.clone())),
+            Unknown(ref raw) => return raw.serialize(serializer),
+        };
+
+        RawMessageEntity {
+            type_: type_.to_string(),
+            offset: self.offset,
+            length: self.length,
+            url,
+            user,
+            custom_emoji_id,
+        }
+        .serialize(serializer)
+    }
+}
+
 /// This object represents one special entity in a text message.
 /// For example, hashtags, usernames, URLs, etc. Directly mapped.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct RawMessageEntity {
     /// Type of the entity. Can be mention (@username), hashtag, bot_command, url, email,
-    /// bold (bold text), italic (italic text), code (monowidth string), pre (monowidth block),
-    /// text_link (for clickable text URLs), text_mention (for users without usernames).
+    /// bold (bold text), italic (italic text), underline, strikethrough, spoiler,
+    /// code (monowidth string), pre (monowidth block), blockquote, text_link (for clickable text URLs), text_mention (for users without usernames),
+    /// custom_emoji (for inline custom emoji stickers).
     #[serde(rename = "type")]
     pub type_: String,
     /// Offset in UTF-16 code units to the start of the entity.
@@ -737,9 +1080,14 @@ pub struct RawMessageEntity {
     /// Length of the entity in UTF-16 code units.
     pub length: Integer,
     /// For “text_link” only, url that will be opened after user taps on the text.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
     /// For “text_mention” only, the mentioned user.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<User>,
+    /// For “custom_emoji” only, unique identifier of the custom emoji.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_emoji_id: Option<String>,
 }
 
 /// This object represents one size of a photo or a file / sticker thumbnail.
@@ -799,12 +1147,26 @@ pub struct Sticker {
     pub width: Integer,
     /// Sticker height.
     pub height: Integer,
+    /// Type of the sticker. The type of the sticker is independent from its format,
+    /// which is determined by the fields `is_animated` and `is_video`.
+    #[serde(rename = "type", default)]
+    pub kind: StickerType,
+    /// True, if the sticker is animated.
+    #[serde(default)]
+    pub is_animated: bool,
+    /// True, if the sticker is a video sticker.
+    #[serde(default)]
+    pub is_video: bool,
     /// Sticker thumbnail in .webp or .jpg format.
     pub thumb: Option<PhotoSize>,
     /// Emoji associated with the sticker.
     pub emoji: Option<String>,
     /// The name of the sticker set this sticker belongs to.
     pub set_name: Option<String>,
+    /// For mask stickers, the position where the mask should be placed.
+    pub mask_position: Option<MaskPosition>,
+    /// For custom emoji stickers, unique identifier of the custom emoji.
+    pub custom_emoji_id: Option<String>,
     /// File size.
     pub file_size: Option<Integer>,
 }
@@ -822,12 +1184,36 @@ pub struct Video {
     pub duration: Integer,
     /// Video thumbnail.
     pub thumb: Option<PhotoSize>,
+    /// Original filename as defined by sender.
+    pub file_name: Option<String>,
     /// Mime type of a file as defined by sender.
     pub mime_type: Option<String>,
     /// File size.
     pub file_size: Option<Integer>,
 }
 
+/// This object represents an animation file (GIF or H.264/MPEG-4 AVC video without sound).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct Animation {
+    /// Unique identifier for this file.
+    pub file_id: String,
+    /// Video width as defined by sender.
+    pub width: Integer,
+    /// Video height as defined by sender.
+    pub height: Integer,
+    /// Duration of the video in seconds as defined by sender.
+    pub duration: Integer,
+    /// Animation thumbnail as defined by sender.
+    #[serde(rename = "thumbnail", alias = "thumb")]
+    pub thumb: Option<PhotoSize>,
+    /// Original animation filename as defined by sender.
+    pub file_name: Option<String>,
+    /// MIME type of the file as defined by sender.
+    pub mime_type: Option<String>,
+    /// File size.
+    pub file_size: Option<Integer>,
+}
+
 /// This object represents a voice note.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
 pub struct Voice {
@@ -866,6 +1252,8 @@ pub struct Contact {
     pub last_name: Option<String>,
     /// Contact's user identifier in Telegram.
     pub user_id: Option<Integer>,
+    /// Additional data about the contact in the form of a vCard.
+    pub vcard: Option<String>,
 }
 
 /// This object represents a point on the map.
@@ -875,6 +1263,16 @@ pub struct Location {
     pub longitude: Float,
     /// Latitude as defined by sender.
     pub latitude: Float,
+    /// The radius of uncertainty for the location, measured in meters; 0-1500.
+    pub horizontal_accuracy: Option<Float>,
+    /// Time relative to the message sending date, during which the location can be updated,
+    /// in seconds. For active live locations only.
+    pub live_period: Option<Integer>,
+    /// The direction in which user is moving, in degrees; 1-360. For active live locations only.
+    pub heading: Option<Integer>,
+    /// Maximum distance for proximity alerts about approaching another chat member, in meters.
+    /// For sent live locations only.
+    pub proximity_alert_radius: Option<Integer>,
 }
 
 /// This object represents a venue.
@@ -888,6 +1286,42 @@ pub struct Venue {
     pub address: String,
     /// Foursquare identifier of the venue.
     pub foursquare_id: Option<String>,
+    /// Foursquare type of the venue.
+    pub foursquare_type: Option<String>,
+    /// Google Places identifier of the venue.
+    pub google_place_id: Option<String>,
+    /// Google Places type of the venue.
+    pub google_place_type: Option<String>,
+}
+
+/// This object represents an animated emoji that displays a random value.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct Dice {
+    /// Emoji on which the dice throw animation is based.
+    pub emoji: DiceEmoji,
+    /// Value of the dice, 1-6 for “🎲”, “🎯” and “🎳” base emoji,
+    /// 1-5 for “🏀” and “⚽” base emoji, 1-64 for “🎰” base emoji.
+    pub value: Integer,
+}
+
+/// Emoji on which the dice throw animation is based.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub enum DiceEmoji {
+    #[serde(rename = "🎲")]
+    Dice,
+    #[serde(rename = "🎯")]
+    Darts,
+    #[serde(rename = "🏀")]
+    Basketball,
+    #[serde(rename = "⚽")]
+    Football,
+    #[serde(rename = "🎳")]
+    Bowling,
+    #[serde(rename = "🎰")]
+    SlotMachine,
+    #[doc(hidden)]
+    #[serde(other)]
+    Unknown,
 }
 
 /// This object contains information about a poll.
@@ -978,7 +1412,7 @@ impl File {
     pub fn get_url(&self, token: &str) -> Option<String> {
         self.file_path
             .as_ref()
-            .map(|path| format!("{}file/bot{}/{}", telegram_api_url(), token, path))
+            .map(|path| RequestUrl::file(path.as_str()).url(token))
     }
 }
 
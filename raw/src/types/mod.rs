@@ -1,37 +1,85 @@
+pub mod bot_command;
+pub mod bot_description;
+pub mod business;
+pub mod callback_data;
 pub mod callback_query;
 pub mod chat;
+pub mod chat_administrator_rights;
+pub mod chat_boost;
 pub mod chat_invite_link;
+pub mod chat_join_request;
 pub mod chat_member;
 pub mod chat_member_update;
+pub mod chat_permissions;
 pub mod chosen_inline_result;
+pub mod forum_topic;
+pub mod game;
+pub mod giveaway;
 pub mod inline_query;
 pub mod inline_query_result;
 pub mod input_file;
+pub mod input_media;
+pub mod input_sticker;
+pub mod link_preview_options;
+pub mod menu_button;
 pub mod message;
+pub mod paid_media;
+pub mod passport;
+pub mod payment;
 pub mod pre_checkout_query;
 pub mod primitive;
+pub mod reaction;
 pub mod refs;
 pub mod reply_markup;
+pub mod reply_parameters;
 pub mod response_parameters;
 pub mod shipping_query;
+pub mod sticker;
 pub mod text;
 pub mod update;
+pub mod video_chat;
+pub mod web_app;
+pub mod webhook_info;
 
+pub use self::bot_command::*;
+pub use self::bot_description::*;
+pub use self::business::*;
+pub use self::callback_data::*;
 pub use self::callback_query::*;
 pub use self::chat::*;
+pub use self::chat_administrator_rights::*;
+pub use self::chat_boost::*;
 pub use self::chat_invite_link::*;
+pub use self::chat_join_request::*;
 pub use self::chat_member::*;
 pub use self::chat_member_update::*;
+pub use self::chat_permissions::*;
 pub use self::chosen_inline_result::*;
+pub use self::forum_topic::*;
+pub use self::game::*;
+pub use self::giveaway::*;
 pub use self::inline_query::*;
 pub use self::inline_query_result::*;
 pub use self::input_file::*;
+pub use self::input_media::*;
+pub use self::input_sticker::*;
+pub use self::link_preview_options::*;
+pub use self::menu_button::*;
 pub use self::message::*;
+pub use self::paid_media::*;
+pub use self::passport::*;
+pub use self::payment::*;
 pub use self::pre_checkout_query::*;
 pub use self::primitive::*;
+pub use self::reaction::*;
 pub use self::refs::*;
 pub use self::reply_markup::*;
+pub use self::reply_parameters::*;
 pub use self::response_parameters::*;
 pub use self::shipping_query::*;
+pub use self::sticker::*;
 pub use self::text::*;
 pub use self::update::*;
+pub use self::video_chat::*;
+pub use self::web_app::*;
+pub use self::webhook_info::*;
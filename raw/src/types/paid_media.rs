@@ -0,0 +1,180 @@
+use std::ops::Not;
+
+use crate::requests::*;
+use crate::types::*;
+
+/// Describes the paid media added to a message.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct PaidMediaInfo {
+    /// The number of Telegram Stars that must be paid to buy access to the media.
+    pub star_count: Integer,
+    /// Information about the paid media.
+    pub paid_media: Vec<PaidMedia>,
+}
+
+/// This object describes paid media.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+#[serde(tag = "type")]
+pub enum PaidMedia {
+    /// The paid media isn't available before the payment.
+    #[serde(rename = "preview")]
+    Preview {
+        /// Media width as defined by the sender.
+        width: Option<Integer>,
+        /// Media height as defined by the sender.
+        height: Option<Integer>,
+        /// Duration of the media in seconds as defined by the sender.
+        duration: Option<Integer>,
+    },
+    /// The paid media is a photo.
+    #[serde(rename = "photo")]
+    Photo {
+        /// The photo.
+        photo: Vec<PhotoSize>,
+    },
+    /// The paid media is a video.
+    #[serde(rename = "video")]
+    Video {
+        /// The video.
+        video: Video,
+    },
+    #[doc(hidden)]
+    #[serde(other)]
+    Unknown,
+}
+
+/// This object describes the paid media to be sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum InputPaidMedia {
+    /// The paid media to send is a photo.
+    Photo(InputFile),
+    /// The paid media to send is a video.
+    Video(InputPaidMediaVideo),
+}
+
+impl InputPaidMedia {
+    pub fn photo<F: Into<InputFile>>(media: F) -> Self {
+        InputPaidMedia::Photo(media.into())
+    }
+}
+
+/// The paid media to send is a video.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct InputPaidMediaVideo {
+    media: InputFile,
+    thumb: Option<InputFile>,
+    width: Option<Integer>,
+    height: Option<Integer>,
+    duration: Option<Integer>,
+    supports_streaming: bool,
+}
+
+impl InputPaidMediaVideo {
+    pub fn new<F: Into<InputFile>>(media: F) -> Self {
+        InputPaidMediaVideo {
+            media: media.into(),
+            thumb: None,
+            width: None,
+            height: None,
+            duration: None,
+            supports_streaming: false,
+        }
+    }
+
+    /// Thumbnail of the file sent.
+    pub fn thumb<F: Into<InputFile>>(&mut self, thumb: F) -> &mut Self {
+        self.thumb = Some(thumb.into());
+        self
+    }
+
+    pub fn width(&mut self, width: Integer) -> &mut Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(&mut self, height: Integer) -> &mut Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn duration(&mut self, duration: Integer) -> &mut Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Pass True, if the uploaded video is suitable for streaming.
+    pub fn supports_streaming(&mut self) -> &mut Self {
+        self.supports_streaming = true;
+        self
+    }
+}
+
+impl From<InputPaidMediaVideo> for InputPaidMedia {
+    fn from(value: InputPaidMediaVideo) -> Self {
+        InputPaidMedia::Video(value)
+    }
+}
+
+impl<'a> From<&'a mut InputPaidMediaVideo> for InputPaidMedia {
+    fn from(value: &'a mut InputPaidMediaVideo) -> Self {
+        InputPaidMedia::Video(value.clone())
+    }
+}
+
+/// `InputPaidMedia` as it is sent to Telegram, with uploads replaced by `attach://<name>`.
+#[derive(Serialize)]
+struct RawInputPaidMedia {
+    #[serde(rename = "type")]
+    type_: &'static str,
+    media: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumbnail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<Integer>,
+    #[serde(skip_serializing_if = "Not::not")]
+    supports_streaming: bool,
+}
+
+impl InputPaidMedia {
+    /// Serializes a list of media to JSON, collecting files to upload in `attachments`.
+    pub(crate) fn list_to_json(
+        media: &[InputPaidMedia],
+        attachments: &mut Multipart,
+    ) -> Result<String, Error> {
+        let raw = media
+            .iter()
+            .map(|media| media.to_raw(attachments))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(serde_json::to_string(&raw).map_err(ErrorKind::from)?)
+    }
+
+    fn to_raw(&self, attachments: &mut Multipart) -> Result<RawInputPaidMedia, Error> {
+        Ok(match self {
+            InputPaidMedia::Photo(photo) => RawInputPaidMedia {
+                type_: "photo",
+                media: attach(photo, attachments)?,
+                thumbnail: None,
+                width: None,
+                height: None,
+                duration: None,
+                supports_streaming: false,
+            },
+            InputPaidMedia::Video(video) => RawInputPaidMedia {
+                type_: "video",
+                media: attach(&video.media, attachments)?,
+                thumbnail: match &video.thumb {
+                    Some(thumb) => Some(attach(thumb, attachments)?),
+                    None => None,
+                },
+                width: video.width,
+                height: video.height,
+                duration: video.duration,
+                supports_streaming: video.supports_streaming,
+            },
+        })
+    }
+}
@@ -0,0 +1,259 @@
+use crate::types::*;
+
+/// Describes Telegram Passport data shared with the bot by the user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct PassportData {
+    /// Array with information about documents and other Telegram Passport elements
+    /// that was shared with the bot.
+    pub data: Vec<EncryptedPassportElement>,
+    /// Encrypted credentials required to decrypt the data.
+    pub credentials: EncryptedCredentials,
+}
+
+/// This object represents a file uploaded to Telegram Passport. Currently all Telegram
+/// Passport files are in JPEG format when decrypted and don't exceed 10MB.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct PassportFile {
+    /// Identifier for this file, which can be used to download or reuse the file.
+    pub file_id: String,
+    /// Unique identifier for this file, which is supposed to be the same over time and for different bots.
+    /// Can't be used to download or reuse the file.
+    pub file_unique_id: String,
+    /// File size in bytes.
+    pub file_size: Integer,
+    /// Unix time when the file was uploaded.
+    pub file_date: Integer,
+}
+
+/// Type of a Telegram Passport element.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub enum EncryptedPassportElementType {
+    #[serde(rename = "personal_details")]
+    PersonalDetails,
+    #[serde(rename = "passport")]
+    Passport,
+    #[serde(rename = "driver_license")]
+    DriverLicense,
+    #[serde(rename = "identity_card")]
+    IdentityCard,
+    #[serde(rename = "internal_passport")]
+    InternalPassport,
+    #[serde(rename = "address")]
+    Address,
+    #[serde(rename = "utility_bill")]
+    UtilityBill,
+    #[serde(rename = "bank_statement")]
+    BankStatement,
+    #[serde(rename = "rental_agreement")]
+    RentalAgreement,
+    #[serde(rename = "passport_registration")]
+    PassportRegistration,
+    #[serde(rename = "temporary_registration")]
+    TemporaryRegistration,
+    #[serde(rename = "phone_number")]
+    PhoneNumber,
+    #[serde(rename = "email")]
+    Email,
+    #[doc(hidden)]
+    #[serde(other)]
+    Unknown,
+}
+
+/// Describes documents or other Telegram Passport elements shared with the bot by the user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct EncryptedPassportElement {
+    /// Element type.
+    #[serde(rename = "type")]
+    pub kind: EncryptedPassportElementType,
+    /// Base64-encoded encrypted Telegram Passport element data provided by the user;
+    /// available only for personal details, identity documents and address.
+    pub data: Option<String>,
+    /// User's verified phone number; available only for “phone_number” type.
+    pub phone_number: Option<String>,
+    /// User's verified email address; available only for “email” type.
+    pub email: Option<String>,
+    /// Array of encrypted files with documents provided by the user;
+    /// available only for utility bills, statements, rental agreements and registrations.
+    pub files: Option<Vec<PassportFile>>,
+    /// Encrypted file with the front side of the document, provided by the user.
+    pub front_side: Option<PassportFile>,
+    /// Encrypted file with the reverse side of the document, provided by the user;
+    /// available only for driver licenses and identity cards.
+    pub reverse_side: Option<PassportFile>,
+    /// Encrypted file with the selfie of the user holding a document, provided by the user.
+    pub selfie: Option<PassportFile>,
+    /// Array of encrypted files with translated versions of documents provided by the user.
+    pub translation: Option<Vec<PassportFile>>,
+    /// Base64-encoded element hash for using in `PassportElementError::Unspecified`.
+    pub hash: String,
+}
+
+/// Describes data required for decrypting and authenticating `EncryptedPassportElement`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct EncryptedCredentials {
+    /// Base64-encoded encrypted JSON-serialized data with unique user's payload,
+    /// data hashes and secrets required for `EncryptedPassportElement` decryption
+    /// and authentication.
+    pub data: String,
+    /// Base64-encoded data hash for data authentication.
+    pub hash: String,
+    /// Base64-encoded secret, encrypted with the bot's public RSA key,
+    /// required for data decryption.
+    pub secret: String,
+}
+
+/// Decrypted contents of `EncryptedCredentials`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct Credentials {
+    /// Credentials for encrypted data.
+    pub secure_data: SecureData,
+    /// Bot-specified nonce.
+    pub nonce: String,
+}
+
+/// Credentials for all elements shared with the bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct SecureData {
+    pub personal_details: Option<SecureValue>,
+    pub passport: Option<SecureValue>,
+    pub internal_passport: Option<SecureValue>,
+    pub driver_license: Option<SecureValue>,
+    pub identity_card: Option<SecureValue>,
+    pub address: Option<SecureValue>,
+    pub utility_bill: Option<SecureValue>,
+    pub bank_statement: Option<SecureValue>,
+    pub rental_agreement: Option<SecureValue>,
+    pub passport_registration: Option<SecureValue>,
+    pub temporary_registration: Option<SecureValue>,
+}
+
+impl SecureData {
+    /// Credentials for the element of the given type, if it was shared.
+    pub fn get(&self, kind: EncryptedPassportElementType) -> Option<&SecureValue> {
+        use self::EncryptedPassportElementType::*;
+
+        match kind {
+            PersonalDetails => self.personal_details.as_ref(),
+            Passport => self.passport.as_ref(),
+            InternalPassport => self.internal_passport.as_ref(),
+            DriverLicense => self.driver_license.as_ref(),
+            IdentityCard => self.identity_card.as_ref(),
+            Address => self.address.as_ref(),
+            UtilityBill => self.utility_bill.as_ref(),
+            BankStatement => self.bank_statement.as_ref(),
+            RentalAgreement => self.rental_agreement.as_ref(),
+            PassportRegistration => self.passport_registration.as_ref(),
+            TemporaryRegistration => self.temporary_registration.as_ref(),
+            PhoneNumber | Email | Unknown => None,
+        }
+    }
+}
+
+/// Credentials required to decrypt the data and files of one element.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct SecureValue {
+    pub data: Option<DataCredentials>,
+    pub front_side: Option<FileCredentials>,
+    pub reverse_side: Option<FileCredentials>,
+    pub selfie: Option<FileCredentials>,
+    pub translation: Option<Vec<FileCredentials>>,
+    pub files: Option<Vec<FileCredentials>>,
+}
+
+/// Credentials required to decrypt the `data` field of an element.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct DataCredentials {
+    /// Base64-encoded checksum of the encrypted data.
+    pub data_hash: String,
+    /// Base64-encoded secret of the encrypted data.
+    pub secret: String,
+}
+
+/// Credentials required to decrypt a `PassportFile`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct FileCredentials {
+    /// Base64-encoded checksum of the encrypted file.
+    pub file_hash: String,
+    /// Base64-encoded secret of the encrypted file.
+    pub secret: String,
+}
+
+/// This object represents an error in the Telegram Passport element which was submitted
+/// that should be resolved by the user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(tag = "source")]
+pub enum PassportElementError {
+    /// An issue in one of the data fields that was provided by the user.
+    #[serde(rename = "data")]
+    Data {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        field_name: String,
+        data_hash: String,
+        message: String,
+    },
+    /// An issue with the front side of a document.
+    #[serde(rename = "front_side")]
+    FrontSide {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with the reverse side of a document.
+    #[serde(rename = "reverse_side")]
+    ReverseSide {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with the selfie with a document.
+    #[serde(rename = "selfie")]
+    Selfie {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with a document scan.
+    #[serde(rename = "file")]
+    File {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with a list of scans.
+    #[serde(rename = "files")]
+    Files {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hashes: Vec<String>,
+        message: String,
+    },
+    /// An issue with one of the files that constitute the translation of a document.
+    #[serde(rename = "translation_file")]
+    TranslationFile {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with the translated version of a document.
+    #[serde(rename = "translation_files")]
+    TranslationFiles {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        file_hashes: Vec<String>,
+        message: String,
+    },
+    /// An issue in an unspecified place.
+    #[serde(rename = "unspecified")]
+    Unspecified {
+        #[serde(rename = "type")]
+        kind: EncryptedPassportElementType,
+        element_hash: String,
+        message: String,
+    },
+}
@@ -0,0 +1,141 @@
+use crate::types::*;
+
+/// This object represents a portion of the price for goods or services.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub struct LabeledPrice {
+    /// Portion label
+    pub label: String,
+    /// Price of the product in the smallest units of the currency (integer, not float/double).
+    /// For example, for a price of US$ 1.45 pass amount = 145.
+    pub amount: Integer,
+}
+
+impl LabeledPrice {
+    pub fn new<L: Into<String>>(label: L, amount: Integer) -> Self {
+        LabeledPrice {
+            label: label.into(),
+            amount,
+        }
+    }
+}
+
+/// This object contains basic information about an invoice.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct Invoice {
+    /// Product name
+    pub title: String,
+    /// Product description
+    pub description: String,
+    /// Unique bot deep-linking parameter that can be used to generate this invoice
+    pub start_parameter: String,
+    /// Three-letter ISO 4217 currency code
+    pub currency: String,
+    /// Total price in the smallest units of the currency (integer, not float/double).
+    pub total_amount: Integer,
+}
+
+/// This object contains basic information about a successful payment.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct SuccessfulPayment {
+    /// Three-letter ISO 4217 currency code
+    pub currency: String,
+    /// Total price in the smallest units of the currency (integer, not float/double).
+    pub total_amount: Integer,
+    /// Bot specified invoice payload
+    pub invoice_payload: String,
+    /// Identifier of the shipping option chosen by the user
+    pub shipping_option_id: Option<String>,
+    /// Order info provided by the user
+    pub order_info: Option<OrderInfo>,
+    /// Telegram payment identifier
+    pub telegram_payment_charge_id: String,
+    /// Provider payment identifier
+    pub provider_payment_charge_id: String,
+}
+
+/// This object represents one shipping option.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize)]
+pub struct ShippingOption {
+    id: String,
+    title: String,
+    prices: Vec<LabeledPrice>,
+}
+
+impl ShippingOption {
+    pub fn new<I, T>(id: I, title: T, prices: Vec<LabeledPrice>) -> Self
+    where
+        I: Into<String>,
+        T: Into<String>,
+    {
+        ShippingOption {
+            id: id.into(),
+            title: title.into(),
+            prices,
+        }
+    }
+}
+
+/// This object contains basic information about a refunded payment.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct RefundedPayment {
+    /// Three-letter ISO 4217 currency code, or “XTR” for payments in Telegram Stars.
+    pub currency: String,
+    /// Total refunded price in the smallest units of the currency (integer, not float/double).
+    pub total_amount: Integer,
+    /// Bot-specified invoice payload
+    pub invoice_payload: String,
+    /// Telegram payment identifier
+    pub telegram_payment_charge_id: String,
+    /// Provider payment identifier
+    pub provider_payment_charge_id: Option<String>,
+}
+
+/// Contains a list of Telegram Star transactions.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct StarTransactions {
+    /// The list of transactions
+    pub transactions: Vec<StarTransaction>,
+}
+
+/// Describes a Telegram Star transaction.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct StarTransaction {
+    /// Unique identifier of the transaction. Coincides with the identifier of the original
+    /// transaction for refund transactions. Coincides with SuccessfulPayment.telegram_payment_charge_id
+    /// for successful incoming payments from users.
+    pub id: String,
+    /// Number of Telegram Stars transferred by the transaction
+    pub amount: Integer,
+    /// Date the transaction was created in Unix time
+    pub date: Integer,
+    /// Source of an incoming transaction. Only for incoming transactions
+    pub source: Option<TransactionPartner>,
+    /// Receiver of an outgoing transaction. Only for outgoing transactions
+    pub receiver: Option<TransactionPartner>,
+}
+
+/// This object describes the source of a transaction, or its recipient for outgoing transactions.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+#[serde(tag = "type")]
+pub enum TransactionPartner {
+    /// Describes a transaction with a user.
+    #[serde(rename = "user")]
+    User {
+        /// Information about the user
+        user: User,
+        /// Bot-specified invoice payload
+        invoice_payload: Option<String>,
+    },
+    /// Describes a withdrawal transaction with Fragment.
+    #[serde(rename = "fragment")]
+    Fragment,
+    /// Describes a withdrawal transaction to the Telegram Ads platform.
+    #[serde(rename = "telegram_ads")]
+    TelegramAds,
+    /// Describes a transaction with an unknown source or recipient.
+    #[serde(rename = "other")]
+    Other,
+    #[doc(hidden)]
+    #[serde(other)]
+    Unknown,
+}
@@ -0,0 +1,90 @@
+use crate::types::*;
+
+/// This object describes the type of a reaction.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ReactionType {
+    /// The reaction is based on an emoji.
+    #[serde(rename = "emoji")]
+    Emoji {
+        /// Reaction emoji.
+        emoji: String,
+    },
+    /// The reaction is based on a custom emoji.
+    #[serde(rename = "custom_emoji")]
+    CustomEmoji {
+        /// Custom emoji identifier.
+        custom_emoji_id: String,
+    },
+    #[doc(hidden)]
+    #[serde(other)]
+    Unknown,
+}
+
+impl ReactionType {
+    pub fn emoji<E: Into<String>>(emoji: E) -> Self {
+        ReactionType::Emoji {
+            emoji: emoji.into(),
+        }
+    }
+
+    pub fn custom_emoji<I: Into<String>>(custom_emoji_id: I) -> Self {
+        ReactionType::CustomEmoji {
+            custom_emoji_id: custom_emoji_id.into(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReactionType {
+    fn from(emoji: &'a str) -> Self {
+        ReactionType::emoji(emoji)
+    }
+}
+
+impl From<String> for ReactionType {
+    fn from(emoji: String) -> Self {
+        ReactionType::emoji(emoji)
+    }
+}
+
+/// Represents a reaction added to a message along with the number of times it was added.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct ReactionCount {
+    /// Type of the reaction.
+    #[serde(rename = "type")]
+    pub kind: ReactionType,
+    /// Number of times the reaction was added.
+    pub total_count: Integer,
+}
+
+/// This object represents a change of a reaction on a message performed by a user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct MessageReactionUpdated {
+    /// The chat containing the message the user reacted to.
+    pub chat: Chat,
+    /// Unique identifier of the message inside the chat.
+    pub message_id: MessageId,
+    /// The user that changed the reaction, if the user isn't anonymous.
+    pub user: Option<User>,
+    /// The chat on behalf of which the reaction was changed, if the user is anonymous.
+    pub actor_chat: Option<Chat>,
+    /// Date of the change in Unix time.
+    pub date: Integer,
+    /// Previous list of reaction types that were set by the user.
+    pub old_reaction: Vec<ReactionType>,
+    /// New list of reaction types that have been set by the user.
+    pub new_reaction: Vec<ReactionType>,
+}
+
+/// This object represents reaction changes on a message with anonymous reactions.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct MessageReactionCountUpdated {
+    /// The chat containing the message.
+    pub chat: Chat,
+    /// Unique message identifier inside the chat.
+    pub message_id: MessageId,
+    /// Date of the change in Unix time.
+    pub date: Integer,
+    /// List of reactions that are present on the message.
+    pub reactions: Vec<ReactionCount>,
+}
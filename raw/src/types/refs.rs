@@ -332,6 +332,12 @@ where
     }
 }
 
+impl ToFileRef for FileRef {
+    fn to_file_ref(&self) -> FileRef {
+        self.clone()
+    }
+}
+
 macro_rules! file_id_impls {
     ($name: ident) => {
         impl ToFileRef for $name {
@@ -345,10 +351,12 @@ macro_rules! file_id_impls {
 file_id_impls!(PhotoSize);
 file_id_impls!(Audio);
 file_id_impls!(Document);
+file_id_impls!(Animation);
 file_id_impls!(Sticker);
 file_id_impls!(Video);
 file_id_impls!(Voice);
 file_id_impls!(VideoNote);
+file_id_impls!(PassportFile);
 
 /// Unique file identifier reference.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -17,6 +17,12 @@ impl From<InlineKeyboardMarkup> for ReplyMarkup {
     }
 }
 
+impl<'a> From<&'a mut InlineKeyboardMarkup> for ReplyMarkup {
+    fn from(value: &'a mut InlineKeyboardMarkup) -> ReplyMarkup {
+        ReplyMarkup::InlineKeyboardMarkup(value.clone())
+    }
+}
+
 impl From<Vec<Vec<InlineKeyboardButton>>> for ReplyMarkup {
     fn from(value: Vec<Vec<InlineKeyboardButton>>) -> ReplyMarkup {
         ReplyMarkup::InlineKeyboardMarkup(value.into())
@@ -29,18 +35,36 @@ impl From<ReplyKeyboardMarkup> for ReplyMarkup {
     }
 }
 
+impl<'a> From<&'a mut ReplyKeyboardMarkup> for ReplyMarkup {
+    fn from(value: &'a mut ReplyKeyboardMarkup) -> ReplyMarkup {
+        ReplyMarkup::ReplyKeyboardMarkup(value.clone())
+    }
+}
+
 impl From<ReplyKeyboardRemove> for ReplyMarkup {
     fn from(value: ReplyKeyboardRemove) -> ReplyMarkup {
         ReplyMarkup::ReplyKeyboardRemove(value)
     }
 }
 
+impl<'a> From<&'a mut ReplyKeyboardRemove> for ReplyMarkup {
+    fn from(value: &'a mut ReplyKeyboardRemove) -> ReplyMarkup {
+        ReplyMarkup::ReplyKeyboardRemove(value.clone())
+    }
+}
+
 impl From<ForceReply> for ReplyMarkup {
     fn from(value: ForceReply) -> ReplyMarkup {
         ReplyMarkup::ForceReply(value)
     }
 }
 
+impl<'a> From<&'a mut ForceReply> for ReplyMarkup {
+    fn from(value: &'a mut ForceReply) -> ReplyMarkup {
+        ReplyMarkup::ForceReply(value.clone())
+    }
+}
+
 /// This object represents a custom keyboard with reply options.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
 pub struct ReplyKeyboardMarkup {
@@ -49,6 +73,8 @@ pub struct ReplyKeyboardMarkup {
     resize_keyboard: bool,
     #[serde(skip_serializing_if = "Not::not")]
     one_time_keyboard: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_field_placeholder: Option<String>,
     #[serde(skip_serializing_if = "Not::not")]
     selective: bool,
 }
@@ -59,6 +85,7 @@ impl ReplyKeyboardMarkup {
             keyboard: Vec::new(),
             resize_keyboard: false,
             one_time_keyboard: false,
+            input_field_placeholder: None,
             selective: false,
         }
     }
@@ -88,6 +115,13 @@ impl ReplyKeyboardMarkup {
         self
     }
 
+    /// The placeholder to be shown in the input field when the keyboard is active;
+    /// 1-64 characters.
+    pub fn input_field_placeholder<T: AsRef<str>>(&mut self, placeholder: T) -> &mut Self {
+        self.input_field_placeholder = Some(placeholder.as_ref().to_string());
+        self
+    }
+
     /// Use this method if you want to force reply from specific users only.
     /// Targets: 1) users that are @mentioned in the text of
     /// the Message object; 2) if the bot's message is a reply (has reply_to_message_id),
@@ -121,6 +155,8 @@ pub struct KeyboardButton {
     request_contact: bool,
     #[serde(skip_serializing_if = "Not::not")]
     request_location: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_app: Option<WebAppInfo>,
 }
 
 impl KeyboardButton {
@@ -129,6 +165,7 @@ impl KeyboardButton {
             text: text.as_ref().to_string(),
             request_contact: false,
             request_location: false,
+            web_app: None,
         }
     }
 
@@ -136,6 +173,7 @@ impl KeyboardButton {
     /// button is pressed. Available in private chats only
     pub fn request_contact(&mut self) -> &mut Self {
         self.request_location = false;
+        self.web_app = None;
         self.request_contact = true;
         self
     }
@@ -144,9 +182,19 @@ impl KeyboardButton {
     /// button is pressed. Available in private chats only
     pub fn request_location(&mut self) -> &mut Self {
         self.request_contact = false;
+        self.web_app = None;
         self.request_location = true;
         self
     }
+
+    /// The described Web App will be launched when the button is pressed. The Web App will
+    /// be able to send a `web_app_data` service message. Available in private chats only
+    pub fn web_app<W: Into<WebAppInfo>>(&mut self, web_app: W) -> &mut Self {
+        self.request_contact = false;
+        self.request_location = false;
+        self.web_app = Some(web_app.into());
+        self
+    }
 }
 
 impl<'a> From<&'a str> for KeyboardButton {
@@ -259,6 +307,42 @@ impl InlineKeyboardButton {
         }
     }
 
+    /// HTTP URL used to automatically authorize the user. Can be used as a replacement for
+    /// the Telegram Login Widget.
+    pub fn login_url<T: AsRef<str>, L: Into<LoginUrl>>(text: T, login_url: L) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::LoginUrl(login_url.into()),
+        }
+    }
+
+    /// Description of the game that will be launched when the user presses the button.
+    /// This type of button must always be the first button in the first row.
+    pub fn callback_game<T: AsRef<str>>(text: T) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::CallbackGame(CallbackGame {}),
+        }
+    }
+
+    /// Description of the Web App that will be launched when the user presses the button.
+    /// Available only in private chats between a user and the bot.
+    pub fn web_app<T: AsRef<str>, W: Into<WebAppInfo>>(text: T, web_app: W) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::WebApp(web_app.into()),
+        }
+    }
+
+    /// Pay button. This type of button must always be the first button in the first row
+    /// and can only be used in invoice messages.
+    pub fn pay<T: AsRef<str>>(text: T) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::Pay(True),
+        }
+    }
+
     /// Pressing the button will insert the bot‘s username and the specified inline query in the
     /// current chat's input field. Can be empty, in which case just the bot’s username will be
     /// inserted.
@@ -285,12 +369,77 @@ pub enum InlineKeyboardButtonKind {
     SwitchInlineQuery(String),
     #[serde(rename = "switch_inline_query_current_chat")]
     SwitchInlineQueryCurrentChat(String),
-    // #[serde(rename = "callback_game")]
-    //  CallbackGame(CallbackGame),
-    // #[serde(rename = "pay")]
-    //  Pay,
-    // #[serde(rename = "login_url")]
-    //  LoginUrl(LoginUrl),
+    #[serde(rename = "login_url")]
+    LoginUrl(LoginUrl),
+    #[serde(rename = "web_app")]
+    WebApp(WebAppInfo),
+    #[serde(rename = "callback_game")]
+    CallbackGame(CallbackGame),
+    #[serde(rename = "pay")]
+    Pay(True),
+}
+
+/// This object represents a parameter of the inline keyboard button used to automatically
+/// authorize a user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct LoginUrl {
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    forward_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bot_username: Option<String>,
+    #[serde(skip_serializing_if = "Not::not")]
+    request_write_access: bool,
+}
+
+impl LoginUrl {
+    /// An HTTP URL to be opened with user authorization data added to the query string
+    /// when the button is pressed.
+    pub fn new<U: AsRef<str>>(url: U) -> Self {
+        Self {
+            url: url.as_ref().to_string(),
+            forward_text: None,
+            bot_username: None,
+            request_write_access: false,
+        }
+    }
+
+    /// New text of the button in forwarded messages.
+    pub fn forward_text<T: AsRef<str>>(&mut self, forward_text: T) -> &mut Self {
+        self.forward_text = Some(forward_text.as_ref().to_string());
+        self
+    }
+
+    /// Username of a bot, which will be used for user authorization.
+    /// If not specified, the current bot's username will be assumed.
+    pub fn bot_username<T: AsRef<str>>(&mut self, bot_username: T) -> &mut Self {
+        self.bot_username = Some(bot_username.as_ref().to_string());
+        self
+    }
+
+    /// Request the permission for your bot to send messages to the user.
+    pub fn request_write_access(&mut self) -> &mut Self {
+        self.request_write_access = true;
+        self
+    }
+}
+
+impl<'a> From<&'a str> for LoginUrl {
+    fn from(value: &'a str) -> LoginUrl {
+        LoginUrl::new(value)
+    }
+}
+
+impl From<String> for LoginUrl {
+    fn from(value: String) -> LoginUrl {
+        LoginUrl::new(value)
+    }
+}
+
+impl<'a> From<&'a mut LoginUrl> for LoginUrl {
+    fn from(value: &'a mut LoginUrl) -> LoginUrl {
+        value.clone()
+    }
 }
 
 /// Upon receiving a message with this object, Telegram clients will
@@ -301,6 +450,8 @@ pub enum InlineKeyboardButtonKind {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
 pub struct ForceReply {
     force_reply: True,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_field_placeholder: Option<String>,
     #[serde(skip_serializing_if = "Not::not")]
     selective: bool,
 }
@@ -309,10 +460,18 @@ impl ForceReply {
     pub fn new() -> Self {
         Self {
             force_reply: True,
+            input_field_placeholder: None,
             selective: false,
         }
     }
 
+    /// The placeholder to be shown in the input field when the reply is active;
+    /// 1-64 characters.
+    pub fn input_field_placeholder<T: AsRef<str>>(&mut self, placeholder: T) -> &mut Self {
+        self.input_field_placeholder = Some(placeholder.as_ref().to_string());
+        self
+    }
+
     /// Use this method if you want to force reply from specific users only.
     /// Targets: 1) users that are @mentioned in the text of
     /// the Message object; 2) if the bot's message is a reply (has reply_to_message_id),
@@ -0,0 +1,84 @@
+use std::ops::Not;
+
+use crate::types::*;
+
+/// Describes the message a new message is a reply to.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct ReplyParameters {
+    /// Identifier of the message that will be replied to in the current chat, or in the
+    /// chat `chat_id` if it is specified.
+    pub message_id: MessageId,
+    /// If the message to be replied to is from a different chat, the identifier of the chat.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<ChatRef>,
+    /// Pass true if the message should be sent even if the message to be replied to is
+    /// not found.
+    #[serde(skip_serializing_if = "Not::not")]
+    pub allow_sending_without_reply: bool,
+    /// Quoted part of the message to be replied to, 0-1024 characters after entities parsing.
+    /// The quote must be an exact substring of the message to be replied to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote: Option<String>,
+    /// Mode for parsing entities in the quote.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_parse_mode: Option<ParseMode>,
+    /// Entities in the quote, which can be specified instead of `quote_parse_mode`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_entities: Option<Vec<MessageEntity>>,
+    /// Position of the quote in the original message in UTF-16 code units.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_position: Option<Integer>,
+}
+
+impl ReplyParameters {
+    pub fn new<M: ToMessageId>(message: M) -> Self {
+        ReplyParameters {
+            message_id: message.to_message_id(),
+            chat_id: None,
+            allow_sending_without_reply: false,
+            quote: None,
+            quote_parse_mode: None,
+            quote_entities: None,
+            quote_position: None,
+        }
+    }
+
+    /// Reply to a message in another chat.
+    pub fn chat<C: ToChatRef>(&mut self, chat: C) -> &mut Self {
+        self.chat_id = Some(chat.to_chat_ref());
+        self
+    }
+
+    pub fn allow_sending_without_reply(&mut self) -> &mut Self {
+        self.allow_sending_without_reply = true;
+        self
+    }
+
+    /// Quote a part of the message, which must be an exact substring of its text.
+    pub fn quote<Q: Into<String>>(&mut self, quote: Q) -> &mut Self {
+        self.quote = Some(quote.into());
+        self
+    }
+
+    pub fn quote_parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.quote_parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn quote_entities(&mut self, entities: Vec<MessageEntity>) -> &mut Self {
+        self.quote_entities = Some(entities);
+        self
+    }
+
+    /// Position of the quote in the message, to tell apart multiple occurrences of it.
+    pub fn quote_position(&mut self, position: Integer) -> &mut Self {
+        self.quote_position = Some(position);
+        self
+    }
+}
+
+impl<'a> From<&'a mut ReplyParameters> for ReplyParameters {
+    fn from(value: &'a mut ReplyParameters) -> ReplyParameters {
+        value.clone()
+    }
+}
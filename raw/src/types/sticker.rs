@@ -0,0 +1,108 @@
+use crate::types::*;
+
+/// This object represents a sticker set.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct StickerSet {
+    /// Sticker set name.
+    pub name: String,
+    /// Sticker set title.
+    pub title: String,
+    /// Type of stickers in the set.
+    #[serde(rename = "sticker_type", default)]
+    pub kind: StickerType,
+    /// List of all set stickers.
+    pub stickers: Vec<Sticker>,
+    /// Sticker set thumbnail in the .WEBP, .TGS, or .WEBM format.
+    pub thumbnail: Option<PhotoSize>,
+}
+
+/// Type of a sticker.
+#[derive(
+    Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub enum StickerType {
+    #[default]
+    #[serde(rename = "regular")]
+    Regular,
+    #[serde(rename = "mask")]
+    Mask,
+    #[serde(rename = "custom_emoji")]
+    CustomEmoji,
+    #[doc(hidden)]
+    #[serde(other)]
+    Unknown,
+}
+
+impl ::std::fmt::Display for StickerType {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            StickerType::Regular => write!(f, "regular"),
+            StickerType::Mask => write!(f, "mask"),
+            StickerType::CustomEmoji => write!(f, "custom_emoji"),
+            StickerType::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Format of a sticker file.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize)]
+pub enum StickerFormat {
+    /// A .WEBP or .PNG image.
+    #[serde(rename = "static")]
+    Static,
+    /// A .TGS animation.
+    #[serde(rename = "animated")]
+    Animated,
+    /// A .WEBM video.
+    #[serde(rename = "video")]
+    Video,
+}
+
+impl ::std::fmt::Display for StickerFormat {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            StickerFormat::Static => write!(f, "static"),
+            StickerFormat::Animated => write!(f, "animated"),
+            StickerFormat::Video => write!(f, "video"),
+        }
+    }
+}
+
+/// This object describes the position on faces where a mask should be placed by default.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct MaskPosition {
+    /// The part of the face relative to which the mask should be placed.
+    pub point: MaskPositionPoint,
+    /// Shift by X-axis measured in widths of the mask scaled to the face size,
+    /// from left to right.
+    pub x_shift: Float,
+    /// Shift by Y-axis measured in heights of the mask scaled to the face size,
+    /// from top to bottom.
+    pub y_shift: Float,
+    /// Mask scaling coefficient.
+    pub scale: Float,
+}
+
+impl MaskPosition {
+    pub fn new(point: MaskPositionPoint, x_shift: Float, y_shift: Float, scale: Float) -> Self {
+        Self {
+            point,
+            x_shift,
+            y_shift,
+            scale,
+        }
+    }
+}
+
+/// The part of the face relative to which a mask should be placed.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub enum MaskPositionPoint {
+    #[serde(rename = "forehead")]
+    Forehead,
+    #[serde(rename = "eyes")]
+    Eyes,
+    #[serde(rename = "mouth")]
+    Mouth,
+    #[serde(rename = "chin")]
+    Chin,
+}
@@ -1,17 +1,60 @@
+use std::collections::BTreeMap;
+
+use serde::de::{Deserialize, Deserializer};
+use serde_value::{DeserializerError, Value};
+
 use crate::types::*;
 
 /// This object represents an incoming update.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct Update {
     /// The update‘s unique identifier. Update identifiers start from a certain
     /// positive number and increase sequentially.
-    #[serde(rename = "update_id")]
     pub id: Integer,
     /// Kind of the incoming update.
-    #[serde(flatten)]
     pub kind: UpdateKind,
 }
 
+impl<'de> Deserialize<'de> for Update {
+    fn deserialize<D>(deserializer: D) -> Result<Update, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct RawUpdate {
+            update_id: Integer,
+            #[serde(flatten)]
+            kind: BTreeMap<String, Value>,
+        }
+
+        let raw = RawUpdate::deserialize(deserializer)?;
+
+        // A single update that can't be parsed must not fail the whole batch
+        // returned by `getUpdates`, so failures are kept in the update kind.
+        let name = raw.kind.keys().next().cloned();
+        let value = Value::Map(
+            raw.kind
+                .into_iter()
+                .map(|(key, value)| (Value::String(key), value))
+                .collect(),
+        );
+        let kind = match value.deserialize_into() {
+            Ok(kind) => kind,
+            Err(DeserializerError::UnknownVariant(ref variant, _))
+                if Some(variant) == name.as_ref() =>
+            {
+                UpdateKind::Unknown
+            }
+            Err(err) => UpdateKind::Error(err.to_string()),
+        };
+
+        Ok(Update {
+            id: raw.update_id,
+            kind,
+        })
+    }
+}
+
 /// Kind of the incoming update.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
 pub enum UpdateKind {
@@ -52,8 +95,94 @@ pub enum UpdateKind {
     /// A chat member's status was updated in a chat. The bot must be an administrator in the chat and must explicitly specify “chat_member” in the list of allowed_updates to receive these updates.
     #[serde(rename = "chat_member")]
     ChatMember(ChatMemberUpdate),
-    #[doc(hidden)]
+    /// A request to join the chat has been sent. The bot must have the can_invite_users administrator right in the chat to receive these updates.
+    #[serde(rename = "chat_join_request")]
+    ChatJoinRequest(ChatJoinRequest),
+    /// A reaction to a message was changed by a user. The bot must be an administrator in the chat and must explicitly specify “message_reaction” in the list of allowed_updates to receive these updates.
+    #[serde(rename = "message_reaction")]
+    MessageReaction(MessageReactionUpdated),
+    /// Reactions to a message with anonymous reactions were changed. The bot must be an administrator in the chat and must explicitly specify “message_reaction_count” in the list of allowed_updates to receive these updates.
+    #[serde(rename = "message_reaction_count")]
+    MessageReactionCount(MessageReactionCountUpdated),
+    /// A chat boost was added or changed. The bot must be an administrator in the chat to receive these updates.
+    #[serde(rename = "chat_boost")]
+    ChatBoost(ChatBoostUpdated),
+    /// A boost was removed from a chat. The bot must be an administrator in the chat to receive these updates.
+    #[serde(rename = "removed_chat_boost")]
+    RemovedChatBoost(ChatBoostRemoved),
+    /// The bot was connected to or disconnected from a business account, or a user edited an existing connection with the bot.
+    #[serde(rename = "business_connection")]
+    BusinessConnection(BusinessConnection),
+    /// New message from a connected business account.
+    #[serde(rename = "business_message")]
+    BusinessMessage(Message),
+    /// New version of a message from a connected business account.
+    #[serde(rename = "edited_business_message")]
+    EditedBusinessMessage(Message),
+    /// Messages were deleted from a connected business account.
+    #[serde(rename = "deleted_business_messages")]
+    DeletedBusinessMessages(BusinessMessagesDeleted),
+    /// The update has a known kind, but couldn't be parsed. Contains the parsing error.
+    #[serde(skip)]
     Error(String),
-    #[doc(hidden)]
+    /// The update has a kind which isn't supported by this library yet.
+    #[serde(skip)]
     Unknown,
 }
+
+impl UpdateKind {
+    /// True, if the update carries a new version of an already known message or channel post.
+    pub fn is_edit(&self) -> bool {
+        matches!(
+            self,
+            UpdateKind::EditedMessage(_)
+                | UpdateKind::EditedChannelPost(_)
+                | UpdateKind::EditedBusinessMessage(_)
+        )
+    }
+
+    /// The chat the update belongs to, if any.
+    pub fn chat(&self) -> Option<ChatRef> {
+        match self {
+            UpdateKind::Message(message)
+            | UpdateKind::EditedMessage(message)
+            | UpdateKind::BusinessMessage(message)
+            | UpdateKind::EditedBusinessMessage(message) => Some(message.chat.to_chat_ref()),
+            UpdateKind::ChannelPost(post) | UpdateKind::EditedChannelPost(post) => {
+                Some(post.chat.to_chat_ref())
+            }
+            UpdateKind::CallbackQuery(query) => match query.message {
+                Some(MessageOrChannelPost::Message(ref message)) => {
+                    Some(message.chat.to_chat_ref())
+                }
+                Some(MessageOrChannelPost::ChannelPost(ref post)) => Some(post.chat.to_chat_ref()),
+                None => None,
+            },
+            UpdateKind::MyChatMember(update) | UpdateKind::ChatMember(update) => {
+                Some(update.chat.to_chat_ref())
+            }
+            UpdateKind::ChatJoinRequest(request) => Some(request.chat.to_chat_ref()),
+            UpdateKind::MessageReaction(reaction) => Some(reaction.chat.to_chat_ref()),
+            UpdateKind::MessageReactionCount(reaction) => Some(reaction.chat.to_chat_ref()),
+            UpdateKind::ChatBoost(boost) => Some(boost.chat.to_chat_ref()),
+            UpdateKind::RemovedChatBoost(boost) => Some(boost.chat.to_chat_ref()),
+            UpdateKind::DeletedBusinessMessages(deleted) => Some(deleted.chat.to_chat_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the message or channel post of the update, regardless of whether it is new
+    /// or edited.
+    pub fn into_message(self) -> Option<MessageOrChannelPost> {
+        match self {
+            UpdateKind::Message(message)
+            | UpdateKind::EditedMessage(message)
+            | UpdateKind::BusinessMessage(message)
+            | UpdateKind::EditedBusinessMessage(message) => Some(message.into()),
+            UpdateKind::ChannelPost(post) | UpdateKind::EditedChannelPost(post) => {
+                Some(post.into())
+            }
+            _ => None,
+        }
+    }
+}
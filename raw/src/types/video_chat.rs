@@ -0,0 +1,29 @@
+use crate::types::*;
+
+/// This object represents a service message about a video chat scheduled in the chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct VideoChatScheduled {
+    /// Point in time (Unix timestamp) when the video chat is supposed to be started
+    /// by a chat administrator.
+    pub start_date: Integer,
+}
+
+/// This object represents a service message about a video chat started in the chat.
+/// Currently holds no information.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct VideoChatStarted {}
+
+/// This object represents a service message about a video chat ended in the chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct VideoChatEnded {
+    /// Video chat duration in seconds.
+    pub duration: Integer,
+}
+
+/// This object represents a service message about new members invited to a video chat.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct VideoChatParticipantsInvited {
+    /// New members that were invited to the video chat.
+    #[serde(default)]
+    pub users: Vec<User>,
+}
@@ -0,0 +1,42 @@
+/// Describes a Web App.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub struct WebAppInfo {
+    /// An HTTPS URL of a Web App to be opened with additional data.
+    pub url: String,
+}
+
+impl WebAppInfo {
+    pub fn new<U: Into<String>>(url: U) -> Self {
+        Self { url: url.into() }
+    }
+}
+
+impl<'a> From<&'a str> for WebAppInfo {
+    fn from(url: &'a str) -> Self {
+        WebAppInfo::new(url)
+    }
+}
+
+impl From<String> for WebAppInfo {
+    fn from(url: String) -> Self {
+        WebAppInfo::new(url)
+    }
+}
+
+/// Describes data sent from a Web App to the bot.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct WebAppData {
+    /// The data. Be aware that a bad client can send arbitrary data in this field.
+    pub data: String,
+    /// Text of the `web_app` keyboard button from which the Web App was opened.
+    /// Be aware that a bad client can send arbitrary data in this field.
+    pub button_text: String,
+}
+
+/// Describes an inline message sent by a Web App on behalf of a user.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct SentWebAppMessage {
+    /// Identifier of the sent inline message.
+    /// Available only if there is an inline keyboard attached to the message.
+    pub inline_message_id: Option<String>,
+}
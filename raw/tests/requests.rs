@@ -0,0 +1,37 @@
+use telegram_bot_raw::{
+    Body, ChatId, InputFile, InputFileUpload, InputMedia, InputMediaVideo, MultipartValue, Request,
+    SendMediaGroup, SendVideo,
+};
+
+fn multipart(request: &impl Request) -> Vec<(&'static str, MultipartValue)> {
+    match request.serialize().unwrap().body {
+        Body::Multipart(fields) => fields,
+        body => panic!("unexpected body: {:?}", body),
+    }
+}
+
+#[test]
+fn send_video_thumbnail() {
+    let mut request = SendVideo::new(ChatId::new(1), InputFile::file_id("video"));
+    request.thumb(InputFileUpload::with_data(vec![1, 2, 3], "thumb.jpg"));
+
+    let names: Vec<_> = multipart(&request).iter().map(|&(name, _)| name).collect();
+    assert!(names.contains(&"thumbnail"));
+    assert!(!names.contains(&"thumb"));
+}
+
+#[test]
+fn input_media_video_thumbnail() {
+    let mut video = InputMediaVideo::new(InputFile::file_id("video"));
+    video.thumb(InputFileUpload::with_data(vec![1, 2, 3], "thumb.jpg"));
+    let request = SendMediaGroup::new(ChatId::new(1), vec![InputMedia::from(video)]);
+
+    let fields = multipart(&request);
+    match fields.iter().find(|&&(name, _)| name == "media") {
+        Some((_, MultipartValue::Text(media))) => assert_eq!(
+            media.as_str(),
+            r#"[{"type":"video","media":"video","thumbnail":"attach://file0"}]"#
+        ),
+        field => panic!("unexpected media field: {:?}", field),
+    }
+}
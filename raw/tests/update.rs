@@ -1,7 +1,12 @@
 use std::fs::File;
 use std::io::prelude::*;
 
-use telegram_bot_raw::types::message::MessageKind;
+use telegram_bot_raw::types::chat_member::ChatMemberKind;
+use telegram_bot_raw::types::chat_member_update::ChatMemberStatusChange;
+use telegram_bot_raw::types::message::{
+    DiceEmoji, MessageEntityKind, MessageKind, MessageOrChannelPost,
+};
+use telegram_bot_raw::types::reaction::ReactionType;
 use telegram_bot_raw::types::update::{Update, UpdateKind};
 
 macro_rules! make_test {
@@ -39,6 +44,65 @@ make_test!(migrate_to_chat_id, |update: Update| {
     assert!(false)
 });
 
+make_test!(custom_emoji, |update: Update| {
+    match update.kind {
+        UpdateKind::Message(message) => match message.kind {
+            MessageKind::Text { entities, .. } => {
+                assert_eq!(entities.len(), 1);
+                assert_eq!(
+                    entities[0].kind,
+                    MessageEntityKind::CustomEmoji("5368324170671202286".into())
+                );
+            }
+            kind => panic!("unexpected message kind: {:?}", kind),
+        },
+        kind => panic!("unexpected update kind: {:?}", kind),
+    }
+});
+
+make_test!(dice, |update: Update| {
+    match update.kind {
+        UpdateKind::Message(message) => match message.kind {
+            MessageKind::Dice { data } => {
+                assert_eq!(data.emoji, DiceEmoji::Darts);
+                assert_eq!(data.value, 6);
+            }
+            kind => panic!("unexpected message kind: {:?}", kind),
+        },
+        kind => panic!("unexpected update kind: {:?}", kind),
+    }
+});
+
+make_test!(edited_channel_post, |update: Update| {
+    assert!(update.kind.is_edit());
+    match update.kind.into_message() {
+        Some(post @ MessageOrChannelPost::ChannelPost(_)) => {
+            assert_eq!(post.edit_date(), Some(1593844950));
+            assert!(post.from().is_none());
+            match post.kind() {
+                MessageKind::Text { data, .. } => assert_eq!(data, "Weekly digest"),
+                kind => panic!("unexpected message kind: {:?}", kind),
+            }
+        }
+        message => panic!("unexpected message: {:?}", message),
+    }
+});
+
+make_test!(giveaway, |update: Update| {
+    match update.kind {
+        UpdateKind::ChannelPost(post) => match post.kind {
+            MessageKind::Giveaway { data } => {
+                assert_eq!(data.winner_count, 3);
+                assert!(data.only_new_members);
+                assert!(!data.has_public_winners);
+                assert_eq!(data.chats.len(), 1);
+            }
+            kind => panic!("unexpected message kind: {:?}", kind),
+        },
+        kind => panic!("unexpected update kind: {:?}", kind),
+    }
+});
+
 make_test!(inline_query, |update: Update| {
     if let UpdateKind::InlineQuery(_query) = update.kind {
         return ();
@@ -47,6 +111,94 @@ make_test!(inline_query, |update: Update| {
     assert!(false)
 });
 
+make_test!(chat_join_request, |update: Update| {
+    match update.kind {
+        UpdateKind::ChatJoinRequest(request) => {
+            assert_eq!(request.bio.as_deref(), Some("Hello there"))
+        }
+        kind => panic!("unexpected update kind: {:?}", kind),
+    }
+});
+
+make_test!(chosen_inline_result, |update: Update| {
+    match update.kind {
+        UpdateKind::ChosenInlineResult(result) => assert_eq!(result.result_id, "result id"),
+        kind => panic!("unexpected update kind: {:?}", kind),
+    }
+});
+
+make_test!(animation, |update: Update| {
+    match update.kind {
+        UpdateKind::Message(message) => match message.kind {
+            MessageKind::Animation { data, .. } => {
+                assert_eq!(data.duration, 3);
+                assert_eq!(data.thumb.map(|thumb| thumb.width), Some(90));
+            }
+            kind => panic!("unexpected message kind: {:?}", kind),
+        },
+        kind => panic!("unexpected update kind: {:?}", kind),
+    }
+});
+
+make_test!(contact, |update: Update| {
+    match update.kind {
+        UpdateKind::Message(message) => match message.kind {
+            MessageKind::Contact { data } => {
+                assert_eq!(data.phone_number, "+15550100");
+                assert_eq!(data.user_id, Some(174976101));
+            }
+            kind => panic!("unexpected message kind: {:?}", kind),
+        },
+        kind => panic!("unexpected update kind: {:?}", kind),
+    }
+});
+
+make_test!(my_chat_member, |update: Update| {
+    match update.kind {
+        UpdateKind::MyChatMember(update) => {
+            assert_eq!(update.old_chat_member.kind, ChatMemberKind::Left);
+            assert!(update.new_chat_member.is_admin());
+            assert_eq!(update.status_change(), ChatMemberStatusChange::Joined);
+            match update.new_chat_member.kind {
+                ChatMemberKind::Administrator(admin) => assert!(admin.can_delete_messages),
+                kind => panic!("unexpected member kind: {:?}", kind),
+            }
+        }
+        kind => panic!("unexpected update kind: {:?}", kind),
+    }
+});
+
+make_test!(malformed_update, |update: Update| {
+    assert_eq!(update.id, 424151293);
+    match update.kind {
+        UpdateKind::Error(err) => assert!(err.contains("message_id")),
+        kind => panic!("unexpected update kind: {:?}", kind),
+    }
+});
+
+make_test!(message_reaction, |update: Update| {
+    match update.kind {
+        UpdateKind::MessageReaction(reaction) => {
+            assert!(reaction.old_reaction.is_empty());
+            assert_eq!(
+                reaction.new_reaction,
+                vec![
+                    ReactionType::emoji("👍"),
+                    ReactionType::custom_emoji("5368324170671202286"),
+                ]
+            );
+        }
+        kind => panic!("unexpected update kind: {:?}", kind),
+    }
+});
+
+make_test!(poll_answer, |update: Update| {
+    match update.kind {
+        UpdateKind::PollAnswer(answer) => assert_eq!(answer.option_ids, vec![1]),
+        kind => panic!("unexpected update kind: {:?}", kind),
+    }
+});
+
 make_test!(regression_test_208, |update: Update| {
     if let UpdateKind::CallbackQuery(_query) = update.kind {
         return ();
@@ -54,3 +206,31 @@ make_test!(regression_test_208, |update: Update| {
 
     assert!(false)
 });
+
+make_test!(successful_payment, |update: Update| {
+    match update.kind {
+        UpdateKind::Message(message) => match message.kind {
+            MessageKind::SuccessfulPayment { data } => {
+                assert_eq!(data.total_amount, 145);
+                assert_eq!(data.invoice_payload, "order-42");
+            }
+            kind => panic!("unexpected message kind: {:?}", kind),
+        },
+        kind => panic!("unexpected update kind: {:?}", kind),
+    }
+});
+
+make_test!(video_chat_started, |update: Update| {
+    match update.kind {
+        UpdateKind::Message(message) => match message.kind {
+            MessageKind::VideoChatStarted { .. } => (),
+            kind => panic!("unexpected message kind: {:?}", kind),
+        },
+        kind => panic!("unexpected update kind: {:?}", kind),
+    }
+});
+
+make_test!(unknown_update, |update: Update| {
+    assert_eq!(update.id, 424151292);
+    assert_eq!(update.kind, UpdateKind::Unknown);
+});